@@ -84,8 +84,13 @@
 #![cfg_attr(not(test), feature(slice_rotate))]
 #![cfg_attr(test, feature(rand, test))]
 #![feature(allow_internal_unstable)]
+#![feature(array_chunks)]
+#![feature(array_windows)]
+#![feature(ascii_char)]
 #![feature(box_patterns)]
 #![feature(box_syntax)]
+#![feature(byte_slice_trim_ascii)]
+#![feature(chunks_exact)]
 #![feature(cfg_target_has_atomic)]
 #![feature(coerce_unsized)]
 #![feature(const_fn)]
@@ -93,27 +98,50 @@
 #![feature(custom_attribute)]
 #![feature(dropck_eyepatch)]
 #![feature(exact_size_is_empty)]
+#![feature(fixed_size_array)]
 #![feature(fmt_internals)]
 #![feature(fundamental)]
 #![feature(fused)]
 #![feature(generic_param_attrs)]
+#![feature(get_many_mut)]
+#![feature(hasher_prefixfree_extras)]
 #![feature(i128_type)]
 #![feature(inclusive_range)]
+#![feature(is_sorted)]
 #![feature(lang_items)]
 #![feature(needs_allocator)]
 #![feature(nonzero)]
 #![feature(offset_to)]
 #![feature(optin_builtin_traits)]
+#![feature(partition_point)]
 #![feature(pattern)]
 #![feature(placement_in_syntax)]
 #![feature(placement_new_protocol)]
+#![feature(round_char_boundary)]
 #![feature(shared)]
 #![feature(slice_get_slice)]
+#![feature(slice_align_to)]
+#![feature(slice_as_chunks)]
+#![feature(slice_fill)]
+#![feature(slice_fill_with)]
+#![feature(slice_first_last_chunk)]
+#![feature(slice_flatten)]
+#![feature(slice_group_by)]
 #![feature(slice_patterns)]
+#![feature(slice_ptr_range)]
+#![feature(slice_split_at_unchecked)]
+#![feature(slice_split_once)]
+#![feature(slice_strip)]
+#![feature(slice_swap_unchecked)]
 #![feature(slice_rsplit)]
+#![feature(slice_select_nth_unstable)]
 #![feature(specialization)]
+#![feature(split_ascii_whitespace)]
+#![feature(split_inclusive)]
 #![feature(staged_api)]
 #![feature(str_internals)]
+#![feature(str_split_once)]
+#![feature(str_strip)]
 #![feature(trusted_len)]
 #![feature(unboxed_closures)]
 #![feature(unicode)]
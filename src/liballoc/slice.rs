@@ -97,15 +97,19 @@
 // It's cleaner to just turn off the unused_imports warning than to fix them.
 #![cfg_attr(test, allow(unused_imports, dead_code))]
 
+use core::array::FixedSizeArray;
 use core::cmp::Ordering::{self, Less};
 use core::mem::size_of;
 use core::mem;
+use core::ops::Range;
 use core::ptr;
 use core::slice as core_slice;
 
 use borrow::{Borrow, BorrowMut, ToOwned};
 use boxed::Box;
+use range::RangeArgument;
 use vec::Vec;
+use Bound::{Excluded, Included, Unbounded};
 
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::slice::{Chunks, Windows};
@@ -113,10 +117,22 @@ pub use core::slice::{Chunks, Windows};
 pub use core::slice::{Iter, IterMut};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::slice::{SplitMut, ChunksMut, Split};
+#[unstable(feature = "split_inclusive", issue = "0")]
+pub use core::slice::{SplitInclusive, SplitInclusiveMut};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::slice::{SplitN, RSplitN, SplitNMut, RSplitNMut};
 #[unstable(feature = "slice_rsplit", issue = "41020")]
 pub use core::slice::{RSplit, RSplitMut};
+#[unstable(feature = "chunks_exact", issue = "0")]
+pub use core::slice::{ChunksExact, ChunksExactMut, RChunksExact, RChunksExactMut};
+#[unstable(feature = "array_chunks", issue = "0")]
+pub use core::slice::{ArrayChunks, ArrayChunksMut};
+#[unstable(feature = "array_windows", issue = "0")]
+pub use core::slice::ArrayWindows;
+#[unstable(feature = "slice_group_by", issue = "0")]
+pub use core::slice::{ChunkBy, ChunkByMut};
+#[unstable(feature = "get_many_mut", issue = "0")]
+pub use core::slice::GetManyMutIndices;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::slice::{from_raw_parts, from_raw_parts_mut};
 #[unstable(feature = "slice_get_slice", issue = "35729")]
@@ -289,6 +305,70 @@ impl<T> [T] {
 
     }
 
+    /// Returns an array reference to the first `N` items in the slice and
+    /// the remaining slice, or `None` if it has fewer than `N` elements.
+    ///
+    /// The chunk type `A` is the array type `[T; N]` itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_first_last_chunk)]
+    ///
+    /// let x = &[0, 1, 2];
+    ///
+    /// if let Some((first, elements)) = x.split_first_chunk::<[i32; 2]>() {
+    ///     assert_eq!(first, &[0, 1]);
+    ///     assert_eq!(elements, &[2]);
+    /// }
+    ///
+    /// assert_eq!(None, x.split_first_chunk::<[i32; 4]>());
+    /// ```
+    ///
+    /// Reading a fixed-size header:
+    ///
+    /// ```
+    /// #![feature(slice_first_last_chunk)]
+    ///
+    /// let packet = [0x00, 0x2a, 0xff, 0xff];
+    /// let (len, payload) = packet.split_first_chunk::<[u8; 2]>().unwrap();
+    /// assert_eq!((len[0] as u16) << 8 | len[1] as u16, 42);
+    /// assert_eq!(payload, &[0xff, 0xff]);
+    /// ```
+    #[unstable(feature = "slice_first_last_chunk", issue = "0")]
+    #[inline]
+    pub fn split_first_chunk<A>(&self) -> Option<(&A, &[T])>
+        where A: FixedSizeArray<T>
+    {
+        core_slice::SliceExt::split_first_chunk(self)
+    }
+
+    /// Returns the last `N` items in the slice as an array reference, along
+    /// with the slice before them, or `None` if it has fewer than `N`
+    /// elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_first_last_chunk)]
+    ///
+    /// let x = &[0, 1, 2];
+    ///
+    /// if let Some((elements, last)) = x.split_last_chunk::<[i32; 2]>() {
+    ///     assert_eq!(elements, &[0]);
+    ///     assert_eq!(last, &[1, 2]);
+    /// }
+    ///
+    /// assert_eq!(None, x.split_last_chunk::<[i32; 4]>());
+    /// ```
+    #[unstable(feature = "slice_first_last_chunk", issue = "0")]
+    #[inline]
+    pub fn split_last_chunk<A>(&self) -> Option<(&[T], &A)>
+        where A: FixedSizeArray<T>
+    {
+        core_slice::SliceExt::split_last_chunk(self)
+    }
+
     /// Returns the last and all the rest of the elements of the slice, or `None` if it is empty.
     ///
     /// # Examples
@@ -309,6 +389,61 @@ impl<T> [T] {
         core_slice::SliceExt::split_last_mut(self)
     }
 
+    /// Returns a mutable array reference to the first `N` items in the slice
+    /// and the remaining slice, or `None` if it has fewer than `N` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_first_last_chunk)]
+    ///
+    /// let x = &mut [0, 1, 2];
+    ///
+    /// if let Some((first, elements)) = x.split_first_chunk_mut::<[i32; 2]>() {
+    ///     first[0] = 3;
+    ///     first[1] = 4;
+    ///     elements[0] = 5;
+    /// }
+    /// assert_eq!(x, &[3, 4, 5]);
+    ///
+    /// assert_eq!(None, x.split_first_chunk_mut::<[i32; 4]>());
+    /// ```
+    #[unstable(feature = "slice_first_last_chunk", issue = "0")]
+    #[inline]
+    pub fn split_first_chunk_mut<A>(&mut self) -> Option<(&mut A, &mut [T])>
+        where A: FixedSizeArray<T>
+    {
+        core_slice::SliceExt::split_first_chunk_mut(self)
+    }
+
+    /// Returns the last `N` items in the slice as a mutable array reference,
+    /// along with the slice before them, or `None` if it has fewer than `N`
+    /// elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_first_last_chunk)]
+    ///
+    /// let x = &mut [0, 1, 2];
+    ///
+    /// if let Some((elements, last)) = x.split_last_chunk_mut::<[i32; 2]>() {
+    ///     last[0] = 3;
+    ///     last[1] = 4;
+    ///     elements[0] = 5;
+    /// }
+    /// assert_eq!(x, &[5, 3, 4]);
+    ///
+    /// assert_eq!(None, x.split_last_chunk_mut::<[i32; 4]>());
+    /// ```
+    #[unstable(feature = "slice_first_last_chunk", issue = "0")]
+    #[inline]
+    pub fn split_last_chunk_mut<A>(&mut self) -> Option<(&mut [T], &mut A)>
+        where A: FixedSizeArray<T>
+    {
+        core_slice::SliceExt::split_last_chunk_mut(self)
+    }
+
     /// Returns the last element of the slice, or `None` if it is empty.
     ///
     /// # Examples
@@ -444,6 +579,34 @@ impl<T> [T] {
         core_slice::SliceExt::get_unchecked_mut(self, index)
     }
 
+    /// Returns mutable references to many indices at once.
+    ///
+    /// Returns `None` if any index is out-of-bounds, or if the same index
+    /// was passed more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(get_many_mut)]
+    ///
+    /// let v = &mut [1, 2, 3];
+    /// if let Some(mut refs) = v.get_many_mut([0, 2]) {
+    ///     *refs[0] = 413;
+    ///     *refs[1] = 612;
+    /// }
+    /// assert_eq!(v, &[413, 2, 612]);
+    ///
+    /// assert!(v.get_many_mut([0, 3]).is_none());
+    /// assert!(v.get_many_mut([1, 1]).is_none());
+    /// ```
+    #[unstable(feature = "get_many_mut", issue = "0")]
+    #[inline]
+    pub fn get_many_mut<'a, I>(&'a mut self, indices: I) -> Option<I::Output>
+        where I: GetManyMutIndices<'a, T>
+    {
+        core_slice::SliceExt::get_many_mut(self, indices)
+    }
+
     /// Returns a raw pointer to the slice's buffer.
     ///
     /// The caller must ensure that the slice outlives the pointer this
@@ -470,6 +633,41 @@ impl<T> [T] {
         core_slice::SliceExt::as_ptr(self)
     }
 
+    /// Returns the two raw pointers spanning the slice.
+    ///
+    /// The returned range is half-open, which means that the end pointer
+    /// points *one past* the last element of the slice. This way, an empty
+    /// slice is represented by two equal pointers, and the difference between
+    /// the two pointers represents the size of the slice.
+    ///
+    /// See [`as_ptr`] for warnings on using these pointers. The end pointer
+    /// requires extra caution, as it does not point to a valid element in the
+    /// slice.
+    ///
+    /// This function is useful for interacting with foreign interfaces which
+    /// use two pointers to refer to a range of elements in memory, as is
+    /// common in C++.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_ptr_range, range_contains)]
+    ///
+    /// let a = [1, 2, 3];
+    /// let x = &a[1] as *const _;
+    /// let y = &5 as *const _;
+    ///
+    /// assert!(a.as_ptr_range().contains(x));
+    /// assert!(!a.as_ptr_range().contains(y));
+    /// ```
+    ///
+    /// [`as_ptr`]: #method.as_ptr
+    #[unstable(feature = "slice_ptr_range", issue = "0")]
+    #[inline]
+    pub fn as_ptr_range(&self) -> Range<*const T> {
+        core_slice::SliceExt::as_ptr_range(self)
+    }
+
     /// Returns an unsafe mutable pointer to the slice's buffer.
     ///
     /// The caller must ensure that the slice outlives the pointer this
@@ -497,6 +695,42 @@ impl<T> [T] {
         core_slice::SliceExt::as_mut_ptr(self)
     }
 
+    /// Returns the two unsafe mutable pointers spanning the slice.
+    ///
+    /// The returned range is half-open, which means that the end pointer
+    /// points *one past* the last element of the slice. This way, an empty
+    /// slice is represented by two equal pointers, and the difference between
+    /// the two pointers represents the size of the slice.
+    ///
+    /// See [`as_mut_ptr`] for warnings on using these pointers. The end
+    /// pointer requires extra caution, as it does not point to a valid element
+    /// in the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_ptr_range)]
+    ///
+    /// let mut a = [1, 2, 3];
+    /// let range = a.as_mut_ptr_range();
+    ///
+    /// let mut p = range.start;
+    /// while p != range.end {
+    ///     unsafe {
+    ///         *p *= 10;
+    ///         p = p.offset(1);
+    ///     }
+    /// }
+    /// assert_eq!(a, [10, 20, 30]);
+    /// ```
+    ///
+    /// [`as_mut_ptr`]: #method.as_mut_ptr
+    #[unstable(feature = "slice_ptr_range", issue = "0")]
+    #[inline]
+    pub fn as_mut_ptr_range(&mut self) -> Range<*mut T> {
+        core_slice::SliceExt::as_mut_ptr_range(self)
+    }
+
     /// Swaps two elements in the slice.
     ///
     /// # Arguments
@@ -521,6 +755,40 @@ impl<T> [T] {
         core_slice::SliceExt::swap(self, a, b)
     }
 
+    /// Swaps two elements in the slice, without doing bounds checking.
+    ///
+    /// For a safe alternative see [`swap`].
+    ///
+    /// # Arguments
+    ///
+    /// * a - The index of the first element
+    /// * b - The index of the second element
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with an out-of-bounds index is *[undefined
+    /// behavior]*. The caller has to ensure that `a < self.len()` and
+    /// `b < self.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_swap_unchecked)]
+    ///
+    /// let mut v = ["a", "b", "c", "d"];
+    /// // 1 and 3 are both valid indices of `v`.
+    /// unsafe { v.swap_unchecked(1, 3) };
+    /// assert!(v == ["a", "d", "c", "b"]);
+    /// ```
+    ///
+    /// [`swap`]: #method.swap
+    /// [undefined behavior]: ../../reference/behavior-considered-undefined.html
+    #[unstable(feature = "slice_swap_unchecked", issue = "0")]
+    #[inline]
+    pub unsafe fn swap_unchecked(&mut self, a: usize, b: usize) {
+        core_slice::SliceExt::swap_unchecked(self, a, b)
+    }
+
     /// Reverses the order of elements in the slice, in place.
     ///
     /// # Example
@@ -629,10 +897,15 @@ impl<T> [T] {
         core_slice::SliceExt::chunks(self, size)
     }
 
-    /// Returns an iterator over `chunk_size` elements of the slice at a time.
-    /// The chunks are mutable slices, and do not overlap. If `chunk_size` does
-    /// not divide the length of the slice, then the last chunk will not
-    /// have length `chunk_size`.
+    /// Returns an iterator over `chunk_size` elements of the slice at a
+    /// time. The chunks are slices and do not overlap. If `chunk_size` does
+    /// not divide the length of the slice, then the last up to `chunk_size-1`
+    /// elements will be omitted and can be retrieved from the `remainder`
+    /// function of the iterator.
+    ///
+    /// Due to each chunk having exactly `chunk_size` elements, the compiler
+    /// can often optimize the resulting code better than in the case of
+    /// [`chunks`].
     ///
     /// # Panics
     ///
@@ -641,137 +914,701 @@ impl<T> [T] {
     /// # Examples
     ///
     /// ```
-    /// let v = &mut [0, 0, 0, 0, 0];
-    /// let mut count = 1;
+    /// #![feature(chunks_exact)]
     ///
-    /// for chunk in v.chunks_mut(2) {
-    ///     for elem in chunk.iter_mut() {
-    ///         *elem += count;
-    ///     }
-    ///     count += 1;
-    /// }
-    /// assert_eq!(v, &[1, 1, 2, 2, 3]);
+    /// let slice = ['l', 'o', 'r', 'e', 'm'];
+    /// let mut iter = slice.chunks_exact(2);
+    /// assert_eq!(iter.next().unwrap(), &['l', 'o']);
+    /// assert_eq!(iter.next().unwrap(), &['r', 'e']);
+    /// assert!(iter.next().is_none());
+    /// assert_eq!(iter.remainder(), &['m']);
     /// ```
-    #[stable(feature = "rust1", since = "1.0.0")]
+    ///
+    /// [`chunks`]: #method.chunks
+    #[unstable(feature = "chunks_exact", issue = "0")]
     #[inline]
-    pub fn chunks_mut(&mut self, chunk_size: usize) -> ChunksMut<T> {
-        core_slice::SliceExt::chunks_mut(self, chunk_size)
+    pub fn chunks_exact(&self, chunk_size: usize) -> ChunksExact<T> {
+        core_slice::SliceExt::chunks_exact(self, chunk_size)
     }
 
-    /// Divides one slice into two at an index.
-    ///
-    /// The first will contain all indices from `[0, mid)` (excluding
-    /// the index `mid` itself) and the second will contain all
-    /// indices from `[mid, len)` (excluding the index `len` itself).
+    /// Returns an iterator over `chunk_size` elements of the slice at a
+    /// time, starting at the end of the slice. The chunks are slices and do
+    /// not overlap. If `chunk_size` does not divide the length of the slice,
+    /// then the first up to `chunk_size-1` elements will be omitted and can be
+    /// retrieved from the `remainder` function of the iterator.
     ///
     /// # Panics
     ///
-    /// Panics if `mid > len`.
+    /// Panics if `chunk_size` is 0.
     ///
     /// # Examples
     ///
     /// ```
-    /// let v = [10, 40, 30, 20, 50];
-    /// let (v1, v2) = v.split_at(2);
-    /// assert_eq!([10, 40], v1);
-    /// assert_eq!([30, 20, 50], v2);
+    /// #![feature(chunks_exact)]
+    ///
+    /// let slice = ['l', 'o', 'r', 'e', 'm'];
+    /// let mut iter = slice.rchunks_exact(2);
+    /// assert_eq!(iter.next().unwrap(), &['e', 'm']);
+    /// assert_eq!(iter.next().unwrap(), &['o', 'r']);
+    /// assert!(iter.next().is_none());
+    /// assert_eq!(iter.remainder(), &['l']);
     /// ```
-    #[stable(feature = "rust1", since = "1.0.0")]
+    #[unstable(feature = "chunks_exact", issue = "0")]
     #[inline]
-    pub fn split_at(&self, mid: usize) -> (&[T], &[T]) {
-        core_slice::SliceExt::split_at(self, mid)
+    pub fn rchunks_exact(&self, chunk_size: usize) -> RChunksExact<T> {
+        core_slice::SliceExt::rchunks_exact(self, chunk_size)
     }
 
-    /// Divides one `&mut` into two at an index.
+    /// Splits the slice into a slice of `N`-element arrays,
+    /// starting at the beginning of the slice,
+    /// and a remainder slice with length strictly less than `N`.
     ///
-    /// The first will contain all indices from `[0, mid)` (excluding
-    /// the index `mid` itself) and the second will contain all
-    /// indices from `[mid, len)` (excluding the index `len` itself).
+    /// The chunk type `A` is the array type `[T; N]` itself, so the chunk
+    /// size is picked with a turbofish such as `as_chunks::<[u8; 4]>()`.
     ///
     /// # Panics
     ///
-    /// Panics if `mid > len`.
+    /// Panics if `N` is 0.
     ///
     /// # Examples
     ///
     /// ```
-    /// let mut v = [1, 2, 3, 4, 5, 6];
+    /// #![feature(slice_as_chunks)]
     ///
-    /// // scoped to restrict the lifetime of the borrows
-    /// {
-    ///    let (left, right) = v.split_at_mut(0);
-    ///    assert!(left == []);
-    ///    assert!(right == [1, 2, 3, 4, 5, 6]);
-    /// }
+    /// let slice = ['l', 'o', 'r', 'e', 'm'];
+    /// let (chunks, remainder) = slice.as_chunks::<[char; 2]>();
+    /// assert_eq!(chunks, &[['l', 'o'], ['r', 'e']]);
+    /// assert_eq!(remainder, &['m']);
+    /// ```
     ///
-    /// {
-    ///     let (left, right) = v.split_at_mut(2);
-    ///     assert!(left == [1, 2]);
-    ///     assert!(right == [3, 4, 5, 6]);
-    /// }
+    /// Decoding little-endian `u32`s without any pointer casts:
     ///
-    /// {
-    ///     let (left, right) = v.split_at_mut(6);
-    ///     assert!(left == [1, 2, 3, 4, 5, 6]);
-    ///     assert!(right == []);
-    /// }
     /// ```
-    #[stable(feature = "rust1", since = "1.0.0")]
+    /// #![feature(slice_as_chunks)]
+    ///
+    /// let bytes = [0x78, 0x56, 0x34, 0x12, 0x01, 0x00, 0x00, 0x00, 0xff];
+    /// let (words, rest) = bytes.as_chunks::<[u8; 4]>();
+    /// let words: Vec<u32> = words.iter().map(|w| {
+    ///     w.iter().rev().fold(0, |acc, &b| (acc << 8) | b as u32)
+    /// }).collect();
+    /// assert_eq!(words, [0x12345678, 1]);
+    /// assert_eq!(rest, &[0xff]);
+    /// ```
+    #[unstable(feature = "slice_as_chunks", issue = "0")]
     #[inline]
-    pub fn split_at_mut(&mut self, mid: usize) -> (&mut [T], &mut [T]) {
-        core_slice::SliceExt::split_at_mut(self, mid)
+    pub fn as_chunks<A>(&self) -> (&[A], &[T])
+        where A: FixedSizeArray<T>
+    {
+        core_slice::SliceExt::as_chunks(self)
     }
 
-    /// Returns an iterator over subslices separated by elements that match
-    /// `pred`. The matched element is not contained in the subslices.
+    /// Splits the slice into a slice of `N`-element arrays,
+    /// starting at the end of the slice,
+    /// and a remainder slice with length strictly less than `N`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
     ///
     /// # Examples
     ///
     /// ```
-    /// let slice = [10, 40, 33, 20];
-    /// let mut iter = slice.split(|num| num % 3 == 0);
+    /// #![feature(slice_as_chunks)]
     ///
-    /// assert_eq!(iter.next().unwrap(), &[10, 40]);
-    /// assert_eq!(iter.next().unwrap(), &[20]);
-    /// assert!(iter.next().is_none());
+    /// let slice = ['l', 'o', 'r', 'e', 'm'];
+    /// let (remainder, chunks) = slice.as_rchunks::<[char; 2]>();
+    /// assert_eq!(remainder, &['l']);
+    /// assert_eq!(chunks, &[['o', 'r'], ['e', 'm']]);
     /// ```
+    #[unstable(feature = "slice_as_chunks", issue = "0")]
+    #[inline]
+    pub fn as_rchunks<A>(&self) -> (&[T], &[A])
+        where A: FixedSizeArray<T>
+    {
+        core_slice::SliceExt::as_rchunks(self)
+    }
+
+    /// Returns an iterator over `N` elements of the slice at a time, starting
+    /// at the beginning of the slice. The chunks are array references and do
+    /// not overlap. If `N` does not divide the length of the slice, then the
+    /// last up to `N-1` elements will be omitted and can be retrieved from the
+    /// `remainder` function of the iterator.
     ///
-    /// If the first element is matched, an empty slice will be the first item
-    /// returned by the iterator. Similarly, if the last element in the slice
-    /// is matched, an empty slice will be the last item returned by the
-    /// iterator:
+    /// This method is the array equivalent of [`chunks_exact`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// # Examples
     ///
     /// ```
-    /// let slice = [10, 40, 33];
-    /// let mut iter = slice.split(|num| num % 3 == 0);
+    /// #![feature(array_chunks)]
     ///
-    /// assert_eq!(iter.next().unwrap(), &[10, 40]);
-    /// assert_eq!(iter.next().unwrap(), &[]);
+    /// let slice = ['l', 'o', 'r', 'e', 'm'];
+    /// let mut iter = slice.array_chunks::<[char; 2]>();
+    /// assert_eq!(iter.next().unwrap(), &['l', 'o']);
+    /// assert_eq!(iter.next().unwrap(), &['r', 'e']);
     /// assert!(iter.next().is_none());
+    /// assert_eq!(iter.remainder(), &['m']);
     /// ```
     ///
-    /// If two matched elements are directly adjacent, an empty slice will be
-    /// present between them:
+    /// [`chunks_exact`]: #method.chunks_exact
+    #[unstable(feature = "array_chunks", issue = "0")]
+    #[inline]
+    pub fn array_chunks<A>(&self) -> ArrayChunks<T, A>
+        where A: FixedSizeArray<T>
+    {
+        core_slice::SliceExt::array_chunks(self)
+    }
+
+    /// Returns an iterator over overlapping windows of `N` elements of the
+    /// slice, yielded as array references, starting at the beginning of the
+    /// slice.
+    ///
+    /// This is the array equivalent of [`windows`]. Since the length of each
+    /// window is part of its type, indexing into it needs no bounds checks.
+    ///
+    /// If `N` is greater than the size of the slice, it will return no
+    /// windows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// # Examples
     ///
     /// ```
-    /// let slice = [10, 6, 33, 20];
-    /// let mut iter = slice.split(|num| num % 3 == 0);
+    /// #![feature(array_windows)]
     ///
-    /// assert_eq!(iter.next().unwrap(), &[10]);
-    /// assert_eq!(iter.next().unwrap(), &[]);
-    /// assert_eq!(iter.next().unwrap(), &[20]);
+    /// let slice = [0, 1, 2, 3];
+    /// let mut iter = slice.array_windows::<[i32; 2]>();
+    /// assert_eq!(iter.next().unwrap(), &[0, 1]);
+    /// assert_eq!(iter.next().unwrap(), &[1, 2]);
+    /// assert_eq!(iter.next().unwrap(), &[2, 3]);
     /// assert!(iter.next().is_none());
     /// ```
-    #[stable(feature = "rust1", since = "1.0.0")]
+    ///
+    /// [`windows`]: #method.windows
+    #[unstable(feature = "array_windows", issue = "0")]
     #[inline]
-    pub fn split<F>(&self, pred: F) -> Split<T, F>
-        where F: FnMut(&T) -> bool
+    pub fn array_windows<A>(&self) -> ArrayWindows<T, A>
+        where A: FixedSizeArray<T>
     {
-        core_slice::SliceExt::split(self, pred)
+        core_slice::SliceExt::array_windows(self)
     }
 
-    /// Returns an iterator over mutable subslices separated by elements that
-    /// match `pred`. The matched element is not contained in the subslices.
+    /// Returns an iterator over the slice producing non-overlapping runs
+    /// of elements using the predicate to separate them.
+    ///
+    /// The predicate is called on two elements following themselves,
+    /// it means the predicate is called on `slice[0]` and `slice[1]`
+    /// then on `slice[1]` and `slice[2]` and so on. A new run starts
+    /// wherever the predicate returns `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_group_by)]
+    ///
+    /// let slice = &[1, 1, 1, 3, 3, 2, 2, 2];
+    ///
+    /// let mut iter = slice.chunk_by(|a, b| a == b);
+    ///
+    /// assert_eq!(iter.next(), Some(&[1, 1, 1][..]));
+    /// assert_eq!(iter.next(), Some(&[3, 3][..]));
+    /// assert_eq!(iter.next(), Some(&[2, 2, 2][..]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    ///
+    /// This method can be used to extract the sorted subslices:
+    ///
+    /// ```
+    /// #![feature(slice_group_by)]
+    ///
+    /// let slice = &[1, 1, 2, 3, 2, 3, 2, 3, 4];
+    ///
+    /// let mut iter = slice.chunk_by(|a, b| a <= b);
+    ///
+    /// assert_eq!(iter.next(), Some(&[1, 1, 2, 3][..]));
+    /// assert_eq!(iter.next(), Some(&[2, 3][..]));
+    /// assert_eq!(iter.next(), Some(&[2, 3, 4][..]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[unstable(feature = "slice_group_by", issue = "0")]
+    #[inline]
+    pub fn chunk_by<F>(&self, pred: F) -> ChunkBy<T, F>
+        where F: FnMut(&T, &T) -> bool
+    {
+        core_slice::SliceExt::chunk_by(self, pred)
+    }
+
+    /// Returns an iterator over `chunk_size` elements of the slice at a time.
+    /// The chunks are mutable slices, and do not overlap. If `chunk_size` does
+    /// not divide the length of the slice, then the last chunk will not
+    /// have length `chunk_size`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let v = &mut [0, 0, 0, 0, 0];
+    /// let mut count = 1;
+    ///
+    /// for chunk in v.chunks_mut(2) {
+    ///     for elem in chunk.iter_mut() {
+    ///         *elem += count;
+    ///     }
+    ///     count += 1;
+    /// }
+    /// assert_eq!(v, &[1, 1, 2, 2, 3]);
+    /// ```
+    #[stable(feature = "rust1", since = "1.0.0")]
+    #[inline]
+    pub fn chunks_mut(&mut self, chunk_size: usize) -> ChunksMut<T> {
+        core_slice::SliceExt::chunks_mut(self, chunk_size)
+    }
+
+    /// Returns an iterator over `chunk_size` elements of the slice at a time.
+    /// The chunks are mutable slices, and do not overlap. If `chunk_size` does
+    /// not divide the length of the slice, then the last up to `chunk_size-1`
+    /// elements will be omitted and can be retrieved from the `into_remainder`
+    /// function of the iterator.
+    ///
+    /// Due to each chunk having exactly `chunk_size` elements, the compiler
+    /// can often optimize the resulting code better than in the case of
+    /// [`chunks_mut`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(chunks_exact)]
+    ///
+    /// let v = &mut [0, 0, 0, 0, 0];
+    /// let mut count = 1;
+    ///
+    /// for chunk in v.chunks_exact_mut(2) {
+    ///     for elem in chunk.iter_mut() {
+    ///         *elem += count;
+    ///     }
+    ///     count += 1;
+    /// }
+    /// assert_eq!(v, &[1, 1, 2, 2, 0]);
+    /// ```
+    ///
+    /// [`chunks_mut`]: #method.chunks_mut
+    #[unstable(feature = "chunks_exact", issue = "0")]
+    #[inline]
+    pub fn chunks_exact_mut(&mut self, chunk_size: usize) -> ChunksExactMut<T> {
+        core_slice::SliceExt::chunks_exact_mut(self, chunk_size)
+    }
+
+    /// Returns an iterator over `chunk_size` elements of the slice at a time,
+    /// starting at the end of the slice. The chunks are mutable slices, and do
+    /// not overlap. If `chunk_size` does not divide the length of the slice,
+    /// then the first up to `chunk_size-1` elements will be omitted and can be
+    /// retrieved from the `into_remainder` function of the iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(chunks_exact)]
+    ///
+    /// let v = &mut [0, 0, 0, 0, 0];
+    /// let mut count = 1;
+    ///
+    /// for chunk in v.rchunks_exact_mut(2) {
+    ///     for elem in chunk.iter_mut() {
+    ///         *elem += count;
+    ///     }
+    ///     count += 1;
+    /// }
+    /// assert_eq!(v, &[0, 2, 2, 1, 1]);
+    /// ```
+    #[unstable(feature = "chunks_exact", issue = "0")]
+    #[inline]
+    pub fn rchunks_exact_mut(&mut self, chunk_size: usize) -> RChunksExactMut<T> {
+        core_slice::SliceExt::rchunks_exact_mut(self, chunk_size)
+    }
+
+    /// Splits the slice into a slice of `N`-element arrays,
+    /// starting at the beginning of the slice,
+    /// and a remainder slice with length strictly less than `N`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_as_chunks)]
+    ///
+    /// let v = &mut [0, 0, 0, 0, 0];
+    /// let mut count = 1;
+    ///
+    /// {
+    ///     let (chunks, remainder) = v.as_chunks_mut::<[i32; 2]>();
+    ///     remainder[0] = 9;
+    ///     for chunk in chunks {
+    ///         *chunk = [count; 2];
+    ///         count += 1;
+    ///     }
+    /// }
+    /// assert_eq!(v, &[1, 1, 2, 2, 9]);
+    /// ```
+    #[unstable(feature = "slice_as_chunks", issue = "0")]
+    #[inline]
+    pub fn as_chunks_mut<A>(&mut self) -> (&mut [A], &mut [T])
+        where A: FixedSizeArray<T>
+    {
+        core_slice::SliceExt::as_chunks_mut(self)
+    }
+
+    /// Splits the slice into a slice of `N`-element arrays,
+    /// starting at the end of the slice,
+    /// and a remainder slice with length strictly less than `N`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_as_chunks)]
+    ///
+    /// let v = &mut [0, 0, 0, 0, 0];
+    /// let mut count = 1;
+    ///
+    /// {
+    ///     let (remainder, chunks) = v.as_rchunks_mut::<[i32; 2]>();
+    ///     remainder[0] = 9;
+    ///     for chunk in chunks {
+    ///         *chunk = [count; 2];
+    ///         count += 1;
+    ///     }
+    /// }
+    /// assert_eq!(v, &[9, 1, 1, 2, 2]);
+    /// ```
+    #[unstable(feature = "slice_as_chunks", issue = "0")]
+    #[inline]
+    pub fn as_rchunks_mut<A>(&mut self) -> (&mut [T], &mut [A])
+        where A: FixedSizeArray<T>
+    {
+        core_slice::SliceExt::as_rchunks_mut(self)
+    }
+
+    /// Returns an iterator over `N` elements of the slice at a time, starting
+    /// at the beginning of the slice. The chunks are mutable array references
+    /// and do not overlap. If `N` does not divide the length of the slice,
+    /// then the last up to `N-1` elements will be omitted and can be retrieved
+    /// from the `into_remainder` function of the iterator.
+    ///
+    /// This method is the array equivalent of [`chunks_exact_mut`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(array_chunks)]
+    ///
+    /// let v = &mut [0, 0, 0, 0, 0];
+    /// let mut count = 1;
+    ///
+    /// for chunk in v.array_chunks_mut::<[i32; 2]>() {
+    ///     *chunk = [count; 2];
+    ///     count += 1;
+    /// }
+    /// assert_eq!(v, &[1, 1, 2, 2, 0]);
+    /// ```
+    ///
+    /// [`chunks_exact_mut`]: #method.chunks_exact_mut
+    #[unstable(feature = "array_chunks", issue = "0")]
+    #[inline]
+    pub fn array_chunks_mut<A>(&mut self) -> ArrayChunksMut<T, A>
+        where A: FixedSizeArray<T>
+    {
+        core_slice::SliceExt::array_chunks_mut(self)
+    }
+
+    /// Returns an iterator over the slice producing non-overlapping mutable
+    /// runs of elements using the predicate to separate them.
+    ///
+    /// The predicate is called on two elements following themselves,
+    /// it means the predicate is called on `slice[0]` and `slice[1]`
+    /// then on `slice[1]` and `slice[2]` and so on. A new run starts
+    /// wherever the predicate returns `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_group_by)]
+    ///
+    /// let slice = &mut [1, 1, 1, 3, 3, 2, 2, 2];
+    ///
+    /// let mut iter = slice.chunk_by_mut(|a, b| a == b);
+    ///
+    /// assert_eq!(iter.next(), Some(&mut [1, 1, 1][..]));
+    /// assert_eq!(iter.next(), Some(&mut [3, 3][..]));
+    /// assert_eq!(iter.next(), Some(&mut [2, 2, 2][..]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[unstable(feature = "slice_group_by", issue = "0")]
+    #[inline]
+    pub fn chunk_by_mut<F>(&mut self, pred: F) -> ChunkByMut<T, F>
+        where F: FnMut(&T, &T) -> bool
+    {
+        core_slice::SliceExt::chunk_by_mut(self, pred)
+    }
+
+    /// Divides one slice into two at an index.
+    ///
+    /// The first will contain all indices from `[0, mid)` (excluding
+    /// the index `mid` itself) and the second will contain all
+    /// indices from `[mid, len)` (excluding the index `len` itself).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let v = [10, 40, 30, 20, 50];
+    /// let (v1, v2) = v.split_at(2);
+    /// assert_eq!([10, 40], v1);
+    /// assert_eq!([30, 20, 50], v2);
+    /// ```
+    #[stable(feature = "rust1", since = "1.0.0")]
+    #[inline]
+    pub fn split_at(&self, mid: usize) -> (&[T], &[T]) {
+        core_slice::SliceExt::split_at(self, mid)
+    }
+
+    /// Divides one slice into two at an index, without doing bounds checking.
+    ///
+    /// The first will contain all indices from `[0, mid)` (excluding
+    /// the index `mid` itself) and the second will contain all
+    /// indices from `[mid, len)` (excluding the index `len` itself).
+    ///
+    /// For a safe alternative see [`split_at`].
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with an out-of-bounds index is *[undefined
+    /// behavior]* even if the resulting reference is not used. The caller
+    /// has to ensure that `0 <= mid <= self.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_split_at_unchecked)]
+    ///
+    /// let v = [1, 2, 3, 4, 5, 6];
+    ///
+    /// unsafe {
+    ///    let (left, right) = v.split_at_unchecked(0);
+    ///    assert_eq!(left, []);
+    ///    assert_eq!(right, [1, 2, 3, 4, 5, 6]);
+    /// }
+    ///
+    /// unsafe {
+    ///     let (left, right) = v.split_at_unchecked(2);
+    ///     assert_eq!(left, [1, 2]);
+    ///     assert_eq!(right, [3, 4, 5, 6]);
+    /// }
+    /// ```
+    ///
+    /// [`split_at`]: #method.split_at
+    /// [undefined behavior]: ../../reference/behavior-considered-undefined.html
+    #[unstable(feature = "slice_split_at_unchecked", issue = "0")]
+    #[inline]
+    pub unsafe fn split_at_unchecked(&self, mid: usize) -> (&[T], &[T]) {
+        core_slice::SliceExt::split_at_unchecked(self, mid)
+    }
+
+    /// Divides one `&mut` into two at an index.
+    ///
+    /// The first will contain all indices from `[0, mid)` (excluding
+    /// the index `mid` itself) and the second will contain all
+    /// indices from `[mid, len)` (excluding the index `len` itself).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut v = [1, 2, 3, 4, 5, 6];
+    ///
+    /// // scoped to restrict the lifetime of the borrows
+    /// {
+    ///    let (left, right) = v.split_at_mut(0);
+    ///    assert!(left == []);
+    ///    assert!(right == [1, 2, 3, 4, 5, 6]);
+    /// }
+    ///
+    /// {
+    ///     let (left, right) = v.split_at_mut(2);
+    ///     assert!(left == [1, 2]);
+    ///     assert!(right == [3, 4, 5, 6]);
+    /// }
+    ///
+    /// {
+    ///     let (left, right) = v.split_at_mut(6);
+    ///     assert!(left == [1, 2, 3, 4, 5, 6]);
+    ///     assert!(right == []);
+    /// }
+    /// ```
+    #[stable(feature = "rust1", since = "1.0.0")]
+    #[inline]
+    pub fn split_at_mut(&mut self, mid: usize) -> (&mut [T], &mut [T]) {
+        core_slice::SliceExt::split_at_mut(self, mid)
+    }
+
+    /// Divides one mutable slice into two at an index, without doing bounds
+    /// checking.
+    ///
+    /// The first will contain all indices from `[0, mid)` (excluding
+    /// the index `mid` itself) and the second will contain all
+    /// indices from `[mid, len)` (excluding the index `len` itself).
+    ///
+    /// For a safe alternative see [`split_at_mut`].
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with an out-of-bounds index is *[undefined
+    /// behavior]* even if the resulting reference is not used. The caller
+    /// has to ensure that `0 <= mid <= self.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_split_at_unchecked)]
+    ///
+    /// let mut v = [1, 0, 3, 0, 5, 6];
+    /// // scoped to restrict the lifetime of the borrows
+    /// unsafe {
+    ///     let (left, right) = v.split_at_mut_unchecked(2);
+    ///     assert_eq!(left, [1, 0]);
+    ///     assert_eq!(right, [3, 0, 5, 6]);
+    ///     left[1] = 2;
+    ///     right[1] = 4;
+    /// }
+    /// assert_eq!(v, [1, 2, 3, 4, 5, 6]);
+    /// ```
+    ///
+    /// [`split_at_mut`]: #method.split_at_mut
+    /// [undefined behavior]: ../../reference/behavior-considered-undefined.html
+    #[unstable(feature = "slice_split_at_unchecked", issue = "0")]
+    #[inline]
+    pub unsafe fn split_at_mut_unchecked(&mut self, mid: usize) -> (&mut [T], &mut [T]) {
+        core_slice::SliceExt::split_at_mut_unchecked(self, mid)
+    }
+
+    /// Returns an iterator over subslices separated by elements that match
+    /// `pred`. The matched element is not contained in the subslices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let slice = [10, 40, 33, 20];
+    /// let mut iter = slice.split(|num| num % 3 == 0);
+    ///
+    /// assert_eq!(iter.next().unwrap(), &[10, 40]);
+    /// assert_eq!(iter.next().unwrap(), &[20]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    ///
+    /// If the first element is matched, an empty slice will be the first item
+    /// returned by the iterator. Similarly, if the last element in the slice
+    /// is matched, an empty slice will be the last item returned by the
+    /// iterator:
+    ///
+    /// ```
+    /// let slice = [10, 40, 33];
+    /// let mut iter = slice.split(|num| num % 3 == 0);
+    ///
+    /// assert_eq!(iter.next().unwrap(), &[10, 40]);
+    /// assert_eq!(iter.next().unwrap(), &[]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    ///
+    /// If two matched elements are directly adjacent, an empty slice will be
+    /// present between them:
+    ///
+    /// ```
+    /// let slice = [10, 6, 33, 20];
+    /// let mut iter = slice.split(|num| num % 3 == 0);
+    ///
+    /// assert_eq!(iter.next().unwrap(), &[10]);
+    /// assert_eq!(iter.next().unwrap(), &[]);
+    /// assert_eq!(iter.next().unwrap(), &[20]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    #[stable(feature = "rust1", since = "1.0.0")]
+    #[inline]
+    pub fn split<F>(&self, pred: F) -> Split<T, F>
+        where F: FnMut(&T) -> bool
+    {
+        core_slice::SliceExt::split(self, pred)
+    }
+
+    /// Returns an iterator over subslices separated by elements that match
+    /// `pred`. The matched element is contained in the end of the previous
+    /// subslice as a terminator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(split_inclusive)]
+    /// let slice = [10, 40, 33, 20];
+    /// let mut iter = slice.split_inclusive(|num| num % 3 == 0);
+    ///
+    /// assert_eq!(iter.next().unwrap(), &[10, 40, 33]);
+    /// assert_eq!(iter.next().unwrap(), &[20]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    ///
+    /// If the last element of the slice is matched,
+    /// that element will be considered the terminator of the preceding slice.
+    /// That slice will be the last item returned by the iterator.
+    ///
+    /// ```
+    /// #![feature(split_inclusive)]
+    /// let slice = [3, 10, 40, 33];
+    /// let mut iter = slice.split_inclusive(|num| num % 3 == 0);
+    ///
+    /// assert_eq!(iter.next().unwrap(), &[3]);
+    /// assert_eq!(iter.next().unwrap(), &[10, 40, 33]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    #[unstable(feature = "split_inclusive", issue = "0")]
+    #[inline]
+    pub fn split_inclusive<F>(&self, pred: F) -> SplitInclusive<T, F>
+        where F: FnMut(&T) -> bool
+    {
+        core_slice::SliceExt::split_inclusive(self, pred)
+    }
+
+    /// Returns an iterator over mutable subslices separated by elements that
+    /// match `pred`. The matched element is not contained in the subslices.
     ///
     /// # Examples
     ///
@@ -791,6 +1628,30 @@ impl<T> [T] {
         core_slice::SliceExt::split_mut(self, pred)
     }
 
+    /// Returns an iterator over mutable subslices separated by elements that
+    /// match `pred`. The matched element is contained in the previous
+    /// subslice as a terminator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(split_inclusive)]
+    /// let mut v = [10, 40, 30, 20, 60, 50];
+    ///
+    /// for group in v.split_inclusive_mut(|num| *num % 3 == 0) {
+    ///     let terminator_idx = group.len()-1;
+    ///     group[terminator_idx] = 1;
+    /// }
+    /// assert_eq!(v, [10, 40, 1, 20, 1, 1]);
+    /// ```
+    #[unstable(feature = "split_inclusive", issue = "0")]
+    #[inline]
+    pub fn split_inclusive_mut<F>(&mut self, pred: F) -> SplitInclusiveMut<T, F>
+        where F: FnMut(&T) -> bool
+    {
+        core_slice::SliceExt::split_inclusive_mut(self, pred)
+    }
+
     /// Returns an iterator over subslices separated by elements that match
     /// `pred`, starting at the end of the slice and working backwards.
     /// The matched element is not contained in the subslices.
@@ -937,6 +1798,65 @@ impl<T> [T] {
         core_slice::SliceExt::rsplitn(self, n, pred)
     }
 
+    /// Splits the slice on the first element that matches the specified
+    /// predicate.
+    ///
+    /// If any matching elements are present in the slice, returns the prefix
+    /// before the match and suffix after. The matching element itself is not
+    /// included. If no elements match, returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_split_once)]
+    ///
+    /// let s = [1, 2, 3, 2, 4];
+    /// assert_eq!(s.split_once(|&x| x == 2), Some((&[1][..], &[3, 2, 4][..])));
+    /// assert_eq!(s.split_once(|&x| x == 0), None);
+    /// ```
+    ///
+    /// Splitting a header line off a byte buffer:
+    ///
+    /// ```
+    /// #![feature(slice_split_once)]
+    ///
+    /// let data = b"key=value";
+    /// let (key, value) = data.split_once(|&b| b == b'=').unwrap();
+    /// assert_eq!(key, b"key");
+    /// assert_eq!(value, b"value");
+    /// ```
+    #[unstable(feature = "slice_split_once", issue = "0")]
+    #[inline]
+    pub fn split_once<F>(&self, pred: F) -> Option<(&[T], &[T])>
+        where F: FnMut(&T) -> bool
+    {
+        core_slice::SliceExt::split_once(self, pred)
+    }
+
+    /// Splits the slice on the last element that matches the specified
+    /// predicate.
+    ///
+    /// If any matching elements are present in the slice, returns the prefix
+    /// before the match and suffix after. The matching element itself is not
+    /// included. If no elements match, returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_split_once)]
+    ///
+    /// let s = [1, 2, 3, 2, 4];
+    /// assert_eq!(s.rsplit_once(|&x| x == 2), Some((&[1, 2, 3][..], &[4][..])));
+    /// assert_eq!(s.rsplit_once(|&x| x == 0), None);
+    /// ```
+    #[unstable(feature = "slice_split_once", issue = "0")]
+    #[inline]
+    pub fn rsplit_once<F>(&self, pred: F) -> Option<(&[T], &[T])>
+        where F: FnMut(&T) -> bool
+    {
+        core_slice::SliceExt::rsplit_once(self, pred)
+    }
+
     /// Returns an iterator over subslices separated by elements that match
     /// `pred` limited to returning at most `n` items. This starts at the end of
     /// the slice and works backwards. The matched element is not contained in
@@ -1033,13 +1953,76 @@ impl<T> [T] {
         core_slice::SliceExt::ends_with(self, needle)
     }
 
+    /// Returns a subslice with the prefix removed.
+    ///
+    /// If the slice starts with `prefix`, returns the subslice after the
+    /// prefix, wrapped in `Some`. If `prefix` is empty, simply returns the
+    /// original slice.
+    ///
+    /// If the slice does not start with `prefix`, returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_strip)]
+    ///
+    /// let v = &[10, 40, 30];
+    /// assert_eq!(v.strip_prefix(&[10]), Some(&[40, 30][..]));
+    /// assert_eq!(v.strip_prefix(&[10, 40]), Some(&[30][..]));
+    /// assert_eq!(v.strip_prefix(&[50]), None);
+    /// assert_eq!(v.strip_prefix(&[10, 50]), None);
+    ///
+    /// let png = b"\x89PNG\r\n\x1a\n...";
+    /// assert_eq!(png.strip_prefix(b"\x89PNG"), Some(&b"\r\n\x1a\n..."[..]));
+    /// ```
+    #[unstable(feature = "slice_strip", issue = "0")]
+    #[inline]
+    pub fn strip_prefix(&self, prefix: &[T]) -> Option<&[T]>
+        where T: PartialEq
+    {
+        core_slice::SliceExt::strip_prefix(self, prefix)
+    }
+
+    /// Returns a subslice with the suffix removed.
+    ///
+    /// If the slice ends with `suffix`, returns the subslice before the
+    /// suffix, wrapped in `Some`. If `suffix` is empty, simply returns the
+    /// original slice.
+    ///
+    /// If the slice does not end with `suffix`, returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_strip)]
+    ///
+    /// let v = &[10, 40, 30];
+    /// assert_eq!(v.strip_suffix(&[30]), Some(&[10, 40][..]));
+    /// assert_eq!(v.strip_suffix(&[40, 30]), Some(&[10][..]));
+    /// assert_eq!(v.strip_suffix(&[50]), None);
+    /// assert_eq!(v.strip_suffix(&[50, 30]), None);
+    /// ```
+    #[unstable(feature = "slice_strip", issue = "0")]
+    #[inline]
+    pub fn strip_suffix(&self, suffix: &[T]) -> Option<&[T]>
+        where T: PartialEq
+    {
+        core_slice::SliceExt::strip_suffix(self, suffix)
+    }
+
     /// Binary searches this sorted slice for a given element.
     ///
     /// If the value is found then `Ok` is returned, containing the
-    /// index of the matching element; if the value is not found then
+    /// index of the matching element. If there are multiple matches, then any
+    /// one of the matches could be returned. If the value is not found then
     /// `Err` is returned, containing the index where a matching
     /// element could be inserted while maintaining sorted order.
     ///
+    /// See also [`partition_point`], which always returns the boundary of a
+    /// run of equal elements.
+    ///
+    /// [`partition_point`]: #method.partition_point
+    ///
     /// # Example
     ///
     /// Looks up a series of four elements. The first is found, with a
@@ -1055,6 +2038,32 @@ impl<T> [T] {
     /// let r = s.binary_search(&1);
     /// assert!(match r { Ok(1...4) => true, _ => false, });
     /// ```
+    ///
+    /// If you want to find the whole range of matching items, rather than
+    /// an arbitrary matching one, use [`partition_point`] for the leftmost
+    /// and rightmost boundaries:
+    ///
+    /// ```
+    /// #![feature(partition_point)]
+    ///
+    /// let s = [0, 1, 1, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55];
+    ///
+    /// let low = s.partition_point(|x| x < &1);
+    /// assert_eq!(low, 1);
+    /// let high = s.partition_point(|x| x <= &1);
+    /// assert_eq!(high, 5);
+    /// let i = s.binary_search(&1).unwrap();
+    /// assert!(low <= i && i < high);
+    ///
+    /// assert!(s[..low].iter().all(|&x| x < 1));
+    /// assert!(s[low..high].iter().all(|&x| x == 1));
+    /// assert!(s[high..].iter().all(|&x| x > 1));
+    ///
+    /// // For something not found, the "range" of equal items is empty
+    /// assert_eq!(s.partition_point(|x| x < &11), 9);
+    /// assert_eq!(s.partition_point(|x| x <= &11), 9);
+    /// assert_eq!(s.binary_search(&11), Err(9));
+    /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn binary_search(&self, x: &T) -> Result<usize, usize>
         where T: Ord
@@ -1070,10 +2079,16 @@ impl<T> [T] {
     /// `Equal` or `Greater` the desired target.
     ///
     /// If a matching value is found then returns `Ok`, containing
-    /// the index for the matched element; if no match is found then
-    /// `Err` is returned, containing the index where a matching
+    /// the index for the matched element. If there are multiple matches,
+    /// then any one of the matches could be returned. If no match is found
+    /// then `Err` is returned, containing the index where a matching
     /// element could be inserted while maintaining sorted order.
     ///
+    /// To find the first or last of several matching elements, use
+    /// [`partition_point`] instead.
+    ///
+    /// [`partition_point`]: #method.partition_point
+    ///
     /// # Example
     ///
     /// Looks up a series of four elements. The first is found, with a
@@ -1107,11 +2122,16 @@ impl<T> [T] {
     /// [`sort_by_key`] using the same key extraction function.
     ///
     /// If a matching value is found then returns `Ok`, containing the
-    /// index for the matched element; if no match is found then `Err`
-    /// is returned, containing the index where a matching element could
+    /// index for the matched element. If there are multiple matches, then
+    /// any one of the matches could be returned. If no match is found then
+    /// `Err` is returned, containing the index where a matching element could
     /// be inserted while maintaining sorted order.
     ///
+    /// To find the first or last of several matching elements, use
+    /// [`partition_point`] instead.
+    ///
     /// [`sort_by_key`]: #method.sort_by_key
+    /// [`partition_point`]: #method.partition_point
     ///
     /// # Examples
     ///
@@ -1140,6 +2160,152 @@ impl<T> [T] {
         core_slice::SliceExt::binary_search_by_key(self, b, f)
     }
 
+    /// Returns the index of the partition point according to the given
+    /// predicate (the index of the first element of the second partition).
+    ///
+    /// The slice is assumed to be partitioned according to the given
+    /// predicate. This means that all elements for which the predicate
+    /// returns true are at the start of the slice and all elements for which
+    /// the predicate returns false are at the end. For example,
+    /// `[7, 15, 3, 5, 4, 12, 6]` is partitioned under the predicate
+    /// `x % 2 != 0` (all odd numbers are at the start, all even at the end).
+    ///
+    /// If this slice is not partitioned, the returned result is unspecified
+    /// and meaningless, as this method performs a kind of binary search.
+    ///
+    /// See also [`binary_search`] and [`binary_search_by`].
+    ///
+    /// [`binary_search`]: #method.binary_search
+    /// [`binary_search_by`]: #method.binary_search_by
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(partition_point)]
+    ///
+    /// let v = [1, 2, 3, 3, 5, 6, 7];
+    /// let i = v.partition_point(|&x| x < 5);
+    ///
+    /// assert_eq!(i, 4);
+    /// assert!(v[..i].iter().all(|&x| x < 5));
+    /// assert!(v[i..].iter().all(|&x| !(x < 5)));
+    /// ```
+    ///
+    /// Finding the range of elements equal to a value in a sorted slice
+    /// with duplicates:
+    ///
+    /// ```
+    /// #![feature(partition_point)]
+    ///
+    /// let v = [1, 2, 2, 2, 3, 5];
+    /// let low = v.partition_point(|&x| x < 2);
+    /// let high = v.partition_point(|&x| x <= 2);
+    /// assert_eq!(&v[low..high], &[2, 2, 2]);
+    /// ```
+    #[unstable(feature = "partition_point", issue = "0")]
+    #[inline]
+    pub fn partition_point<P>(&self, pred: P) -> usize
+        where P: FnMut(&T) -> bool
+    {
+        core_slice::SliceExt::partition_point(self, pred)
+    }
+
+    /// Checks if the elements of this slice are sorted.
+    ///
+    /// That is, for each element `a` and its following element `b`, `a <= b`
+    /// must hold. If the slice yields exactly zero or one element, `true` is
+    /// returned.
+    ///
+    /// Note that if `Self::Item` is only `PartialOrd`, but not `Ord`, the
+    /// above definition implies that this function returns `false` if any two
+    /// consecutive items are not comparable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(is_sorted)]
+    /// let empty: [i32; 0] = [];
+    ///
+    /// assert!([1, 2, 2, 9].is_sorted());
+    /// assert!(![1, 3, 2, 4].is_sorted());
+    /// assert!([0].is_sorted());
+    /// assert!(empty.is_sorted());
+    /// assert!(![0.0, 1.0, std::f32::NAN].is_sorted());
+    /// ```
+    ///
+    /// This makes it cheap to check the precondition of [`binary_search`]
+    /// in debug builds:
+    ///
+    /// ```
+    /// #![feature(is_sorted)]
+    ///
+    /// let v = [1, 3, 5, 7];
+    /// debug_assert!(v.is_sorted());
+    /// assert_eq!(v.binary_search(&5), Ok(2));
+    /// ```
+    ///
+    /// [`binary_search`]: #method.binary_search
+    #[unstable(feature = "is_sorted", issue = "0")]
+    #[inline]
+    pub fn is_sorted(&self) -> bool
+        where T: PartialOrd
+    {
+        core_slice::SliceExt::is_sorted(self)
+    }
+
+    /// Checks if the elements of this slice are sorted using the given
+    /// comparator function.
+    ///
+    /// Instead of using `PartialOrd::le`, this function uses the given
+    /// `compare` function to determine whether two elements are to be
+    /// considered in sorted order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(is_sorted)]
+    ///
+    /// assert!([1, 2, 2, 9].is_sorted_by(|a, b| a <= b));
+    /// assert!(![1, 2, 2, 9].is_sorted_by(|a, b| a < b));
+    ///
+    /// assert!([0].is_sorted_by(|a, b| true));
+    /// assert!([0].is_sorted_by(|a, b| false));
+    /// ```
+    #[unstable(feature = "is_sorted", issue = "0")]
+    #[inline]
+    pub fn is_sorted_by<F>(&self, compare: F) -> bool
+        where F: FnMut(&T, &T) -> bool
+    {
+        core_slice::SliceExt::is_sorted_by(self, compare)
+    }
+
+    /// Checks if the elements of this slice are sorted using the given key
+    /// extraction function.
+    ///
+    /// Instead of comparing the slice's elements directly, this function
+    /// compares the keys of the elements, as determined by `f`. Apart from
+    /// that, it's equivalent to [`is_sorted`]; see its documentation for
+    /// more information.
+    ///
+    /// [`is_sorted`]: #method.is_sorted
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(is_sorted)]
+    ///
+    /// assert!(["c", "bb", "aaa"].is_sorted_by_key(|s| s.len()));
+    /// assert!(![-2i32, -1, 0, 3].is_sorted_by_key(|n| n.abs()));
+    /// ```
+    #[unstable(feature = "is_sorted", issue = "0")]
+    #[inline]
+    pub fn is_sorted_by_key<F, K>(&self, f: F) -> bool
+        where F: FnMut(&T) -> K,
+              K: PartialOrd
+    {
+        core_slice::SliceExt::is_sorted_by_key(self, f)
+    }
+
     /// Sorts the slice.
     ///
     /// This sort is stable (i.e. does not reorder equal elements) and `O(n log n)` worst-case.
@@ -1226,131 +2392,414 @@ impl<T> [T] {
     /// It is designed to be very fast in cases where the slice is nearly sorted, or consists of
     /// two or more sorted sequences concatenated one after another.
     ///
-    /// Also, it allocates temporary storage half the size of `self`, but for short slices a
-    /// non-allocating insertion sort is used instead.
+    /// Also, it allocates temporary storage half the size of `self`, but for short slices a
+    /// non-allocating insertion sort is used instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut v = [-5i32, 4, 1, -3, 2];
+    ///
+    /// v.sort_by_key(|k| k.abs());
+    /// assert!(v == [1, 2, -3, 4, -5]);
+    /// ```
+    #[stable(feature = "slice_sort_by_key", since = "1.7.0")]
+    #[inline]
+    pub fn sort_by_key<B, F>(&mut self, mut f: F)
+        where F: FnMut(&T) -> B, B: Ord
+    {
+        merge_sort(self, |a, b| f(a).lt(&f(b)));
+    }
+
+    /// Sorts the slice, but may not preserve the order of equal elements.
+    ///
+    /// This sort is unstable (i.e. may reorder equal elements), in-place (i.e. does not allocate),
+    /// and `O(n log n)` worst-case.
+    ///
+    /// # Current implementation
+    ///
+    /// The current algorithm is based on [pattern-defeating quicksort][pdqsort] by Orson Peters,
+    /// which combines the fast average case of randomized quicksort with the fast worst case of
+    /// heapsort, while achieving linear time on slices with certain patterns. It uses some
+    /// randomization to avoid degenerate cases, but with a fixed seed to always provide
+    /// deterministic behavior.
+    ///
+    /// It is typically faster than stable sorting, except in a few special cases, e.g. when the
+    /// slice consists of several concatenated sorted sequences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut v = [-5, 4, 1, -3, 2];
+    ///
+    /// v.sort_unstable();
+    /// assert!(v == [-5, -3, 1, 2, 4]);
+    /// ```
+    ///
+    /// [pdqsort]: https://github.com/orlp/pdqsort
+    #[stable(feature = "sort_unstable", since = "1.20.0")]
+    #[inline]
+    pub fn sort_unstable(&mut self)
+        where T: Ord
+    {
+        core_slice::SliceExt::sort_unstable(self);
+    }
+
+    /// Sorts the slice with a comparator function, but may not preserve the order of equal
+    /// elements.
+    ///
+    /// This sort is unstable (i.e. may reorder equal elements), in-place (i.e. does not allocate),
+    /// and `O(n log n)` worst-case.
+    ///
+    /// # Current implementation
+    ///
+    /// The current algorithm is based on [pattern-defeating quicksort][pdqsort] by Orson Peters,
+    /// which combines the fast average case of randomized quicksort with the fast worst case of
+    /// heapsort, while achieving linear time on slices with certain patterns. It uses some
+    /// randomization to avoid degenerate cases, but with a fixed seed to always provide
+    /// deterministic behavior.
+    ///
+    /// It is typically faster than stable sorting, except in a few special cases, e.g. when the
+    /// slice consists of several concatenated sorted sequences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut v = [5, 4, 1, 3, 2];
+    /// v.sort_unstable_by(|a, b| a.cmp(b));
+    /// assert!(v == [1, 2, 3, 4, 5]);
+    ///
+    /// // reverse sorting
+    /// v.sort_unstable_by(|a, b| b.cmp(a));
+    /// assert!(v == [5, 4, 3, 2, 1]);
+    /// ```
+    ///
+    /// [pdqsort]: https://github.com/orlp/pdqsort
+    #[stable(feature = "sort_unstable", since = "1.20.0")]
+    #[inline]
+    pub fn sort_unstable_by<F>(&mut self, compare: F)
+        where F: FnMut(&T, &T) -> Ordering
+    {
+        core_slice::SliceExt::sort_unstable_by(self, compare);
+    }
+
+    /// Sorts the slice with a key extraction function, but may not preserve the order of equal
+    /// elements.
+    ///
+    /// This sort is unstable (i.e. may reorder equal elements), in-place (i.e. does not allocate),
+    /// and `O(n log n)` worst-case.
+    ///
+    /// # Current implementation
+    ///
+    /// The current algorithm is based on [pattern-defeating quicksort][pdqsort] by Orson Peters,
+    /// which combines the fast average case of randomized quicksort with the fast worst case of
+    /// heapsort, while achieving linear time on slices with certain patterns. It uses some
+    /// randomization to avoid degenerate cases, but with a fixed seed to always provide
+    /// deterministic behavior.
+    ///
+    /// It is typically faster than stable sorting, except in a few special cases, e.g. when the
+    /// slice consists of several concatenated sorted sequences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut v = [-5i32, 4, 1, -3, 2];
+    ///
+    /// v.sort_unstable_by_key(|k| k.abs());
+    /// assert!(v == [1, 2, -3, 4, -5]);
+    /// ```
+    ///
+    /// [pdqsort]: https://github.com/orlp/pdqsort
+    #[stable(feature = "sort_unstable", since = "1.20.0")]
+    #[inline]
+    pub fn sort_unstable_by_key<B, F>(&mut self, f: F)
+        where F: FnMut(&T) -> B,
+              B: Ord
+    {
+        core_slice::SliceExt::sort_unstable_by_key(self, f);
+    }
+
+    /// Reorders the slice such that the element at `index` is at its final
+    /// sorted position.
+    ///
+    /// This reordering has the additional property that any value at position
+    /// `i < index` will be less than or equal to any value at a position
+    /// `j > index`. Additionally, this reordering is unstable (i.e. any number
+    /// of equal elements may end up at position `index`), in-place (i.e. does
+    /// not allocate), and `O(n)` on average. The worst-case performance is
+    /// `O(n log n)`. This function is also known as "kth element" in other
+    /// libraries.
+    ///
+    /// It returns a triplet of the following values: all elements less than
+    /// the one at the given index, the value at the given index, and all
+    /// elements greater than the one at the given index.
+    ///
+    /// # Current implementation
+    ///
+    /// The current algorithm is based on the quickselect portion of the same
+    /// quicksort algorithm used for [`sort_unstable`].
+    ///
+    /// # Panics
+    ///
+    /// Panics when `index >= len()`, meaning it always panics on empty slices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_select_nth_unstable)]
+    ///
+    /// let mut v = [-5i32, 4, 1, -3, 2];
+    ///
+    /// // Find the median
+    /// v.select_nth_unstable(2);
+    ///
+    /// // We are only guaranteed the slice will be one of the following,
+    /// // based on the way we sort about the specified index.
+    /// assert!(v == [-3, -5, 1, 2, 4] ||
+    ///         v == [-5, -3, 1, 2, 4] ||
+    ///         v == [-3, -5, 1, 4, 2] ||
+    ///         v == [-5, -3, 1, 4, 2]);
+    /// ```
+    ///
+    /// [`sort_unstable`]: #method.sort_unstable
+    #[unstable(feature = "slice_select_nth_unstable", issue = "0")]
+    #[inline]
+    pub fn select_nth_unstable(&mut self, index: usize) -> (&mut [T], &mut T, &mut [T])
+        where T: Ord
+    {
+        core_slice::SliceExt::select_nth_unstable(self, index)
+    }
+
+    /// Reorders the slice with a comparator function such that the element at
+    /// `index` is at its final sorted position.
+    ///
+    /// This reordering has the additional property that any value at position
+    /// `i < index` will be less than or equal to any value at a position
+    /// `j > index` using the comparator function. Additionally, this
+    /// reordering is unstable (i.e. any number of equal elements may end up at
+    /// position `index`), in-place (i.e. does not allocate), and `O(n)` on
+    /// average. The worst-case performance is `O(n log n)`.
+    ///
+    /// It returns a triplet of the following values: all elements less than
+    /// the one at the given index, the value at the given index, and all
+    /// elements greater than the one at the given index, using the provided
+    /// comparator function.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `index >= len()`, meaning it always panics on empty slices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_select_nth_unstable)]
+    ///
+    /// let mut v = [-5i32, 4, 1, -3, 2];
+    ///
+    /// // Find the median as if the slice were sorted in descending order.
+    /// v.select_nth_unstable_by(2, |a, b| b.cmp(a));
+    ///
+    /// // We are only guaranteed the slice will be one of the following,
+    /// // based on the way we sort about the specified index.
+    /// assert!(v == [2, 4, 1, -5, -3] ||
+    ///         v == [2, 4, 1, -3, -5] ||
+    ///         v == [4, 2, 1, -5, -3] ||
+    ///         v == [4, 2, 1, -3, -5]);
+    /// ```
+    #[unstable(feature = "slice_select_nth_unstable", issue = "0")]
+    #[inline]
+    pub fn select_nth_unstable_by<F>(&mut self, index: usize, compare: F)
+        -> (&mut [T], &mut T, &mut [T])
+        where F: FnMut(&T, &T) -> Ordering
+    {
+        core_slice::SliceExt::select_nth_unstable_by(self, index, compare)
+    }
+
+    /// Reorders the slice with a key extraction function such that the
+    /// element at `index` is at its final sorted position.
+    ///
+    /// This reordering has the additional property that any value at position
+    /// `i < index` will be less than or equal to any value at a position
+    /// `j > index` using the key extraction function. Additionally, this
+    /// reordering is unstable (i.e. any number of equal elements may end up at
+    /// position `index`), in-place (i.e. does not allocate), and `O(n)` on
+    /// average. The worst-case performance is `O(n log n)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `index >= len()`, meaning it always panics on empty slices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_select_nth_unstable)]
+    ///
+    /// let mut v = [-5i32, 4, 1, -3, 2];
+    ///
+    /// // Return the median as if the array were sorted according to absolute value.
+    /// v.select_nth_unstable_by_key(2, |a| a.abs());
+    ///
+    /// // We are only guaranteed the slice will be one of the following,
+    /// // based on the way we sort about the specified index.
+    /// assert!(v == [1, 2, -3, 4, -5] ||
+    ///         v == [1, 2, -3, -5, 4] ||
+    ///         v == [2, 1, -3, 4, -5] ||
+    ///         v == [2, 1, -3, -5, 4]);
+    /// ```
+    #[unstable(feature = "slice_select_nth_unstable", issue = "0")]
+    #[inline]
+    pub fn select_nth_unstable_by_key<B, F>(&mut self, index: usize, f: F)
+        -> (&mut [T], &mut T, &mut [T])
+        where F: FnMut(&T) -> B,
+              B: Ord
+    {
+        core_slice::SliceExt::select_nth_unstable_by_key(self, index, f)
+    }
+
+    /// Transmute the slice to a slice of another type, ensuring alignment of
+    /// the types is maintained.
+    ///
+    /// This method splits the slice into three distinct slices: prefix,
+    /// correctly aligned middle slice of a new type, and the suffix slice.
+    /// The middle slice will have the greatest length possible for a given
+    /// type and input slice.
+    ///
+    /// This method has no purpose when either input element `T` or output
+    /// element `U` are zero-sized and will return the original slice without
+    /// splitting anything.
+    ///
+    /// # Unsafety
+    ///
+    /// This method is essentially a `transmute` with respect to the elements
+    /// in the returned middle slice, so all the usual caveats pertaining to
+    /// `transmute::<T, U>` also apply here.
     ///
     /// # Examples
     ///
+    /// Basic usage:
+    ///
     /// ```
-    /// let mut v = [-5i32, 4, 1, -3, 2];
+    /// #![feature(slice_align_to)]
     ///
-    /// v.sort_by_key(|k| k.abs());
-    /// assert!(v == [1, 2, -3, 4, -5]);
+    /// unsafe {
+    ///     let bytes: [u8; 7] = [1, 2, 3, 4, 5, 6, 7];
+    ///     let (prefix, shorts, suffix) = bytes.align_to::<u16>();
+    ///     // less_efficient_algorithm_for_bytes(prefix);
+    ///     // more_efficient_algorithm_for_aligned_shorts(shorts);
+    ///     // less_efficient_algorithm_for_bytes(suffix);
+    ///     assert_eq!(prefix.len() + shorts.len() * 2 + suffix.len(), 7);
+    /// }
     /// ```
-    #[stable(feature = "slice_sort_by_key", since = "1.7.0")]
+    #[unstable(feature = "slice_align_to", issue = "0")]
     #[inline]
-    pub fn sort_by_key<B, F>(&mut self, mut f: F)
-        where F: FnMut(&T) -> B, B: Ord
-    {
-        merge_sort(self, |a, b| f(a).lt(&f(b)));
+    pub unsafe fn align_to<U>(&self) -> (&[T], &[U], &[T]) {
+        core_slice::SliceExt::align_to(self)
     }
 
-    /// Sorts the slice, but may not preserve the order of equal elements.
+    /// Transmute the slice to a slice of another type, ensuring alignment of
+    /// the types is maintained.
     ///
-    /// This sort is unstable (i.e. may reorder equal elements), in-place (i.e. does not allocate),
-    /// and `O(n log n)` worst-case.
+    /// This method splits the slice into three distinct slices: prefix,
+    /// correctly aligned middle slice of a new type, and the suffix slice.
+    /// The middle slice will have the greatest length possible for a given
+    /// type and input slice.
     ///
-    /// # Current implementation
+    /// This method has no purpose when either input element `T` or output
+    /// element `U` are zero-sized and will return the original slice without
+    /// splitting anything.
     ///
-    /// The current algorithm is based on [pattern-defeating quicksort][pdqsort] by Orson Peters,
-    /// which combines the fast average case of randomized quicksort with the fast worst case of
-    /// heapsort, while achieving linear time on slices with certain patterns. It uses some
-    /// randomization to avoid degenerate cases, but with a fixed seed to always provide
-    /// deterministic behavior.
+    /// # Unsafety
     ///
-    /// It is typically faster than stable sorting, except in a few special cases, e.g. when the
-    /// slice consists of several concatenated sorted sequences.
+    /// This method is essentially a `transmute` with respect to the elements
+    /// in the returned middle slice, so all the usual caveats pertaining to
+    /// `transmute::<T, U>` also apply here.
     ///
     /// # Examples
     ///
-    /// ```
-    /// let mut v = [-5, 4, 1, -3, 2];
+    /// Basic usage:
     ///
-    /// v.sort_unstable();
-    /// assert!(v == [-5, -3, 1, 2, 4]);
     /// ```
+    /// #![feature(slice_align_to)]
     ///
-    /// [pdqsort]: https://github.com/orlp/pdqsort
-    #[stable(feature = "sort_unstable", since = "1.20.0")]
+    /// unsafe {
+    ///     let mut bytes: [u8; 7] = [1, 2, 3, 4, 5, 6, 7];
+    ///     let (prefix, shorts, suffix) = bytes.align_to_mut::<u16>();
+    ///     // less_efficient_algorithm_for_bytes(prefix);
+    ///     // more_efficient_algorithm_for_aligned_shorts(shorts);
+    ///     // less_efficient_algorithm_for_bytes(suffix);
+    ///     for s in shorts.iter_mut() {
+    ///         *s = 0;
+    ///     }
+    ///     assert_eq!(prefix.len() + shorts.len() * 2 + suffix.len(), 7);
+    /// }
+    /// ```
+    #[unstable(feature = "slice_align_to", issue = "0")]
     #[inline]
-    pub fn sort_unstable(&mut self)
-        where T: Ord
-    {
-        core_slice::SliceExt::sort_unstable(self);
+    pub unsafe fn align_to_mut<U>(&mut self) -> (&mut [T], &mut [U], &mut [T]) {
+        core_slice::SliceExt::align_to_mut(self)
     }
 
-    /// Sorts the slice with a comparator function, but may not preserve the order of equal
-    /// elements.
+    /// Takes a `&[[U; N]]`, and flattens it to a `&[U]`.
     ///
-    /// This sort is unstable (i.e. may reorder equal elements), in-place (i.e. does not allocate),
-    /// and `O(n log n)` worst-case.
-    ///
-    /// # Current implementation
-    ///
-    /// The current algorithm is based on [pattern-defeating quicksort][pdqsort] by Orson Peters,
-    /// which combines the fast average case of randomized quicksort with the fast worst case of
-    /// heapsort, while achieving linear time on slices with certain patterns. It uses some
-    /// randomization to avoid degenerate cases, but with a fixed seed to always provide
-    /// deterministic behavior.
+    /// # Panics
     ///
-    /// It is typically faster than stable sorting, except in a few special cases, e.g. when the
-    /// slice consists of several concatenated sorted sequences.
+    /// This panics if the length of the resulting slice would overflow a
+    /// `usize`. This is only possible when flattening a slice of arrays of
+    /// zero-sized types, and thus tends to be irrelevant in practice.
     ///
     /// # Examples
     ///
     /// ```
-    /// let mut v = [5, 4, 1, 3, 2];
-    /// v.sort_unstable_by(|a, b| a.cmp(b));
-    /// assert!(v == [1, 2, 3, 4, 5]);
+    /// #![feature(slice_flatten)]
     ///
-    /// // reverse sorting
-    /// v.sort_unstable_by(|a, b| b.cmp(a));
-    /// assert!(v == [5, 4, 3, 2, 1]);
-    /// ```
+    /// assert_eq!([[1, 2, 3], [4, 5, 6]].as_flattened::<i32>(), &[1, 2, 3, 4, 5, 6]);
     ///
-    /// [pdqsort]: https://github.com/orlp/pdqsort
-    #[stable(feature = "sort_unstable", since = "1.20.0")]
+    /// assert_eq!(
+    ///     [[1, 2, 3], [4, 5, 6]].as_flattened::<i32>(),
+    ///     [[1, 2], [3, 4], [5, 6]].as_flattened::<i32>()
+    /// );
+    ///
+    /// let pixels: &[[f32; 4]] = &[[0.0, 0.5, 1.0, 1.0], [1.0, 1.0, 1.0, 0.0]];
+    /// let channels: &[f32] = pixels.as_flattened();
+    /// assert_eq!(channels.len(), 8);
+    /// ```
+    #[unstable(feature = "slice_flatten", issue = "0")]
     #[inline]
-    pub fn sort_unstable_by<F>(&mut self, compare: F)
-        where F: FnMut(&T, &T) -> Ordering
+    pub fn as_flattened<U>(&self) -> &[U]
+        where T: FixedSizeArray<U>
     {
-        core_slice::SliceExt::sort_unstable_by(self, compare);
+        core_slice::SliceExt::as_flattened(self)
     }
 
-    /// Sorts the slice with a key extraction function, but may not preserve the order of equal
-    /// elements.
-    ///
-    /// This sort is unstable (i.e. may reorder equal elements), in-place (i.e. does not allocate),
-    /// and `O(n log n)` worst-case.
+    /// Takes a `&mut [[U; N]]`, and flattens it to a `&mut [U]`.
     ///
-    /// # Current implementation
-    ///
-    /// The current algorithm is based on [pattern-defeating quicksort][pdqsort] by Orson Peters,
-    /// which combines the fast average case of randomized quicksort with the fast worst case of
-    /// heapsort, while achieving linear time on slices with certain patterns. It uses some
-    /// randomization to avoid degenerate cases, but with a fixed seed to always provide
-    /// deterministic behavior.
+    /// # Panics
     ///
-    /// It is typically faster than stable sorting, except in a few special cases, e.g. when the
-    /// slice consists of several concatenated sorted sequences.
+    /// This panics if the length of the resulting slice would overflow a
+    /// `usize`. This is only possible when flattening a slice of arrays of
+    /// zero-sized types, and thus tends to be irrelevant in practice.
     ///
     /// # Examples
     ///
     /// ```
-    /// let mut v = [-5i32, 4, 1, -3, 2];
+    /// #![feature(slice_flatten)]
     ///
-    /// v.sort_unstable_by_key(|k| k.abs());
-    /// assert!(v == [1, 2, -3, 4, -5]);
-    /// ```
+    /// fn add_5_to_all(slice: &mut [i32]) {
+    ///     for i in slice {
+    ///         *i += 5;
+    ///     }
+    /// }
     ///
-    /// [pdqsort]: https://github.com/orlp/pdqsort
-    #[stable(feature = "sort_unstable", since = "1.20.0")]
+    /// let mut array = [[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+    /// add_5_to_all(array.as_flattened_mut());
+    /// assert_eq!(array, [[6, 7, 8], [9, 10, 11], [12, 13, 14]]);
+    /// ```
+    #[unstable(feature = "slice_flatten", issue = "0")]
     #[inline]
-    pub fn sort_unstable_by_key<B, F>(&mut self, f: F)
-        where F: FnMut(&T) -> B,
-              B: Ord
+    pub fn as_flattened_mut<U>(&mut self) -> &mut [U]
+        where T: FixedSizeArray<U>
     {
-        core_slice::SliceExt::sort_unstable_by_key(self, f);
+        core_slice::SliceExt::as_flattened_mut(self)
     }
 
     /// Permutes the slice in-place such that `self[mid..]` moves to the
@@ -1461,6 +2910,297 @@ impl<T> [T] {
         core_slice::SliceExt::copy_from_slice(self, src)
     }
 
+    /// Fills `self` with elements by cloning `value`.
+    ///
+    /// For byte-sized `Copy` types this compiles down to a single memset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_fill)]
+    ///
+    /// let mut buf = vec![0; 10];
+    /// buf.fill(1);
+    /// assert_eq!(buf, vec![1; 10]);
+    /// ```
+    #[unstable(feature = "slice_fill", issue = "0")]
+    #[inline]
+    pub fn fill(&mut self, value: T) where T: Clone {
+        core_slice::SliceExt::fill(self, value)
+    }
+
+    /// Fills `self` with elements returned by calling a closure repeatedly.
+    ///
+    /// This method uses a closure to create new values. If you'd rather
+    /// [`Clone`] a given value, use [`fill`]. If you want to use the
+    /// [`Default`] trait to generate values, you can pass
+    /// `Default::default` as the argument.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_fill_with)]
+    ///
+    /// let mut buf = vec![1; 10];
+    /// buf.fill_with(Default::default);
+    /// assert_eq!(buf, vec![0; 10]);
+    /// ```
+    ///
+    /// [`Clone`]: ../../std/clone/trait.Clone.html
+    /// [`fill`]: #method.fill
+    /// [`Default`]: ../../std/default/trait.Default.html
+    #[unstable(feature = "slice_fill_with", issue = "0")]
+    #[inline]
+    pub fn fill_with<F>(&mut self, f: F) where F: FnMut() -> T {
+        core_slice::SliceExt::fill_with(self, f)
+    }
+
+    /// Copies elements from one part of the slice to another part of itself,
+    /// using a memmove.
+    ///
+    /// `src` is the range within `self` to copy from. `dest` is the starting
+    /// index of the range within `self` to copy to, which will have the same
+    /// length as `src`. The two ranges may overlap. The ends of the two ranges
+    /// must be less than or equal to `self.len()`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if either range exceeds the end of the slice,
+    /// or if the end of `src` is before the start.
+    ///
+    /// # Examples
+    ///
+    /// Copying four bytes within a slice:
+    ///
+    /// ```
+    /// #![feature(copy_within)]
+    ///
+    /// let mut bytes = *b"Hello, World!";
+    ///
+    /// bytes.copy_within(1..5, 8);
+    ///
+    /// assert_eq!(&bytes, b"Hello, Wello!");
+    /// ```
+    #[unstable(feature = "copy_within", issue = "0")]
+    pub fn copy_within<R>(&mut self, src: R, dest: usize)
+        where R: RangeArgument<usize>, T: Copy
+    {
+        let src_start = match src.start() {
+            Included(&n) => n,
+            Excluded(&n) => n.checked_add(1).expect("range start overflows usize"),
+            Unbounded    => 0,
+        };
+        let src_end = match src.end() {
+            Included(&n) => n.checked_add(1).expect("range end overflows usize"),
+            Excluded(&n) => n,
+            Unbounded    => self.len(),
+        };
+        core_slice::SliceExt::copy_within(self, src_start, src_end, dest)
+    }
+
+    /// Removes the subslice corresponding to the given range from the front
+    /// or back of `slice` and returns a reference to it, advancing `slice`
+    /// past it.
+    ///
+    /// Only one-sided ranges are accepted: `..n` and `...n` split off a
+    /// prefix, `n..` splits off a suffix and `..` takes the whole slice.
+    /// Returns `None` and leaves `slice` unchanged if the range is
+    /// bounded on both sides or if its bound is out of range.
+    ///
+    /// This is the "advance the input" step of a zero-copy parser.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_take)]
+    ///
+    /// let mut slice: &[_] = &['a', 'b', 'c', 'd'];
+    /// let first_three = <[char]>::split_off(&mut slice, ..3).unwrap();
+    ///
+    /// assert_eq!(slice, &['d']);
+    /// assert_eq!(first_three, &['a', 'b', 'c']);
+    /// ```
+    ///
+    /// Splitting off a suffix:
+    ///
+    /// ```
+    /// #![feature(slice_take)]
+    ///
+    /// let mut slice: &[_] = &['a', 'b', 'c', 'd'];
+    /// let tail = <[char]>::split_off(&mut slice, 2..).unwrap();
+    ///
+    /// assert_eq!(slice, &['a', 'b']);
+    /// assert_eq!(tail, &['c', 'd']);
+    /// ```
+    ///
+    /// Getting `None` when the range is out of bounds or two-sided:
+    ///
+    /// ```
+    /// #![feature(slice_take)]
+    ///
+    /// let mut slice: &[_] = &['a', 'b', 'c', 'd'];
+    ///
+    /// assert_eq!(None, <[char]>::split_off(&mut slice, 5..));
+    /// assert_eq!(None, <[char]>::split_off(&mut slice, ..5));
+    /// assert_eq!(None, <[char]>::split_off(&mut slice, 1..3));
+    /// assert_eq!(slice, &['a', 'b', 'c', 'd']);
+    /// ```
+    #[unstable(feature = "slice_take", issue = "0")]
+    pub fn split_off<'a, R>(slice: &mut &'a [T], range: R) -> Option<&'a [T]>
+        where R: RangeArgument<usize>
+    {
+        let (front, index) = match split_point_of(range, slice.len()) {
+            Some(split) => split,
+            None => return None,
+        };
+        if index > slice.len() {
+            return None;
+        }
+        let whole: &'a [T] = *slice;
+        let (head, tail) = whole.split_at(index);
+        if front {
+            *slice = tail;
+            Some(head)
+        } else {
+            *slice = head;
+            Some(tail)
+        }
+    }
+
+    /// Removes the subslice corresponding to the given range from the front
+    /// or back of `slice` and returns a mutable reference to it, advancing
+    /// `slice` past it.
+    ///
+    /// Accepts the same ranges as [`split_off`], and returns `None` in the
+    /// same cases, leaving `slice` unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_take)]
+    ///
+    /// let mut buf = ['a', 'b', 'c', 'd'];
+    /// let mut slice: &mut [_] = &mut buf;
+    /// {
+    ///     let first_three = <[char]>::split_off_mut(&mut slice, ..3).unwrap();
+    ///     first_three[0] = 'x';
+    /// }
+    /// slice[0] = 'z';
+    /// assert_eq!(buf, ['x', 'b', 'c', 'z']);
+    /// ```
+    ///
+    /// [`split_off`]: #method.split_off
+    #[unstable(feature = "slice_take", issue = "0")]
+    pub fn split_off_mut<'a, R>(slice: &mut &'a mut [T], range: R) -> Option<&'a mut [T]>
+        where R: RangeArgument<usize>
+    {
+        let (front, index) = match split_point_of(range, slice.len()) {
+            Some(split) => split,
+            None => return None,
+        };
+        if index > slice.len() {
+            return None;
+        }
+        let (head, tail) = mem::replace(slice, &mut []).split_at_mut(index);
+        if front {
+            *slice = tail;
+            Some(head)
+        } else {
+            *slice = head;
+            Some(tail)
+        }
+    }
+
+    /// Removes the first element of `slice` and returns a reference to it,
+    /// advancing `slice` past it.
+    ///
+    /// Returns `None` if the slice is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_take)]
+    ///
+    /// let mut slice: &[_] = &['a', 'b', 'c'];
+    /// let first = <[char]>::split_off_first(&mut slice).unwrap();
+    ///
+    /// assert_eq!(slice, &['b', 'c']);
+    /// assert_eq!(first, &'a');
+    /// ```
+    #[unstable(feature = "slice_take", issue = "0")]
+    pub fn split_off_first<'a>(slice: &mut &'a [T]) -> Option<&'a T> {
+        core_slice::SliceExt::split_off_first(slice)
+    }
+
+    /// Removes the first element of `slice` and returns a mutable reference
+    /// to it, advancing `slice` past it.
+    ///
+    /// Returns `None` if the slice is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_take)]
+    ///
+    /// let mut buf = ['a', 'b', 'c'];
+    /// {
+    ///     let mut slice: &mut [_] = &mut buf;
+    ///     let first = <[char]>::split_off_first_mut(&mut slice).unwrap();
+    ///     *first = 'd';
+    ///     assert_eq!(slice, &['b', 'c']);
+    /// }
+    /// assert_eq!(buf, ['d', 'b', 'c']);
+    /// ```
+    #[unstable(feature = "slice_take", issue = "0")]
+    pub fn split_off_first_mut<'a>(slice: &mut &'a mut [T]) -> Option<&'a mut T> {
+        core_slice::SliceExt::split_off_first_mut(slice)
+    }
+
+    /// Removes the last element of `slice` and returns a reference to it,
+    /// shrinking `slice` from the back.
+    ///
+    /// Returns `None` if the slice is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_take)]
+    ///
+    /// let mut slice: &[_] = &['a', 'b', 'c'];
+    /// let last = <[char]>::split_off_last(&mut slice).unwrap();
+    ///
+    /// assert_eq!(slice, &['a', 'b']);
+    /// assert_eq!(last, &'c');
+    /// ```
+    #[unstable(feature = "slice_take", issue = "0")]
+    pub fn split_off_last<'a>(slice: &mut &'a [T]) -> Option<&'a T> {
+        core_slice::SliceExt::split_off_last(slice)
+    }
+
+    /// Removes the last element of `slice` and returns a mutable reference
+    /// to it, shrinking `slice` from the back.
+    ///
+    /// Returns `None` if the slice is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_take)]
+    ///
+    /// let mut buf = ['a', 'b', 'c'];
+    /// {
+    ///     let mut slice: &mut [_] = &mut buf;
+    ///     let last = <[char]>::split_off_last_mut(&mut slice).unwrap();
+    ///     *last = 'd';
+    ///     assert_eq!(slice, &['a', 'b']);
+    /// }
+    /// assert_eq!(buf, ['a', 'b', 'd']);
+    /// ```
+    #[unstable(feature = "slice_take", issue = "0")]
+    pub fn split_off_last_mut<'a>(slice: &mut &'a mut [T]) -> Option<&'a mut T> {
+        core_slice::SliceExt::split_off_last_mut(slice)
+    }
+
     /// Copies `self` into a new `Vec`.
     ///
     /// # Examples
@@ -1501,6 +3241,92 @@ impl<T> [T] {
     }
 }
 
+/// Converts a one-sided range into the index at which `split_off` divides a
+/// slice of length `len`, along with whether the prefix (`true`) or the
+/// suffix (`false`) is the part being split off. Returns `None` for ranges
+/// bounded on both sides.
+fn split_point_of<R: RangeArgument<usize>>(range: R, len: usize) -> Option<(bool, usize)> {
+    match (range.start(), range.end()) {
+        (Unbounded, Excluded(&n)) => Some((true, n)),
+        (Unbounded, Included(&n)) => n.checked_add(1).map(|n| (true, n)),
+        (Unbounded, Unbounded) => Some((true, len)),
+        (Included(&n), Unbounded) => Some((false, n)),
+        (Excluded(&n), Unbounded) => n.checked_add(1).map(|n| (false, n)),
+        _ => None,
+    }
+}
+
+#[lang = "slice_u8"]
+#[cfg(not(stage0))]
+#[cfg(not(test))]
+impl [u8] {
+    /// Returns a byte slice with leading ASCII whitespace bytes removed.
+    ///
+    /// 'Whitespace' refers to the definition used by
+    /// `AsciiExt::is_ascii_whitespace`: space, horizontal tab, line feed, form
+    /// feed and carriage return.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(byte_slice_trim_ascii)]
+    ///
+    /// assert_eq!(b" \t hello world\n".trim_ascii_start(), b"hello world\n");
+    /// assert_eq!(b"  ".trim_ascii_start(), b"");
+    /// assert_eq!(b"".trim_ascii_start(), b"");
+    /// ```
+    #[unstable(feature = "byte_slice_trim_ascii", issue = "0")]
+    #[inline]
+    pub fn trim_ascii_start(&self) -> &[u8] {
+        let start = self.iter().position(|b| !b.is_ascii_whitespace()).unwrap_or(self.len());
+        &self[start..]
+    }
+
+    /// Returns a byte slice with trailing ASCII whitespace bytes removed.
+    ///
+    /// 'Whitespace' refers to the definition used by
+    /// `AsciiExt::is_ascii_whitespace`: space, horizontal tab, line feed, form
+    /// feed and carriage return.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(byte_slice_trim_ascii)]
+    ///
+    /// assert_eq!(b"\r hello world\n ".trim_ascii_end(), b"\r hello world");
+    /// assert_eq!(b"  ".trim_ascii_end(), b"");
+    /// assert_eq!(b"".trim_ascii_end(), b"");
+    /// ```
+    #[unstable(feature = "byte_slice_trim_ascii", issue = "0")]
+    #[inline]
+    pub fn trim_ascii_end(&self) -> &[u8] {
+        let end = self.iter().rposition(|b| !b.is_ascii_whitespace()).map_or(0, |i| i + 1);
+        &self[..end]
+    }
+
+    /// Returns a byte slice with leading and trailing ASCII whitespace bytes
+    /// removed.
+    ///
+    /// 'Whitespace' refers to the definition used by
+    /// `AsciiExt::is_ascii_whitespace`: space, horizontal tab, line feed, form
+    /// feed and carriage return.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(byte_slice_trim_ascii)]
+    ///
+    /// assert_eq!(b"\r hello world\n ".trim_ascii(), b"hello world");
+    /// assert_eq!(b"  ".trim_ascii(), b"");
+    /// assert_eq!(b"".trim_ascii(), b"");
+    /// ```
+    #[unstable(feature = "byte_slice_trim_ascii", issue = "0")]
+    #[inline]
+    pub fn trim_ascii(&self) -> &[u8] {
+        self.trim_ascii_start().trim_ascii_end()
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Extension traits for slices over specific kinds of data
 ////////////////////////////////////////////////////////////////////////////////
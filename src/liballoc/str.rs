@@ -38,6 +38,7 @@
 // It's cleaner to just turn off the unused_imports warning than to fix them.
 #![allow(unused_imports)]
 
+use core::ascii;
 use core::fmt;
 use core::str as core_str;
 use core::str::pattern::Pattern;
@@ -61,6 +62,8 @@ pub use core::str::{FromStr, Utf8Error};
 pub use core::str::{Lines, LinesAny};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::str::{Split, RSplit};
+#[unstable(feature = "split_inclusive", issue = "0")]
+pub use core::str::SplitInclusive;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::str::{SplitN, RSplitN};
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -73,8 +76,12 @@ pub use core::str::{MatchIndices, RMatchIndices};
 pub use core::str::{from_utf8, from_utf8_mut, Chars, CharIndices, Bytes};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::str::{from_utf8_unchecked, from_utf8_unchecked_mut, ParseBoolError};
+#[unstable(feature = "utf8_chunks", issue = "0")]
+pub use core::str::{utf8_chunks, Utf8Chunk, Utf8Chunks};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use std_unicode::str::SplitWhitespace;
+#[unstable(feature = "split_ascii_whitespace", issue = "0")]
+pub use core::str::SplitAsciiWhitespace;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::str::pattern;
 
@@ -273,6 +280,64 @@ impl str {
         core_str::StrExt::is_char_boundary(self, index)
     }
 
+    /// Finds the closest `x` not exceeding `index` where `is_char_boundary(x)`
+    /// is `true`.
+    ///
+    /// This method can help you truncate a string so that it's still valid
+    /// UTF-8, but doesn't exceed a given number of bytes. Note that this is
+    /// done purely at the character level and can still visually split
+    /// graphemes, even though the underlying characters aren't split.
+    ///
+    /// If `index` is greater than the length of the string, this returns the
+    /// length of the string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(round_char_boundary)]
+    /// let s = "❤️🧡💛💚💙💜";
+    /// assert_eq!(s.len(), 26);
+    /// assert!(!s.is_char_boundary(13));
+    ///
+    /// let closest = s.floor_char_boundary(13);
+    /// assert_eq!(closest, 10);
+    /// assert_eq!(&s[..closest], "❤️🧡");
+    /// ```
+    #[unstable(feature = "round_char_boundary", issue = "0")]
+    #[inline]
+    pub fn floor_char_boundary(&self, index: usize) -> usize {
+        core_str::StrExt::floor_char_boundary(self, index)
+    }
+
+    /// Finds the closest `x` not below `index` where `is_char_boundary(x)` is
+    /// `true`.
+    ///
+    /// If `index` is greater than the length of the string, this returns the
+    /// length of the string.
+    ///
+    /// This method is the natural complement to [`floor_char_boundary`]. See
+    /// that method for more details.
+    ///
+    /// [`floor_char_boundary`]: #method.floor_char_boundary
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(round_char_boundary)]
+    /// let s = "❤️🧡💛💚💙💜";
+    /// assert_eq!(s.len(), 26);
+    /// assert!(!s.is_char_boundary(13));
+    ///
+    /// let closest = s.ceil_char_boundary(13);
+    /// assert_eq!(closest, 14);
+    /// assert_eq!(&s[..closest], "❤️🧡💛");
+    /// ```
+    #[unstable(feature = "round_char_boundary", issue = "0")]
+    #[inline]
+    pub fn ceil_char_boundary(&self, index: usize) -> usize {
+        core_str::StrExt::ceil_char_boundary(self, index)
+    }
+
     /// Converts a string slice to a byte slice. To convert the byte slice back
     /// into a string slice, use the [`str::from_utf8`] function.
     ///
@@ -292,6 +357,27 @@ impl str {
         core_str::StrExt::as_bytes(self)
     }
 
+    /// If this string slice `is_ascii`, returns it as a slice of
+    /// [ASCII characters](ascii/struct.Char.html), otherwise returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_char)]
+    /// use std::ascii::Char;
+    ///
+    /// let method = "GET".as_ascii().unwrap();
+    /// assert_eq!(method.len(), 3);
+    /// assert_eq!(Char::slice_as_str(method), "GET");
+    ///
+    /// assert_eq!("Grüße".as_ascii(), None);
+    /// ```
+    #[unstable(feature = "ascii_char", issue = "0")]
+    #[inline]
+    pub fn as_ascii(&self) -> Option<&[ascii::Char]> {
+        core_str::StrExt::as_ascii(self)
+    }
+
     /// Converts a mutable string slice to a mutable byte slice. To convert the
     /// mutable byte slice back into a mutable string slice, use the
     /// [`str::from_utf8_mut`] function.
@@ -766,6 +852,50 @@ impl str {
         UnicodeStr::split_whitespace(self)
     }
 
+    /// Splits a string slice by ASCII whitespace.
+    ///
+    /// The iterator returned will return string slices that are sub-slices of
+    /// the original string slice, separated by any amount of ASCII whitespace.
+    ///
+    /// To split by Unicode `Whitespace` instead, use [`split_whitespace`].
+    /// Unlike it, this doesn't need the Unicode property tables, so it's
+    /// faster and available in `core`.
+    ///
+    /// [`split_whitespace`]: #method.split_whitespace
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(split_ascii_whitespace)]
+    /// let mut iter = "A few words".split_ascii_whitespace();
+    ///
+    /// assert_eq!(Some("A"), iter.next());
+    /// assert_eq!(Some("few"), iter.next());
+    /// assert_eq!(Some("words"), iter.next());
+    ///
+    /// assert_eq!(None, iter.next());
+    /// ```
+    ///
+    /// Only ASCII whitespace is considered:
+    ///
+    /// ```
+    /// #![feature(split_ascii_whitespace)]
+    /// let mut iter = " Mary   had\ta\u{2009}little  \n\t lamb".split_ascii_whitespace();
+    /// assert_eq!(Some("Mary"), iter.next());
+    /// assert_eq!(Some("had"), iter.next());
+    /// assert_eq!(Some("a\u{2009}little"), iter.next());
+    /// assert_eq!(Some("lamb"), iter.next());
+    ///
+    /// assert_eq!(None, iter.next());
+    /// ```
+    #[unstable(feature = "split_ascii_whitespace", issue = "0")]
+    #[inline]
+    pub fn split_ascii_whitespace(&self) -> SplitAsciiWhitespace {
+        core_str::StrExt::split_ascii_whitespace(self)
+    }
+
     /// An iterator over the lines of a string, as string slices.
     ///
     /// Lines are ended with either a newline (`\n`) or a carriage return with
@@ -1091,6 +1221,46 @@ impl str {
         core_str::StrExt::split(self, pat)
     }
 
+    /// An iterator over substrings of this string slice, separated by
+    /// characters matched by a pattern. Differs from the iterator produced by
+    /// `split` in that `split_inclusive` leaves the matched part as the
+    /// terminator of the substring.
+    ///
+    /// The pattern can be a `&str`, [`char`], or a closure that
+    /// determines the split.
+    ///
+    /// If the last element of the string is matched,
+    /// that element will be considered the terminator of the preceding substring.
+    /// That substring will be the last item returned by the iterator.
+    ///
+    /// [`char`]: primitive.char.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(split_inclusive)]
+    /// let v: Vec<&str> = "Mary had a little lamb\nlittle lamb\nlittle lamb."
+    ///     .split_inclusive('\n').collect();
+    /// assert_eq!(v, ["Mary had a little lamb\n", "little lamb\n", "little lamb."]);
+    ///
+    /// let v: Vec<&str> = "Mary had a little lamb\nlittle lamb\nlittle lamb.\n"
+    ///     .split_inclusive('\n').collect();
+    /// assert_eq!(v, ["Mary had a little lamb\n", "little lamb\n", "little lamb.\n"]);
+    /// ```
+    ///
+    /// Line terminators are preserved exactly:
+    ///
+    /// ```
+    /// #![feature(split_inclusive)]
+    /// let v: Vec<&str> = "one\r\ntwo\nthree".split_inclusive('\n').collect();
+    /// assert_eq!(v, ["one\r\n", "two\n", "three"]);
+    /// ```
+    #[unstable(feature = "split_inclusive", issue = "0")]
+    #[inline]
+    pub fn split_inclusive<'a, P: Pattern<'a>>(&'a self, pat: P) -> SplitInclusive<'a, P> {
+        core_str::StrExt::split_inclusive(self, pat)
+    }
+
     /// An iterator over substrings of the given string slice, separated by
     /// characters matched by a pattern and yielded in reverse order.
     ///
@@ -1335,6 +1505,61 @@ impl str {
         core_str::StrExt::rsplitn(self, n, pat)
     }
 
+    /// Splits the string on the first occurrence of the specified delimiter and
+    /// returns prefix before delimiter and suffix after delimiter.
+    ///
+    /// Returns `None` if the delimiter is not found.
+    ///
+    /// The delimiter can be a `&str`, [`char`], or a closure that
+    /// determines the split.
+    ///
+    /// [`char`]: primitive.char.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_split_once)]
+    ///
+    /// assert_eq!("cfg".split_once('='), None);
+    /// assert_eq!("cfg=".split_once('='), Some(("cfg", "")));
+    /// assert_eq!("cfg=foo".split_once('='), Some(("cfg", "foo")));
+    /// assert_eq!("cfg=foo=bar".split_once('='), Some(("cfg", "foo=bar")));
+    /// assert_eq!("key: value".split_once(": "), Some(("key", "value")));
+    /// ```
+    #[unstable(feature = "str_split_once", issue = "0")]
+    #[inline]
+    pub fn split_once<'a, P: Pattern<'a>>(&'a self, delimiter: P) -> Option<(&'a str, &'a str)> {
+        core_str::StrExt::split_once(self, delimiter)
+    }
+
+    /// Splits the string on the last occurrence of the specified delimiter and
+    /// returns prefix before delimiter and suffix after delimiter.
+    ///
+    /// Returns `None` if the delimiter is not found.
+    ///
+    /// The delimiter can be a `&str`, [`char`], or a closure that
+    /// determines the split.
+    ///
+    /// [`char`]: primitive.char.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_split_once)]
+    ///
+    /// assert_eq!("cfg".rsplit_once('='), None);
+    /// assert_eq!("cfg=foo".rsplit_once('='), Some(("cfg", "foo")));
+    /// assert_eq!("cfg=foo=bar".rsplit_once('='), Some(("cfg=foo", "bar")));
+    /// assert_eq!("a.tar.gz".rsplit_once(|c| c == '.'), Some(("a.tar", "gz")));
+    /// ```
+    #[unstable(feature = "str_split_once", issue = "0")]
+    #[inline]
+    pub fn rsplit_once<'a, P>(&'a self, delimiter: P) -> Option<(&'a str, &'a str)>
+        where P: Pattern<'a>, P::Searcher: ReverseSearcher<'a>
+    {
+        core_str::StrExt::rsplit_once(self, delimiter)
+    }
+
     /// An iterator over the disjoint matches of a pattern within the given string
     /// slice.
     ///
@@ -1593,6 +1818,79 @@ impl str {
         UnicodeStr::trim_right(self)
     }
 
+    /// Returns a string slice with leading ASCII whitespace removed.
+    ///
+    /// 'Whitespace' refers to the definition used by
+    /// `AsciiExt::is_ascii_whitespace`: space, horizontal tab, line feed, form
+    /// feed and carriage return. Unlike [`trim_left`], no Unicode property
+    /// tables are consulted.
+    ///
+    /// [`trim_left`]: #method.trim_left
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(byte_slice_trim_ascii)]
+    ///
+    /// assert_eq!(" \t \u{3000}hello world\n".trim_ascii_start(), "\u{3000}hello world\n");
+    /// assert_eq!("  ".trim_ascii_start(), "");
+    /// assert_eq!("".trim_ascii_start(), "");
+    /// ```
+    #[unstable(feature = "byte_slice_trim_ascii", issue = "0")]
+    #[inline]
+    pub fn trim_ascii_start(&self) -> &str {
+        core_str::StrExt::trim_ascii_start(self)
+    }
+
+    /// Returns a string slice with trailing ASCII whitespace removed.
+    ///
+    /// 'Whitespace' refers to the definition used by
+    /// `AsciiExt::is_ascii_whitespace`: space, horizontal tab, line feed, form
+    /// feed and carriage return. Unlike [`trim_right`], no Unicode property
+    /// tables are consulted.
+    ///
+    /// [`trim_right`]: #method.trim_right
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(byte_slice_trim_ascii)]
+    ///
+    /// assert_eq!("\r hello world\u{3000}\n ".trim_ascii_end(), "\r hello world\u{3000}");
+    /// assert_eq!("  ".trim_ascii_end(), "");
+    /// assert_eq!("".trim_ascii_end(), "");
+    /// ```
+    #[unstable(feature = "byte_slice_trim_ascii", issue = "0")]
+    #[inline]
+    pub fn trim_ascii_end(&self) -> &str {
+        core_str::StrExt::trim_ascii_end(self)
+    }
+
+    /// Returns a string slice with leading and trailing ASCII whitespace
+    /// removed.
+    ///
+    /// 'Whitespace' refers to the definition used by
+    /// `AsciiExt::is_ascii_whitespace`: space, horizontal tab, line feed, form
+    /// feed and carriage return. Unlike [`trim`], no Unicode property
+    /// tables are consulted.
+    ///
+    /// [`trim`]: #method.trim
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(byte_slice_trim_ascii)]
+    ///
+    /// assert_eq!("\r hello world\n ".trim_ascii(), "hello world");
+    /// assert_eq!("  ".trim_ascii(), "");
+    /// assert_eq!("".trim_ascii(), "");
+    /// ```
+    #[unstable(feature = "byte_slice_trim_ascii", issue = "0")]
+    #[inline]
+    pub fn trim_ascii(&self) -> &str {
+        core_str::StrExt::trim_ascii(self)
+    }
+
     /// Returns a string slice with all prefixes and suffixes that match a
     /// pattern repeatedly removed.
     ///
@@ -1695,6 +1993,68 @@ impl str {
         core_str::StrExt::trim_right_matches(self, pat)
     }
 
+    /// Returns a string slice with the prefix removed.
+    ///
+    /// If the string starts with the pattern `prefix`, returns substring after
+    /// the prefix, wrapped in `Some`. Unlike [`trim_left_matches`], this
+    /// method removes the prefix exactly once.
+    ///
+    /// If the string does not start with `prefix`, returns `None`.
+    ///
+    /// The pattern can be a `&str`, [`char`], or a closure that
+    /// determines if a character matches.
+    ///
+    /// [`char`]: primitive.char.html
+    /// [`trim_left_matches`]: #method.trim_left_matches
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_strip)]
+    ///
+    /// assert_eq!("foo:bar".strip_prefix("foo:"), Some("bar"));
+    /// assert_eq!("foo:bar".strip_prefix("bar"), None);
+    /// assert_eq!("foofoo".strip_prefix("foo"), Some("foo"));
+    /// assert_eq!("ümlaut".strip_prefix('ü'), Some("mlaut"));
+    /// ```
+    #[unstable(feature = "str_strip", issue = "0")]
+    #[inline]
+    pub fn strip_prefix<'a, P: Pattern<'a>>(&'a self, prefix: P) -> Option<&'a str> {
+        core_str::StrExt::strip_prefix(self, prefix)
+    }
+
+    /// Returns a string slice with the suffix removed.
+    ///
+    /// If the string ends with the pattern `suffix`, returns the substring
+    /// before the suffix, wrapped in `Some`. Unlike [`trim_right_matches`],
+    /// this method removes the suffix exactly once.
+    ///
+    /// If the string does not end with `suffix`, returns `None`.
+    ///
+    /// The pattern can be a `&str`, [`char`], or a closure that
+    /// determines if a character matches.
+    ///
+    /// [`char`]: primitive.char.html
+    /// [`trim_right_matches`]: #method.trim_right_matches
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_strip)]
+    ///
+    /// assert_eq!("bar:foo".strip_suffix(":foo"), Some("bar"));
+    /// assert_eq!("bar:foo".strip_suffix("bar"), None);
+    /// assert_eq!("foofoo".strip_suffix("foo"), Some("foo"));
+    /// assert_eq!("naïve".strip_suffix(char::is_alphabetic), Some("naïv"));
+    /// ```
+    #[unstable(feature = "str_strip", issue = "0")]
+    #[inline]
+    pub fn strip_suffix<'a, P>(&'a self, suffix: P) -> Option<&'a str>
+        where P: Pattern<'a>, P::Searcher: ReverseSearcher<'a>
+    {
+        core_str::StrExt::strip_suffix(self, suffix)
+    }
+
     /// Parses this string slice into another type.
     ///
     /// Because `parse` is so general, it can cause problems with type
@@ -11,23 +11,42 @@
 #![deny(warnings)]
 
 #![feature(alloc)]
+#![feature(array_chunks)]
+#![feature(array_windows)]
 #![feature(attr_literals)]
 #![feature(box_syntax)]
+#![feature(byte_slice_trim_ascii)]
+#![feature(chunks_exact)]
 #![feature(inclusive_range_syntax)]
 #![feature(collection_placement)]
 #![feature(const_fn)]
+#![feature(copy_within)]
 #![feature(exact_size_is_empty)]
+#![feature(get_many_mut)]
 #![feature(iterator_step_by)]
 #![feature(pattern)]
 #![feature(placement_in_syntax)]
 #![feature(rand)]
 #![feature(repr_align)]
+#![feature(round_char_boundary)]
+#![feature(slice_as_chunks)]
+#![feature(slice_fill)]
+#![feature(slice_fill_with)]
+#![feature(slice_first_last_chunk)]
+#![feature(slice_flatten)]
+#![feature(slice_group_by)]
 #![feature(slice_rotate)]
+#![feature(slice_take)]
 #![feature(splice)]
+#![feature(split_ascii_whitespace)]
+#![feature(split_inclusive)]
 #![feature(str_escape)]
+#![feature(str_split_once)]
+#![feature(str_strip)]
 #![feature(test)]
 #![feature(unboxed_closures)]
 #![feature(unicode)]
+#![feature(utf8_chunks)]
 
 extern crate alloc;
 extern crate test;
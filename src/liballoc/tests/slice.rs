@@ -794,6 +794,55 @@ fn test_splitator() {
     assert_eq!(xs.split(|x| *x == 5).collect::<Vec<&[i32]>>(), splits);
 }
 
+#[test]
+fn test_split_inclusive() {
+    let xs = &[1, 2, 3, 4, 5];
+
+    let splits: &[&[_]] = &[&[1, 2], &[3, 4], &[5]];
+    assert_eq!(xs.split_inclusive(|x| *x % 2 == 0).collect::<Vec<_>>(), splits);
+    let splits: &[&[_]] = &[&[1], &[2, 3, 4, 5]];
+    assert_eq!(xs.split_inclusive(|x| *x == 1).collect::<Vec<_>>(), splits);
+    let splits: &[&[_]] = &[&[1, 2, 3, 4, 5]];
+    assert_eq!(xs.split_inclusive(|x| *x == 5).collect::<Vec<_>>(), splits);
+    let splits: &[&[_]] = &[&[1, 2, 3, 4, 5]];
+    assert_eq!(xs.split_inclusive(|x| *x == 10).collect::<Vec<_>>(), splits);
+    let splits: &[&[_]] = &[&[1], &[2], &[3], &[4], &[5]];
+    assert_eq!(xs.split_inclusive(|_| true).collect::<Vec<&[i32]>>(), splits);
+
+    let splits: &[&[_]] = &[&[5], &[3, 4], &[1, 2]];
+    assert_eq!(xs.split_inclusive(|x| *x % 2 == 0).rev().collect::<Vec<_>>(), splits);
+    let splits: &[&[_]] = &[&[1, 2, 3, 4, 5]];
+    assert_eq!(xs.split_inclusive(|x| *x == 5).rev().collect::<Vec<_>>(), splits);
+
+    let mut iter = xs.split_inclusive(|x| *x % 2 == 0);
+    assert_eq!(iter.next(), Some(&[1, 2][..]));
+    assert_eq!(iter.next_back(), Some(&[5][..]));
+    assert_eq!(iter.next_back(), Some(&[3, 4][..]));
+    assert_eq!(iter.next(), None);
+
+    let xs: &[i32] = &[];
+    assert_eq!(xs.split_inclusive(|x| *x == 5).next(), None);
+    assert_eq!(xs.split_inclusive(|x| *x == 5).next_back(), None);
+}
+
+#[test]
+fn test_split_inclusive_mut() {
+    let xs = &mut [1, 2, 3, 4, 5];
+
+    let splits: &[&mut [_]] = &[&mut [1, 2], &mut [3, 4], &mut [5]];
+    assert_eq!(xs.split_inclusive_mut(|x| *x % 2 == 0).collect::<Vec<_>>(), splits);
+    let splits: &[&mut [_]] = &[&mut [5], &mut [3, 4], &mut [1, 2]];
+    assert_eq!(xs.split_inclusive_mut(|x| *x % 2 == 0).rev().collect::<Vec<_>>(), splits);
+
+    for group in xs.split_inclusive_mut(|x| *x % 2 == 0) {
+        group[0] = 0;
+    }
+    assert_eq!(*xs, [0, 2, 0, 4, 0]);
+
+    let xs: &mut [i32] = &mut [];
+    assert_eq!(xs.split_inclusive_mut(|x| *x == 5).next(), None);
+}
+
 #[test]
 fn test_splitnator() {
     let xs = &[1, 2, 3, 4, 5];
@@ -909,6 +958,210 @@ fn test_chunksator_0() {
     let _it = v.chunks(0);
 }
 
+#[test]
+fn test_chunks_exactator() {
+    let v = &[1, 2, 3, 4, 5];
+
+    assert_eq!(v.chunks_exact(2).len(), 2);
+
+    let chunks: &[&[_]] = &[&[1, 2], &[3, 4]];
+    assert_eq!(v.chunks_exact(2).collect::<Vec<_>>(), chunks);
+    let chunks: &[&[_]] = &[&[1, 2, 3]];
+    assert_eq!(v.chunks_exact(3).collect::<Vec<_>>(), chunks);
+    let chunks: &[&[_]] = &[];
+    assert_eq!(v.chunks_exact(6).collect::<Vec<_>>(), chunks);
+
+    let chunks: &[&[_]] = &[&[3, 4], &[1, 2]];
+    assert_eq!(v.chunks_exact(2).rev().collect::<Vec<_>>(), chunks);
+
+    let mut it = v.chunks_exact(2);
+    assert_eq!(it.remainder(), &[5]);
+    assert_eq!(it.nth(1).unwrap(), &[3, 4]);
+    assert_eq!(it.next(), None);
+    assert_eq!(it.remainder(), &[5]);
+}
+
+#[test]
+#[should_panic]
+fn test_chunks_exactator_0() {
+    let v = &[1, 2, 3, 4];
+    let _it = v.chunks_exact(0);
+}
+
+#[test]
+fn test_rchunks_exactator() {
+    let v = &[1, 2, 3, 4, 5];
+
+    assert_eq!(v.rchunks_exact(2).len(), 2);
+
+    let chunks: &[&[_]] = &[&[4, 5], &[2, 3]];
+    assert_eq!(v.rchunks_exact(2).collect::<Vec<_>>(), chunks);
+    let chunks: &[&[_]] = &[&[3, 4, 5]];
+    assert_eq!(v.rchunks_exact(3).collect::<Vec<_>>(), chunks);
+    let chunks: &[&[_]] = &[];
+    assert_eq!(v.rchunks_exact(6).collect::<Vec<_>>(), chunks);
+
+    let chunks: &[&[_]] = &[&[2, 3], &[4, 5]];
+    assert_eq!(v.rchunks_exact(2).rev().collect::<Vec<_>>(), chunks);
+
+    let mut it = v.rchunks_exact(2);
+    assert_eq!(it.remainder(), &[1]);
+    assert_eq!(it.nth(1).unwrap(), &[2, 3]);
+    assert_eq!(it.next(), None);
+}
+
+#[test]
+fn test_as_chunks() {
+    let v = &[1, 2, 3, 4, 5];
+
+    let (chunks, rem) = v.as_chunks::<[i32; 2]>();
+    assert_eq!(chunks, &[[1, 2], [3, 4]]);
+    assert_eq!(rem, &[5]);
+
+    let (rem, chunks) = v.as_rchunks::<[i32; 2]>();
+    assert_eq!(rem, &[1]);
+    assert_eq!(chunks, &[[2, 3], [4, 5]]);
+
+    let (chunks, rem) = v.as_chunks::<[i32; 6]>();
+    assert!(chunks.is_empty());
+    assert_eq!(rem, v);
+
+    let zsts = [(); 7];
+    let (chunks, rem) = zsts.as_chunks::<[(); 3]>();
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(rem.len(), 1);
+}
+
+#[test]
+#[should_panic]
+fn test_as_chunks_0() {
+    let v = &[1, 2, 3, 4];
+    let _ = v.as_chunks::<[i32; 0]>();
+}
+
+#[test]
+fn test_split_first_last_chunk() {
+    let v = &[1, 2, 3, 4, 5];
+
+    assert_eq!(v.split_first_chunk::<[i32; 2]>(), Some((&[1, 2], &[3, 4, 5][..])));
+    assert_eq!(v.split_first_chunk::<[i32; 5]>(), Some((&[1, 2, 3, 4, 5], &[][..])));
+    assert_eq!(v.split_first_chunk::<[i32; 6]>(), None);
+
+    assert_eq!(v.split_last_chunk::<[i32; 2]>(), Some((&[1, 2, 3][..], &[4, 5])));
+    assert_eq!(v.split_last_chunk::<[i32; 5]>(), Some((&[][..], &[1, 2, 3, 4, 5])));
+    assert_eq!(v.split_last_chunk::<[i32; 6]>(), None);
+
+    let (empty, rest) = v.split_first_chunk::<[i32; 0]>().unwrap();
+    assert_eq!(empty.len(), 0);
+    assert_eq!(rest, v);
+
+    let mut m = [1, 2, 3, 4, 5];
+    {
+        let (first, rest) = m.split_first_chunk_mut::<[i32; 2]>().unwrap();
+        *first = [9, 8];
+        rest[0] = 7;
+    }
+    {
+        let (_, last) = m.split_last_chunk_mut::<[i32; 2]>().unwrap();
+        last.swap(0, 1);
+    }
+    assert_eq!(m, [9, 8, 7, 5, 4]);
+    assert!(m.split_last_chunk_mut::<[i32; 6]>().is_none());
+}
+
+#[test]
+fn test_as_flattened() {
+    let v: &[[i32; 3]] = &[[1, 2, 3], [4, 5, 6]];
+    assert_eq!(v.as_flattened::<i32>(), &[1, 2, 3, 4, 5, 6]);
+
+    let empty: &[[i32; 3]] = &[];
+    assert!(empty.as_flattened::<i32>().is_empty());
+
+    let zsts = [[(); 4]; 3];
+    assert_eq!(zsts.as_flattened::<()>().len(), 12);
+
+    let mut m = [[1, 2], [3, 4]];
+    m.as_flattened_mut::<i32>()[1] = 7;
+    assert_eq!(m, [[1, 7], [3, 4]]);
+
+    // The chunked and flattened views round-trip.
+    let (chunks, rem) = v.as_flattened::<i32>().as_chunks::<[i32; 2]>();
+    assert!(rem.is_empty());
+    assert_eq!(chunks.as_flattened::<i32>(), v.as_flattened::<i32>());
+}
+
+#[test]
+#[should_panic(expected = "slice len overflow")]
+fn test_as_flattened_zst_overflow() {
+    let zsts = [[(); 4]; ::std::usize::MAX / 2];
+    let _ = zsts.as_flattened::<()>();
+}
+
+#[test]
+fn test_array_chunks() {
+    let v = &[1, 2, 3, 4, 5];
+
+    assert_eq!(v.array_chunks::<[i32; 2]>().len(), 2);
+    assert_eq!(v.array_chunks::<[i32; 2]>().collect::<Vec<_>>(), [&[1, 2], &[3, 4]]);
+    assert_eq!(v.array_chunks::<[i32; 2]>().rev().collect::<Vec<_>>(), [&[3, 4], &[1, 2]]);
+    assert_eq!(v.array_chunks::<[i32; 3]>().remainder(), &[4, 5]);
+    assert_eq!(v.array_chunks::<[i32; 6]>().next(), None);
+}
+
+#[test]
+fn test_array_windows() {
+    let v = &[1, 2, 3, 4];
+
+    assert_eq!(v.array_windows::<[i32; 2]>().len(), 3);
+    assert_eq!(v.array_windows::<[i32; 2]>().collect::<Vec<_>>(), [&[1, 2], &[2, 3], &[3, 4]]);
+    assert_eq!(v.array_windows::<[i32; 3]>().rev().collect::<Vec<_>>(), [&[2, 3, 4], &[1, 2, 3]]);
+    assert_eq!(v.array_windows::<[i32; 2]>().nth(1), Some(&[2, 3]));
+    assert_eq!(v.array_windows::<[i32; 2]>().last(), Some(&[3, 4]));
+    assert_eq!(v.array_windows::<[i32; 5]>().next(), None);
+
+    let sums: Vec<_> = v.array_windows::<[i32; 2]>().map(|w| w[0] + w[1]).collect();
+    assert_eq!(sums, [3, 5, 7]);
+}
+
+#[test]
+#[should_panic]
+fn test_array_windows_0() {
+    let v = &[1, 2, 3, 4];
+    let _ = v.array_windows::<[i32; 0]>();
+}
+
+#[test]
+fn test_chunk_by() {
+    let slice = &[1, 1, 1, 3, 3, 2, 2, 2, 1, 0];
+
+    let mut iter = slice.chunk_by(|a, b| a == b);
+    assert_eq!(iter.next(), Some(&[1, 1, 1][..]));
+    assert_eq!(iter.next(), Some(&[3, 3][..]));
+    assert_eq!(iter.next(), Some(&[2, 2, 2][..]));
+    assert_eq!(iter.next(), Some(&[1][..]));
+    assert_eq!(iter.next(), Some(&[0][..]));
+    assert_eq!(iter.next(), None);
+
+    let mut iter = slice.chunk_by(|a, b| a == b);
+    assert_eq!(iter.next_back(), Some(&[0][..]));
+    assert_eq!(iter.next_back(), Some(&[1][..]));
+    assert_eq!(iter.next_back(), Some(&[2, 2, 2][..]));
+    assert_eq!(iter.next_back(), Some(&[3, 3][..]));
+    assert_eq!(iter.next_back(), Some(&[1, 1, 1][..]));
+    assert_eq!(iter.next_back(), None);
+
+    let mut iter = slice.chunk_by(|a, b| a == b);
+    assert_eq!(iter.next(), Some(&[1, 1, 1][..]));
+    assert_eq!(iter.next_back(), Some(&[0][..]));
+    assert_eq!(iter.next(), Some(&[3, 3][..]));
+    assert_eq!(iter.next_back(), Some(&[1][..]));
+    assert_eq!(iter.next(), Some(&[2, 2, 2][..]));
+    assert_eq!(iter.next_back(), None);
+
+    let empty: &[i32] = &[];
+    assert_eq!(empty.chunk_by(|a, b| a == b).next(), None);
+}
+
 #[test]
 fn test_reverse_part() {
     let mut values = [1, 2, 3, 4, 5];
@@ -1145,6 +1398,263 @@ fn test_mut_chunks_0() {
     let _it = v.chunks_mut(0);
 }
 
+#[test]
+fn test_mut_chunks_exact() {
+    let mut v = [0, 1, 2, 3, 4, 5, 6];
+    assert_eq!(v.chunks_exact_mut(2).len(), 3);
+    for (i, chunk) in v.chunks_exact_mut(3).enumerate() {
+        for x in chunk {
+            *x = i as u8;
+        }
+    }
+    let result = [0, 0, 0, 1, 1, 1, 6];
+    assert!(v == result);
+
+    let mut it = v.chunks_exact_mut(3);
+    assert_eq!(it.next_back().unwrap(), &mut [1, 1, 1]);
+    assert_eq!(it.into_remainder(), &mut [6]);
+}
+
+#[test]
+fn test_fill() {
+    let mut bytes = [0u8; 7];
+    bytes.fill(0xab);
+    assert_eq!(bytes, [0xab; 7]);
+
+    let mut bools = [false; 3];
+    bools.fill(true);
+    assert_eq!(bools, [true; 3]);
+
+    let mut words = [0u32; 5];
+    words[1..4].fill(7);
+    assert_eq!(words, [0, 7, 7, 7, 0]);
+
+    let mut strings = vec![String::new(); 3];
+    strings.fill("x".to_string());
+    assert_eq!(strings, ["x", "x", "x"]);
+
+    let mut empty: [String; 0] = [];
+    empty.fill("y".to_string());
+}
+
+#[test]
+fn test_fill_with() {
+    let mut v = [0; 5];
+    let mut count = 0;
+    v.fill_with(|| { count += 1; count });
+    assert_eq!(v, [1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_copy_within() {
+    // Start to end, with a RangeTo.
+    let mut bytes = *b"Hello, World!";
+    bytes.copy_within(..3, 10);
+    assert_eq!(&bytes, b"Hello, WorHel");
+
+    // End to start, with a RangeFrom.
+    let mut bytes = *b"Hello, World!";
+    bytes.copy_within(10.., 0);
+    assert_eq!(&bytes, b"ld!lo, World!");
+
+    // Overlapping, with a RangeInclusive.
+    let mut bytes = *b"Hello, World!";
+    bytes.copy_within(0...11, 1);
+    assert_eq!(&bytes, b"HHello, World");
+
+    // Whole slice, with a RangeFull.
+    let mut bytes = *b"Hello, World!";
+    bytes.copy_within(.., 0);
+    assert_eq!(&bytes, b"Hello, World!");
+
+    // Empty ranges starting at the end of the slice.
+    let mut bytes = *b"Hello, World!";
+    bytes.copy_within(13.., 13);
+    assert_eq!(&bytes, b"Hello, World!");
+}
+
+#[test]
+#[should_panic(expected = "src is out of bounds")]
+fn test_copy_within_panics_src_too_long() {
+    let mut bytes = *b"Hello, World!";
+    // The length is only 13, so 14 is out of bounds.
+    bytes.copy_within(10..14, 0);
+}
+
+#[test]
+#[should_panic(expected = "dest is out of bounds")]
+fn test_copy_within_panics_dest_too_long() {
+    let mut bytes = *b"Hello, World!";
+    // The length is only 13, so a slice of length 4 starting at index 10 is out of bounds.
+    bytes.copy_within(0..4, 10);
+}
+
+#[test]
+#[should_panic(expected = "src end is before src start")]
+fn test_copy_within_panics_src_inverted() {
+    let mut bytes = *b"Hello, World!";
+    // 2 is greater than 1, so this range is invalid.
+    bytes.copy_within(2..1, 0);
+}
+
+#[test]
+fn test_trim_ascii() {
+    assert!(b"".trim_ascii().is_empty());
+    assert!(b" \t\n\x0C\r".trim_ascii().is_empty());
+    assert_eq!(b" a b ".trim_ascii(), b"a b");
+    assert_eq!(b" a b ".trim_ascii_start(), b"a b ");
+    assert_eq!(b" a b ".trim_ascii_end(), b" a b");
+    assert_eq!(b"\x0Ba\x0B".trim_ascii(), b"\x0Ba\x0B");
+    assert_eq!(b"\xa0a\xa0".trim_ascii(), b"\xa0a\xa0");
+
+    let v = vec![b' ', b'x', b'\n'];
+    assert_eq!(v.trim_ascii(), b"x");
+}
+
+#[test]
+fn test_split_off() {
+    let mut slice: &[_] = &[1, 2, 3, 4, 5];
+    assert_eq!(<[i32]>::split_off(&mut slice, ..2), Some(&[1, 2][..]));
+    assert_eq!(slice, &[3, 4, 5]);
+    assert_eq!(<[i32]>::split_off(&mut slice, 2..), Some(&[5][..]));
+    assert_eq!(slice, &[3, 4]);
+    assert_eq!(<[i32]>::split_off(&mut slice, ...0), Some(&[3][..]));
+    assert_eq!(slice, &[4]);
+
+    assert_eq!(<[i32]>::split_off(&mut slice, ..2), None);
+    assert_eq!(<[i32]>::split_off(&mut slice, 2..), None);
+    assert_eq!(<[i32]>::split_off(&mut slice, 0..1), None);
+    assert_eq!(slice, &[4]);
+
+    assert_eq!(<[i32]>::split_off(&mut slice, ..), Some(&[4][..]));
+    assert!(slice.is_empty());
+    assert_eq!(<[i32]>::split_off(&mut slice, ..0), Some(&[][..]));
+}
+
+#[test]
+fn test_split_off_mut() {
+    let mut buf = [1, 2, 3, 4, 5];
+    {
+        let mut slice: &mut [_] = &mut buf;
+        {
+            let head = <[i32]>::split_off_mut(&mut slice, ..2).unwrap();
+            head[0] = 10;
+        }
+        {
+            let tail = <[i32]>::split_off_mut(&mut slice, 2..).unwrap();
+            tail[0] = 50;
+        }
+        assert!(<[i32]>::split_off_mut(&mut slice, 1..2).is_none());
+        assert_eq!(slice, &mut [3, 4]);
+        slice[0] = 30;
+    }
+    assert_eq!(buf, [10, 2, 30, 4, 50]);
+}
+
+#[test]
+fn test_split_off_first_last() {
+    let mut slice: &[_] = &[1, 2, 3];
+    assert_eq!(<[i32]>::split_off_first(&mut slice), Some(&1));
+    assert_eq!(<[i32]>::split_off_last(&mut slice), Some(&3));
+    assert_eq!(slice, &[2]);
+    assert_eq!(<[i32]>::split_off_last(&mut slice), Some(&2));
+    assert_eq!(<[i32]>::split_off_first(&mut slice), None);
+    assert_eq!(<[i32]>::split_off_last(&mut slice), None);
+
+    let mut buf = [1, 2, 3];
+    {
+        let mut slice: &mut [_] = &mut buf;
+        *<[i32]>::split_off_first_mut(&mut slice).unwrap() = 4;
+        *<[i32]>::split_off_last_mut(&mut slice).unwrap() = 6;
+        assert_eq!(slice, &mut [2]);
+    }
+    assert_eq!(buf, [4, 2, 6]);
+}
+
+#[test]
+fn test_chunk_by_mut() {
+    let slice = &mut [1, 1, 1, 3, 3, 2, 2, 2, 1, 0];
+
+    for run in slice.chunk_by_mut(|a, b| a == b) {
+        let len = run.len() as i32;
+        for x in run.iter_mut() {
+            *x = len;
+        }
+    }
+    assert_eq!(slice, &[3, 3, 3, 2, 2, 3, 3, 3, 1, 1]);
+
+    let mut iter = slice.chunk_by_mut(|a, b| a == b);
+    assert_eq!(iter.next_back(), Some(&mut [1, 1][..]));
+    assert_eq!(iter.next(), Some(&mut [3, 3, 3][..]));
+    assert_eq!(iter.next_back(), Some(&mut [3, 3, 3][..]));
+    assert_eq!(iter.next(), Some(&mut [2, 2][..]));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn test_get_many_mut() {
+    let mut v = [1, 2, 3, 4, 5];
+    {
+        let mut refs = v.get_many_mut([4, 0, 2]).unwrap();
+        *refs[0] += 10;
+        *refs[1] += 20;
+        *refs[2] = 0;
+    }
+    assert_eq!(v, [21, 2, 0, 4, 15]);
+
+    assert!(v.get_many_mut::<[usize; 0]>([]).is_some());
+    assert!(v.get_many_mut([5]).is_none());
+    assert!(v.get_many_mut([0, 5]).is_none());
+    assert!(v.get_many_mut([1, 3, 1]).is_none());
+    assert!(v.get_many_mut([!0, 0]).is_none());
+}
+
+#[test]
+fn test_mut_as_chunks() {
+    let mut v = [0, 1, 2, 3, 4, 5, 6];
+    {
+        let (chunks, rem) = v.as_chunks_mut::<[u8; 3]>();
+        chunks[0] = [7; 3];
+        chunks[1][2] = 8;
+        rem[0] = 9;
+    }
+    assert_eq!(v, [7, 7, 7, 3, 4, 8, 9]);
+
+    {
+        let (rem, chunks) = v.as_rchunks_mut::<[u8; 3]>();
+        chunks[1] = [1; 3];
+        rem[0] = 0;
+    }
+    assert_eq!(v, [0, 7, 7, 3, 1, 1, 1]);
+}
+
+#[test]
+fn test_mut_array_chunks() {
+    let mut v = [0, 1, 2, 3, 4, 5, 6];
+    for (i, chunk) in v.array_chunks_mut::<[u8; 3]>().enumerate() {
+        *chunk = [i as u8; 3];
+    }
+    assert_eq!(v, [0, 0, 0, 1, 1, 1, 6]);
+    assert_eq!(v.array_chunks_mut::<[u8; 3]>().into_remainder(), &mut [6]);
+}
+
+#[test]
+fn test_mut_rchunks_exact() {
+    let mut v = [0, 1, 2, 3, 4, 5, 6];
+    assert_eq!(v.rchunks_exact_mut(2).len(), 3);
+    for (i, chunk) in v.rchunks_exact_mut(3).enumerate() {
+        for x in chunk {
+            *x = i as u8;
+        }
+    }
+    let result = [0, 1, 1, 1, 0, 0, 0];
+    assert!(v == result);
+
+    let mut it = v.rchunks_exact_mut(3);
+    assert_eq!(it.nth(1).unwrap(), &mut [1, 1, 1]);
+    assert_eq!(it.into_remainder(), &mut [0]);
+}
+
 #[test]
 fn test_mut_last() {
     let mut x = [1, 2, 3, 4, 5];
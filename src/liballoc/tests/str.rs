@@ -10,6 +10,7 @@
 
 use std::borrow::Cow;
 use std::cmp::Ordering::{Equal, Greater, Less};
+use std::ops::Range;
 use std::str::from_utf8;
 
 #[test]
@@ -612,6 +613,35 @@ fn from_utf8_error() {
     test!(b"A\xC3\xA9 \xF1\x80\x80 ", 4, Some(3));
 }
 
+#[test]
+fn from_utf8_all_scalar_values() {
+    let mut buf = [0; 4];
+    for c in (0..0x110000).filter_map(::std::char::from_u32) {
+        let s = c.encode_utf8(&mut buf);
+        assert_eq!(from_utf8(s.as_bytes()), Ok(&*s));
+    }
+}
+
+#[test]
+fn from_utf8_error_offsets() {
+    // Errors must be reported at the same place wherever they fall relative
+    // to the blocks of ascii that are checked a word at a time.
+    for i in 0..40 {
+        let mut data = vec![b'a'; 64];
+        data[i..i + 5].copy_from_slice(b"\xE2\x82\xAC\xED\xA0");
+
+        let error = from_utf8(&data).unwrap_err();
+        assert_eq!(error.valid_up_to(), i + 3);
+        assert_eq!(error.error_len(), Some(1));
+
+        let error = from_utf8(&data[..i + 4]).unwrap_err();
+        assert_eq!(error.valid_up_to(), i + 3);
+        assert_eq!(error.error_len(), None);
+
+        assert!(from_utf8(&data[..i + 3]).is_ok());
+    }
+}
+
 #[test]
 fn test_as_bytes() {
     // no null
@@ -976,6 +1006,47 @@ fn test_split_char_iterator_no_trailing() {
     assert_eq!(split, ["", "Märy häd ä little lämb", "Little lämb"]);
 }
 
+#[test]
+fn test_split_char_iterator_inclusive() {
+    let data = "\nMäry häd ä little lämb\nLittle lämb\n";
+
+    let split: Vec<&str> = data.split_inclusive('\n').collect();
+    assert_eq!(split, ["\n", "Märy häd ä little lämb\n", "Little lämb\n"]);
+
+    let uppercase_separated = "SheePSharKTurtlECaT";
+    let mut first_char = true;
+    let split: Vec<&str> = uppercase_separated.split_inclusive(|c: char| {
+        let split = !first_char && c.is_uppercase();
+        first_char = split;
+        split
+    }).collect();
+    assert_eq!(split, ["SheeP", "SharK", "TurtlE", "CaT"]);
+
+    let split: Vec<&str> = "a, b, c".split_inclusive(", ").collect();
+    assert_eq!(split, ["a, ", "b, ", "c"]);
+
+    assert_eq!("".split_inclusive('\n').next(), None);
+}
+
+#[test]
+fn test_split_char_iterator_inclusive_rev() {
+    let data = "\nMäry häd ä little lämb\nLittle lämb\n";
+
+    let split: Vec<&str> = data.split_inclusive('\n').rev().collect();
+    assert_eq!(split, ["Little lämb\n", "Märy häd ä little lämb\n", "\n"]);
+
+    let split: Vec<&str> = "a\nb".split_inclusive('\n').rev().collect();
+    assert_eq!(split, ["b", "a\n"]);
+
+    let mut iter = "a\nb\nc\n".split_inclusive('\n');
+    assert_eq!(iter.next(), Some("a\n"));
+    assert_eq!(iter.next_back(), Some("c\n"));
+    assert_eq!(iter.next_back(), Some("b\n"));
+    assert_eq!(iter.next(), None);
+
+    assert_eq!("".split_inclusive('\n').next_back(), None);
+}
+
 #[test]
 fn test_rsplit() {
     let data = "\nMäry häd ä little lämb\nLittle lämb\n";
@@ -1004,6 +1075,150 @@ fn test_rsplitn() {
     assert_eq!(split, ["mb\n", "\nMäry häd ä little lämb\nLittle l"]);
 }
 
+#[test]
+fn test_floor_char_boundary() {
+    fn check_many(s: &str, arg: Range<usize>, ret: usize) {
+        for idx in arg {
+            assert_eq!(s.floor_char_boundary(idx), ret,
+                       "{:?}.floor_char_boundary({:?}) != {:?}", s, idx, ret);
+        }
+    }
+
+    // edge case
+    check_many("", 0..3, 0);
+
+    // basic check
+    check_many("x", 0..1, 0);
+    check_many("x", 1..4, 1);
+
+    // 1-byte chars
+    check_many("jp", 0..1, 0);
+    check_many("jp", 1..2, 1);
+    check_many("jp", 2..4, 2);
+
+    // 2-byte chars
+    check_many("ĵƥ", 0..2, 0);
+    check_many("ĵƥ", 2..4, 2);
+    check_many("ĵƥ", 4..6, 4);
+
+    // 3-byte chars
+    check_many("日本", 0..3, 0);
+    check_many("日本", 3..6, 3);
+    check_many("日本", 6..8, 6);
+
+    // 4-byte chars
+    check_many("🇯🇵", 0..4, 0);
+    check_many("🇯🇵", 4..8, 4);
+    check_many("🇯🇵", 8..10, 8);
+}
+
+#[test]
+fn test_ceil_char_boundary() {
+    fn check_many(s: &str, arg: Range<usize>, ret: usize) {
+        for idx in arg {
+            assert_eq!(s.ceil_char_boundary(idx), ret,
+                       "{:?}.ceil_char_boundary({:?}) != {:?}", s, idx, ret);
+        }
+    }
+
+    // edge case
+    check_many("", 0..3, 0);
+
+    // basic check
+    check_many("x", 0..1, 0);
+    check_many("x", 1..4, 1);
+
+    // 1-byte chars
+    check_many("jp", 0..1, 0);
+    check_many("jp", 1..2, 1);
+    check_many("jp", 2..4, 2);
+
+    // 2-byte chars
+    check_many("ĵƥ", 0..1, 0);
+    check_many("ĵƥ", 1..3, 2);
+    check_many("ĵƥ", 3..6, 4);
+
+    // 3-byte chars
+    check_many("日本", 0..1, 0);
+    check_many("日本", 1..4, 3);
+    check_many("日本", 4..8, 6);
+
+    // 4-byte chars
+    check_many("🇯🇵", 0..1, 0);
+    check_many("🇯🇵", 1..5, 4);
+    check_many("🇯🇵", 5..10, 8);
+}
+
+#[test]
+fn test_split_once() {
+    assert_eq!("".split_once("->"), None);
+    assert_eq!("-".split_once("->"), None);
+    assert_eq!("->".split_once("->"), Some(("", "")));
+    assert_eq!("a->".split_once("->"), Some(("a", "")));
+    assert_eq!("->b".split_once("->"), Some(("", "b")));
+    assert_eq!("a->b".split_once("->"), Some(("a", "b")));
+    assert_eq!("a->b->c".split_once("->"), Some(("a", "b->c")));
+    assert_eq!("---".split_once("--"), Some(("", "-")));
+    assert_eq!("häd=lämb".split_once('='), Some(("häd", "lämb")));
+    assert_eq!("häd=lämb".split_once(|c: char| c == 'ä'), Some(("h", "d=lämb")));
+}
+
+#[test]
+fn test_rsplit_once() {
+    assert_eq!("".rsplit_once("->"), None);
+    assert_eq!("-".rsplit_once("->"), None);
+    assert_eq!("->".rsplit_once("->"), Some(("", "")));
+    assert_eq!("a->".rsplit_once("->"), Some(("a", "")));
+    assert_eq!("->b".rsplit_once("->"), Some(("", "b")));
+    assert_eq!("a->b".rsplit_once("->"), Some(("a", "b")));
+    assert_eq!("a->b->c".rsplit_once("->"), Some(("a->b", "c")));
+    assert_eq!("---".rsplit_once("--"), Some(("-", "")));
+    assert_eq!("häd=lämb".rsplit_once(|c: char| c == 'ä'), Some(("häd=l", "mb")));
+}
+
+#[test]
+fn test_strip_prefix() {
+    assert_eq!("".strip_prefix(""), Some(""));
+    assert_eq!("abc".strip_prefix(""), Some("abc"));
+    assert_eq!("abc".strip_prefix("ab"), Some("c"));
+    assert_eq!("abc".strip_prefix("abc"), Some(""));
+    assert_eq!("abc".strip_prefix("abcd"), None);
+    assert_eq!("abc".strip_prefix("bc"), None);
+    assert_eq!("aab".strip_prefix('a'), Some("ab"));
+    assert_eq!("äb".strip_prefix('ä'), Some("b"));
+    assert_eq!("äb".strip_prefix("\u{e4}"), Some("b"));
+    assert_eq!("12ab".strip_prefix(char::is_numeric), Some("2ab"));
+    assert_eq!("12ab".strip_prefix(&['2', '1'][..]), Some("2ab"));
+    assert_eq!("ab".strip_prefix(char::is_numeric), None);
+}
+
+#[test]
+fn test_strip_suffix() {
+    assert_eq!("".strip_suffix(""), Some(""));
+    assert_eq!("abc".strip_suffix(""), Some("abc"));
+    assert_eq!("abc".strip_suffix("bc"), Some("a"));
+    assert_eq!("abc".strip_suffix("abc"), Some(""));
+    assert_eq!("abc".strip_suffix("zabc"), None);
+    assert_eq!("abc".strip_suffix("ab"), None);
+    assert_eq!("abb".strip_suffix('b'), Some("ab"));
+    assert_eq!("bä".strip_suffix('ä'), Some("b"));
+    assert_eq!("ab12".strip_suffix(char::is_numeric), Some("ab1"));
+    assert_eq!("ab".strip_suffix(char::is_numeric), None);
+}
+
+#[test]
+fn test_trim_ascii() {
+    assert_eq!("".trim_ascii(), "");
+    assert_eq!(" \t\n\x0C\r".trim_ascii(), "");
+    assert_eq!(" a b ".trim_ascii(), "a b");
+    assert_eq!(" a b ".trim_ascii_start(), "a b ");
+    assert_eq!(" a b ".trim_ascii_end(), " a b");
+    // Vertical tab and non-ASCII whitespace are left alone.
+    assert_eq!("\x0Ba\x0B".trim_ascii(), "\x0Ba\x0B");
+    assert_eq!("\u{a0}ä\u{3000}".trim_ascii(), "\u{a0}ä\u{3000}");
+    assert_eq!(" ä ".trim_ascii(), "ä");
+}
+
 #[test]
 fn test_split_whitespace() {
     let data = "\n \tMäry   häd\tä  little lämb\nLittle lämb\n";
@@ -1011,6 +1226,23 @@ fn test_split_whitespace() {
     assert_eq!(words, ["Märy", "häd", "ä", "little", "lämb", "Little", "lämb"])
 }
 
+#[test]
+fn test_split_ascii_whitespace() {
+    let data = "\n \tMäry   häd\tä  little lämb\nLittle lämb\n";
+    let words: Vec<&str> = data.split_ascii_whitespace().collect();
+    assert_eq!(words, ["Märy", "häd", "ä", "little", "lämb", "Little", "lämb"]);
+
+    let words: Vec<&str> = data.split_ascii_whitespace().rev().collect();
+    assert_eq!(words, ["lämb", "Little", "lämb", "little", "ä", "häd", "Märy"]);
+
+    // Unicode whitespace is left alone.
+    let words: Vec<&str> = "a\u{A0}b\u{3000}c \x0Cd".split_ascii_whitespace().collect();
+    assert_eq!(words, ["a\u{A0}b\u{3000}c", "d"]);
+
+    assert_eq!("".split_ascii_whitespace().next(), None);
+    assert_eq!(" \t\r\n".split_ascii_whitespace().next(), None);
+}
+
 #[test]
 fn test_lines() {
     let data = "\nMäry häd ä little lämb\n\r\nLittle lämb\n";
@@ -1081,6 +1313,36 @@ fn test_str_from_utf8() {
     assert!(from_utf8(xs).is_err());
 }
 
+#[test]
+fn test_utf8_chunks() {
+    use std::str::utf8_chunks;
+
+    fn chunks(v: &[u8]) -> Vec<(&str, &[u8])> {
+        utf8_chunks(v).map(|c| (c.valid(), c.invalid())).collect()
+    }
+
+    assert!(chunks(b"").is_empty());
+    assert_eq!(chunks(b"hello"), [("hello", &b""[..])]);
+    assert_eq!(chunks("ศไทย中华Việt Nam".as_bytes()), [("ศไทย中华Việt Nam", &b""[..])]);
+    assert_eq!(chunks(b"Hello\xC0\x80 There\xE6\x83 Goodbye"),
+               [("Hello", &b"\xC0"[..]), ("", &b"\x80"[..]),
+                (" There", &b"\xE6\x83"[..]), (" Goodbye", &b""[..])]);
+    assert_eq!(chunks(b"\xF5foo\xF5\x80bar"),
+               [("", &b"\xF5"[..]), ("foo", &b"\xF5"[..]), ("", &b"\x80"[..]),
+                ("bar", &b""[..])]);
+    // A sequence cut short by the end of the input is a single invalid run.
+    assert_eq!(chunks(b"abc\xF0\x90\x80"), [("abc", &b"\xF0\x90\x80"[..])]);
+    assert_eq!(chunks(b"\xED\xA0\x80"),
+               [("", &b"\xED"[..]), ("", &b"\xA0"[..]), ("", &b"\x80"[..])]);
+
+    let mut iter = utf8_chunks(b"a\xFFb");
+    assert_eq!(iter.size_hint(), (1, Some(3)));
+    iter.next();
+    iter.next();
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert_eq!(iter.next(), None);
+}
+
 #[test]
 fn test_pattern_deref_forward() {
     let data = "aabcdaa";
@@ -1571,3 +1833,101 @@ fn different_str_pattern_forwarding_lifetimes() {
 
     foo::<&str>("x");
 }
+
+#[test]
+fn custom_pattern() {
+    use std::str::pattern::{Pattern, Searcher, ReverseSearcher, DoubleEndedSearcher};
+    use std::str::pattern::SearchStep;
+
+    // Matches any one of a set of ASCII bytes, searching from either end.
+    struct AsciiSet(&'static [u8]);
+
+    struct AsciiSetSearcher<'a> {
+        haystack: &'a str,
+        set: &'static [u8],
+        front: usize,
+        back: usize,
+    }
+
+    impl<'a> Pattern<'a> for AsciiSet {
+        type Searcher = AsciiSetSearcher<'a>;
+
+        fn into_searcher(self, haystack: &'a str) -> AsciiSetSearcher<'a> {
+            assert!(self.0.iter().all(|&b| b < 128));
+            AsciiSetSearcher { haystack: haystack, set: self.0, front: 0, back: haystack.len() }
+        }
+    }
+
+    unsafe impl<'a> Searcher<'a> for AsciiSetSearcher<'a> {
+        fn haystack(&self) -> &'a str {
+            self.haystack
+        }
+
+        fn next(&mut self) -> SearchStep {
+            let start = self.front;
+            let rest = &self.haystack.as_bytes()[start..self.back];
+            match rest.iter().position(|b| self.set.contains(b)) {
+                _ if rest.is_empty() => SearchStep::Done,
+                Some(0) => {
+                    self.front += 1;
+                    SearchStep::Match(start, start + 1)
+                }
+                Some(i) => {
+                    self.front += i;
+                    SearchStep::Reject(start, start + i)
+                }
+                None => {
+                    self.front = self.back;
+                    SearchStep::Reject(start, self.back)
+                }
+            }
+        }
+    }
+
+    unsafe impl<'a> ReverseSearcher<'a> for AsciiSetSearcher<'a> {
+        fn next_back(&mut self) -> SearchStep {
+            let end = self.back;
+            let rest = &self.haystack.as_bytes()[self.front..end];
+            match rest.iter().rposition(|b| self.set.contains(b)) {
+                _ if rest.is_empty() => SearchStep::Done,
+                Some(i) if self.front + i + 1 == end => {
+                    self.back -= 1;
+                    SearchStep::Match(end - 1, end)
+                }
+                Some(i) => {
+                    self.back = self.front + i + 1;
+                    SearchStep::Reject(self.back, end)
+                }
+                None => {
+                    self.back = self.front;
+                    SearchStep::Reject(self.front, end)
+                }
+            }
+        }
+    }
+
+    impl<'a> DoubleEndedSearcher<'a> for AsciiSetSearcher<'a> {}
+
+    let s = ",größe;;farbe,preis;";
+    let sep = || AsciiSet(b",;");
+
+    assert_eq!(s.find(sep()), Some(0));
+    assert_eq!(s.rfind(sep()), Some(s.len() - 1));
+    assert_eq!(s.find(AsciiSet(b"x")), None);
+    assert!(s.contains(sep()));
+    assert!(s.starts_with(sep()));
+    assert!(s.ends_with(sep()));
+
+    let fields: Vec<&str> = s.split(sep()).collect();
+    assert_eq!(fields, ["", "größe", "", "farbe", "preis", ""]);
+    let fields: Vec<&str> = s.rsplit(sep()).collect();
+    assert_eq!(fields, ["", "preis", "farbe", "", "größe", ""]);
+    let fields: Vec<&str> = s.split(sep()).rev().collect();
+    assert_eq!(fields, ["", "preis", "farbe", "", "größe", ""]);
+
+    assert_eq!(s.matches(sep()).count(), 5);
+    assert_eq!(s.trim_matches(sep()), "größe;;farbe,preis");
+    assert_eq!(s.trim_left_matches(sep()), "größe;;farbe,preis;");
+    assert_eq!(s.trim_right_matches(sep()), ",größe;;farbe,preis");
+    assert_eq!(s.replace(AsciiSet(b";"), "|"), ",größe||farbe,preis|");
+}
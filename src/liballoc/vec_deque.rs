@@ -2331,7 +2331,7 @@ impl<A: Ord> Ord for VecDeque<A> {
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<A: Hash> Hash for VecDeque<A> {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.len().hash(state);
+        state.write_length_prefix(self.len());
         let (a, b) = self.as_slices();
         Hash::hash_slice(a, state);
         Hash::hash_slice(b, state);
@@ -23,7 +23,11 @@ use borrow::{Borrow, BorrowMut};
 use cmp::Ordering;
 use fmt;
 use hash::{Hash, self};
-use marker::Unsize;
+use iter::{FusedIterator, TrustedLen};
+use marker::{PhantomData, Unsize};
+use mem::{self, ManuallyDrop};
+use ops::Range;
+use ptr;
 use slice::{Iter, IterMut};
 
 /// Utility trait implemented only on arrays of fixed size
@@ -57,6 +61,451 @@ unsafe impl<T, A: Unsize<[T]>> FixedSizeArray<T> for A {
     }
 }
 
+/// Element-wise transformation of an array into an array of the same length.
+///
+/// This is implemented for arrays of length 0 to 32 (inclusive), with
+/// `Output = [U; N]` for `[T; N]`.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(array_map)]
+/// extern crate core;
+/// use core::array::ArrayMap;
+///
+/// fn main() {
+///     let x = [1, 2, 3];
+///     let y = x.map(|v| v + 1);
+///     assert_eq!(y, [2, 3, 4]);
+///
+///     let names = ["Ferris", "Crab"];
+///     let lengths = names.map(|name| name.len());
+///     assert_eq!(lengths, [6, 4]);
+/// }
+/// ```
+#[unstable(feature = "array_map", issue = "0")]
+pub trait ArrayMap<T, U> {
+    /// The array of mapped elements.
+    type Output;
+
+    /// Returns an array of the same length as `self`, where `f` has been
+    /// applied to each element in order.
+    ///
+    /// If `f` panics, the elements not yet passed to it and the results it
+    /// already returned are dropped.
+    fn map<F: FnMut(T) -> U>(self, f: F) -> Self::Output;
+
+    /// A fallible version of [`map`], which stops at the first error and
+    /// returns it.
+    ///
+    /// On error, the elements not yet passed to `f` and the results it
+    /// already returned are dropped.
+    ///
+    /// [`map`]: #tymethod.map
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(array_map, array_try_map)]
+    /// extern crate core;
+    /// use core::array::ArrayMap;
+    ///
+    /// fn main() {
+    ///     let fields = ["1", "22", "333"];
+    ///     assert_eq!(fields.try_map(|s| s.parse::<u32>()), Ok([1, 22, 333]));
+    ///
+    ///     let fields = ["1", "x", "333"];
+    ///     assert!(fields.try_map(|s| s.parse::<u32>()).is_err());
+    /// }
+    /// ```
+    #[unstable(feature = "array_try_map", issue = "0")]
+    fn try_map<E, F: FnMut(T) -> Result<U, E>>(self, f: F) -> Result<Self::Output, E>;
+}
+
+/// Arrays that can be built by calling a function with each index.
+///
+/// This is implemented for arrays of length 0 to 32 (inclusive). It is
+/// usually used through [`from_fn`].
+///
+/// [`from_fn`]: fn.from_fn.html
+#[unstable(feature = "array_from_fn", issue = "0")]
+pub trait ArrayFromFn<T>: Sized {
+    /// Creates an array where each element is `f(index)`, calling `f` in
+    /// order of increasing index.
+    fn from_fn<F: FnMut(usize) -> T>(f: F) -> Self;
+
+    /// Creates an array where each element is the `Ok` value of `f(index)`,
+    /// or returns the first error.
+    #[unstable(feature = "array_try_from_fn", issue = "0")]
+    fn try_from_fn<E, F: FnMut(usize) -> Result<T, E>>(f: F) -> Result<Self, E>;
+}
+
+/// Creates an array where each element is the result of calling `f` with
+/// its index.
+///
+/// `f` is called in order of increasing index. If it panics, the elements
+/// it already returned are dropped. This also works for element types that
+/// are neither `Copy` nor `Default`.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(array_from_fn)]
+/// extern crate core;
+/// use core::array;
+///
+/// fn main() {
+///     let squares: [usize; 5] = array::from_fn(|i| i * i);
+///     assert_eq!(squares, [0, 1, 4, 9, 16]);
+///
+///     let names: [String; 3] = array::from_fn(|i| format!("worker-{}", i));
+///     assert_eq!(names[2], "worker-2");
+/// }
+/// ```
+#[unstable(feature = "array_from_fn", issue = "0")]
+#[inline]
+pub fn from_fn<A, T, F>(f: F) -> A
+    where A: ArrayFromFn<T>, F: FnMut(usize) -> T
+{
+    A::from_fn(f)
+}
+
+/// Creates an array where each element is the `Ok` value of calling `f`
+/// with its index, or returns the first error `f` produces.
+///
+/// `f` is called in order of increasing index, and isn't called again
+/// after it returns an error. The elements it already returned are then
+/// dropped.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(array_try_from_fn)]
+/// extern crate core;
+/// use core::array;
+///
+/// fn main() {
+///     let fields = ["3", "1", "4", "1", "5"];
+///     let digits: Result<[u8; 5], _> = array::try_from_fn(|i| fields[i].parse::<u8>());
+///     assert_eq!(digits, Ok([3, 1, 4, 1, 5]));
+///
+///     let too_many: Result<[u8; 6], String> = array::try_from_fn(|i| {
+///         match fields.get(i) {
+///             Some(field) => Ok(field.parse().unwrap()),
+///             None => Err(format!("missing field {}", i)),
+///         }
+///     });
+///     assert_eq!(too_many, Err(String::from("missing field 5")));
+/// }
+/// ```
+#[unstable(feature = "array_try_from_fn", issue = "0")]
+#[inline]
+pub fn try_from_fn<A, T, E, F>(f: F) -> Result<A, E>
+    where A: ArrayFromFn<T>, F: FnMut(usize) -> Result<T, E>
+{
+    A::try_from_fn(f)
+}
+
+/// Borrowing each element of an array separately.
+///
+/// This is implemented for arrays of length 0 to 32 (inclusive), with
+/// `Refs = [&'a T; N]` and `Muts = [&'a mut T; N]` for `[T; N]`.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(array_each_ref)]
+/// extern crate core;
+/// use core::array::ArrayEachRef;
+///
+/// fn main() {
+///     let floats = [3.1, 2.7, -1.0];
+///     let float_refs: [&f64; 3] = floats.each_ref();
+///     assert_eq!(float_refs, [&3.1, &2.7, &-1.0]);
+///
+///     let mut counters = [0, 0, 0];
+///     {
+///         let [a, b, c] = counters.each_mut();
+///         *a += 1;
+///         *c += 3;
+///         *b = *a + *c;
+///     }
+///     assert_eq!(counters, [1, 4, 3]);
+/// }
+/// ```
+#[unstable(feature = "array_each_ref", issue = "0")]
+pub trait ArrayEachRef<'a, T: 'a> {
+    /// The array of shared references.
+    type Refs;
+
+    /// The array of mutable references.
+    type Muts;
+
+    /// Borrows each element and returns an array of references with the
+    /// same length as `self`.
+    fn each_ref(&'a self) -> Self::Refs;
+
+    /// Borrows each element mutably and returns an array of mutable
+    /// references with the same length as `self`.
+    fn each_mut(&'a mut self) -> Self::Muts;
+}
+
+/// Splits an array by value into two arrays.
+///
+/// The lengths of the halves are taken from the types `L` and `R`, and are
+/// usually given by annotating the result. Slices already offer this split
+/// by reference through `split_first_chunk` and `split_last_chunk`.
+///
+/// # Panics
+///
+/// Panics if the lengths of `L` and `R` don't add up to the length of `A`.
+/// All three lengths are constants, so the check is normally optimized out.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(array_split)]
+/// extern crate core;
+/// use core::array;
+///
+/// fn main() {
+///     let packet = [0xca, 0xfe, 0, 4, 1, 2, 3, 4];
+///     let (header, payload): ([u8; 4], [u8; 4]) = array::split_array(packet);
+///     assert_eq!(header, [0xca, 0xfe, 0, 4]);
+///     assert_eq!(payload, [1, 2, 3, 4]);
+/// }
+/// ```
+#[unstable(feature = "array_split", issue = "0")]
+pub fn split_array<T, A, L, R>(array: A) -> (L, R)
+    where A: FixedSizeArray<T>, L: FixedSizeArray<T>, R: FixedSizeArray<T>
+{
+    let array = ManuallyDrop::new(array);
+    let (left, right): (&L, &R) = match array.as_slice().split_first_chunk() {
+        Some((left, rest)) => match rest.split_first_chunk() {
+            Some((right, rest)) if rest.is_empty() => (left, right),
+            _ => panic!("array lengths don't add up when splitting"),
+        },
+        None => panic!("array lengths don't add up when splitting"),
+    };
+    // `array` is never dropped, so each element is moved out exactly once.
+    unsafe { (ptr::read(left), ptr::read(right)) }
+}
+
+/// Concatenates two arrays by value into one array.
+///
+/// The length of the result is taken from the type `A`, and is usually
+/// given by annotating the result.
+///
+/// # Panics
+///
+/// Panics if the lengths of `L` and `R` don't add up to the length of `A`.
+/// All three lengths are constants, so the check is normally optimized out.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(array_split)]
+/// extern crate core;
+/// use core::array;
+///
+/// fn main() {
+///     let header = [0xca, 0xfe, 0, 2];
+///     let packet: [u8; 6] = array::concat_arrays(header, [7, 8]);
+///     assert_eq!(packet, [0xca, 0xfe, 0, 2, 7, 8]);
+/// }
+/// ```
+#[unstable(feature = "array_split", issue = "0")]
+pub fn concat_arrays<T, L, R, A>(left: L, right: R) -> A
+    where L: FixedSizeArray<T>, R: FixedSizeArray<T>, A: FixedSizeArray<T>
+{
+    // Not dropped if the lengths turn out to be wrong, since it is
+    // uninitialized until both halves have been written.
+    let mut array: ManuallyDrop<A> = ManuallyDrop::new(unsafe { mem::uninitialized() });
+    {
+        let (dst_left, dst_right): (&mut L, &mut R) =
+            match array.as_mut_slice().split_first_chunk_mut() {
+                Some((dst_left, rest)) => match rest.split_first_chunk_mut() {
+                    Some((dst_right, rest)) if rest.is_empty() => (dst_left, dst_right),
+                    _ => panic!("array lengths don't add up when concatenating"),
+                },
+                None => panic!("array lengths don't add up when concatenating"),
+            };
+        unsafe {
+            ptr::write(dst_left, left);
+            ptr::write(dst_right, right);
+        }
+    }
+    ManuallyDrop::into_inner(array)
+}
+
+/// A by-value iterator over an array.
+///
+/// This `struct` is created by [`IntoIter::new`]. It yields the elements of
+/// the array as owned values, so arrays of non-`Copy` types can be consumed
+/// by iterator adaptors without going through a `Vec`. Elements that are
+/// never yielded are dropped with the iterator.
+///
+/// Arrays do not implement [`IntoIterator`] by value yet, so
+/// `for x in array` and `array.into_iter()` are still not supported this
+/// way: both go through the slice iterator and yield references.
+/// `IntoIter::new` is currently the only way to iterate over an array by
+/// value.
+///
+/// [`IntoIter::new`]: struct.IntoIter.html#method.new
+/// [`IntoIterator`]: ../iter/trait.IntoIterator.html
+///
+/// # Examples
+///
+/// ```
+/// #![feature(array_value_iter)]
+/// extern crate core;
+/// use core::array::IntoIter;
+///
+/// fn main() {
+///     let names = [String::from("foo"), String::from("bar")];
+///     let shouted: Vec<String> = IntoIter::new(names).map(|s| s.to_uppercase()).collect();
+///     assert_eq!(shouted, ["FOO", "BAR"]);
+/// }
+/// ```
+#[unstable(feature = "array_value_iter", issue = "0")]
+pub struct IntoIter<T, A: FixedSizeArray<T>> {
+    // Only the elements at the indices in `alive` are initialized and owned
+    // by the iterator; the others have already been yielded.
+    data: ManuallyDrop<A>,
+    alive: Range<usize>,
+    _marker: PhantomData<T>,
+}
+
+impl<T, A: FixedSizeArray<T>> IntoIter<T, A> {
+    /// Creates a new iterator over the given `array`, yielding its elements
+    /// by value.
+    ///
+    /// This stands in for an `IntoIterator` impl on arrays, which doesn't
+    /// exist yet: `array.into_iter()` still yields references.
+    #[unstable(feature = "array_value_iter", issue = "0")]
+    #[inline]
+    pub fn new(array: A) -> IntoIter<T, A> {
+        let len = array.as_slice().len();
+        IntoIter {
+            data: ManuallyDrop::new(array),
+            alive: 0..len,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the elements that have not been yielded yet as a slice.
+    #[unstable(feature = "array_value_iter", issue = "0")]
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.data.as_slice()[self.alive.start..self.alive.end]
+    }
+
+    /// Returns the elements that have not been yielded yet as a mutable
+    /// slice.
+    #[unstable(feature = "array_value_iter", issue = "0")]
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data.as_mut_slice()[self.alive.start..self.alive.end]
+    }
+}
+
+#[unstable(feature = "array_value_iter", issue = "0")]
+impl<T, A: FixedSizeArray<T>> Iterator for IntoIter<T, A> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        match self.alive.next() {
+            // The index is removed from `alive` first, so the element is
+            // never read or dropped again.
+            Some(idx) => Some(unsafe { ptr::read(self.data.as_slice().get_unchecked(idx)) }),
+            None => None,
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<T> {
+        self.next_back()
+    }
+}
+
+#[unstable(feature = "array_value_iter", issue = "0")]
+impl<T, A: FixedSizeArray<T>> DoubleEndedIterator for IntoIter<T, A> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        match self.alive.next_back() {
+            Some(idx) => Some(unsafe { ptr::read(self.data.as_slice().get_unchecked(idx)) }),
+            None => None,
+        }
+    }
+}
+
+#[unstable(feature = "array_value_iter", issue = "0")]
+impl<T, A: FixedSizeArray<T>> ExactSizeIterator for IntoIter<T, A> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.alive.end - self.alive.start
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.alive.start == self.alive.end
+    }
+}
+
+#[unstable(feature = "fused", issue = "35602")]
+impl<T, A: FixedSizeArray<T>> FusedIterator for IntoIter<T, A> {}
+
+#[unstable(feature = "trusted_len", issue = "37572")]
+unsafe impl<T, A: FixedSizeArray<T>> TrustedLen for IntoIter<T, A> {}
+
+#[unstable(feature = "array_value_iter", issue = "0")]
+impl<T, A: FixedSizeArray<T>> Drop for IntoIter<T, A> {
+    fn drop(&mut self) {
+        unsafe { ptr::drop_in_place(self.as_mut_slice()) }
+    }
+}
+
+#[unstable(feature = "array_value_iter", issue = "0")]
+impl<T: Clone, A: FixedSizeArray<T>> Clone for IntoIter<T, A> {
+    fn clone(&self) -> IntoIter<T, A> {
+        // Only the clones of the alive elements get written, so `alive`
+        // grows along with them in case `clone` panics.
+        let mut new = IntoIter {
+            data: ManuallyDrop::new(unsafe { mem::uninitialized() }),
+            alive: self.alive.start..self.alive.start,
+            _marker: PhantomData,
+        };
+        for (src, idx) in self.as_slice().iter().zip(self.alive.clone()) {
+            unsafe {
+                ptr::write(new.data.as_mut_slice().get_unchecked_mut(idx), src.clone());
+            }
+            new.alive.end += 1;
+        }
+        new
+    }
+}
+
+#[unstable(feature = "array_value_iter", issue = "0")]
+impl<T: fmt::Debug, A: FixedSizeArray<T>> fmt::Debug for IntoIter<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("IntoIter")
+            .field(&self.as_slice())
+            .finish()
+    }
+}
+
 macro_rules! __impl_slice_eq1 {
     ($Lhs: ty, $Rhs: ty) => {
         __impl_slice_eq1! { $Lhs, $Rhs, Sized }
@@ -93,6 +542,49 @@ macro_rules! __impl_slice_eq2 {
 macro_rules! array_impls {
     ($($N:expr)+) => {
         $(
+            #[unstable(feature = "array_each_ref", issue = "0")]
+            impl<'a, T: 'a> ArrayEachRef<'a, T> for [T; $N] {
+                type Refs = [&'a T; $N];
+                type Muts = [&'a mut T; $N];
+
+                #[inline]
+                fn each_ref(&'a self) -> [&'a T; $N] {
+                    let mut elements = self.iter();
+                    from_fn(|_| elements.next().unwrap())
+                }
+
+                #[inline]
+                fn each_mut(&'a mut self) -> [&'a mut T; $N] {
+                    let mut elements = self.iter_mut();
+                    from_fn(|_| elements.next().unwrap())
+                }
+            }
+
+            #[unstable(feature = "array_from_fn", issue = "0")]
+            impl<T> ArrayFromFn<T> for [T; $N] {
+                #[inline]
+                fn from_fn<F: FnMut(usize) -> T>(mut f: F) -> [T; $N] {
+                    let mut i = 0;
+                    [(); $N].map(|()| {
+                        let x = f(i);
+                        i += 1;
+                        x
+                    })
+                }
+
+                #[inline]
+                fn try_from_fn<E, F>(mut f: F) -> Result<[T; $N], E>
+                    where F: FnMut(usize) -> Result<T, E>
+                {
+                    let mut i = 0;
+                    [(); $N].try_map(|()| {
+                        let x = f(i);
+                        i += 1;
+                        x
+                    })
+                }
+            }
+
             #[stable(feature = "rust1", since = "1.0.0")]
             impl<T> AsRef<[T]> for [T; $N] {
                 #[inline]
@@ -239,3 +731,49 @@ macro_rules! array_impl_default {
 }
 
 array_impl_default!{32, T T T T T T T T T T T T T T T T T T T T T T T T T T T T T T T T}
+
+// Like Default, mapping can't be generated by array_impls! because moving the
+// elements out needs one binding per element.
+
+macro_rules! array_impl_map {
+    {$n:expr, $t:ident $($ts:ident)*} => {
+        #[unstable(feature = "array_map", issue = "0")]
+        impl<T, U> ArrayMap<T, U> for [T; $n] {
+            type Output = [U; $n];
+
+            #[inline]
+            fn map<F: FnMut(T) -> U>(self, mut f: F) -> [U; $n] {
+                let [$t, $($ts),*] = self;
+                [f($t), $(f($ts)),*]
+            }
+
+            #[inline]
+            fn try_map<E, F>(self, mut f: F) -> Result<[U; $n], E>
+                where F: FnMut(T) -> Result<U, E>
+            {
+                let [$t, $($ts),*] = self;
+                Ok([f($t)?, $(f($ts)?),*])
+            }
+        }
+        array_impl_map!{($n - 1), $($ts)*}
+    };
+    {$n:expr,} => {
+        #[unstable(feature = "array_map", issue = "0")]
+        impl<T, U> ArrayMap<T, U> for [T; $n] {
+            type Output = [U; $n];
+
+            #[inline]
+            fn map<F: FnMut(T) -> U>(self, _f: F) -> [U; $n] { [] }
+
+            #[inline]
+            fn try_map<E, F>(self, _f: F) -> Result<[U; $n], E>
+                where F: FnMut(T) -> Result<U, E>
+            {
+                Ok([])
+            }
+        }
+    };
+}
+
+array_impl_map!{32, e0 e1 e2 e3 e4 e5 e6 e7 e8 e9 e10 e11 e12 e13 e14 e15
+                    e16 e17 e18 e19 e20 e21 e22 e23 e24 e25 e26 e27 e28 e29 e30 e31}
@@ -0,0 +1,159 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Operations on ASCII strings and characters.
+//!
+//! The [`Char`] type represents a single 7-bit ASCII character. Text that is
+//! known to be ASCII can be handled as a `&[Char]`, which converts to `&str`
+//! and `&[u8]` for free, without any further validation.
+//!
+//! [`Char`]: struct.Char.html
+
+#![unstable(feature = "ascii_char", issue = "0")]
+
+use fmt;
+use mem;
+use slice;
+use str;
+
+/// One of the 128 Unicode characters from U+0000 through U+007F,
+/// often known as the [ASCII] subset.
+///
+/// A `Char` has the same layout as a `u8` that is known to be less than 128,
+/// so a slice of them can be viewed as a `str` or a byte slice at no cost.
+///
+/// Values are created with [`Char::from_u8`], or with the `as_ascii`
+/// methods on `u8`, `char` and `str`.
+///
+/// [ASCII]: https://www.unicode.org/glossary/index.html#ASCII
+/// [`Char::from_u8`]: #method.from_u8
+///
+/// # Examples
+///
+/// ```
+/// #![feature(ascii_char)]
+/// use std::ascii::Char;
+///
+/// let text = "GET /index.html".as_ascii().unwrap();
+/// assert_eq!(text[0], Char::from_u8(b'G').unwrap());
+/// assert_eq!(Char::slice_as_str(&text[4..]), "/index.html");
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct Char(u8);
+
+impl Char {
+    /// Creates an ASCII character from the byte `b`,
+    /// or returns `None` if it's too large.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_char)]
+    /// use std::ascii::Char;
+    ///
+    /// assert_eq!(Char::from_u8(b'a').map(Char::to_char), Some('a'));
+    /// assert_eq!(Char::from_u8(0x80), None);
+    /// ```
+    #[inline]
+    pub fn from_u8(b: u8) -> Option<Char> {
+        if b < 128 {
+            Some(Char(b))
+        } else {
+            None
+        }
+    }
+
+    /// Creates an ASCII character from the byte `b`,
+    /// without checking whether it's valid.
+    ///
+    /// # Safety
+    ///
+    /// `b` must be in `0..128`, or else this is UB.
+    #[inline]
+    pub unsafe fn from_u8_unchecked(b: u8) -> Char {
+        Char(b)
+    }
+
+    /// Gets this ASCII character as a byte.
+    #[inline]
+    pub fn to_u8(self) -> u8 {
+        self.0
+    }
+
+    /// Gets this ASCII character as a `char` Unicode Scalar Value.
+    #[inline]
+    pub fn to_char(self) -> char {
+        self.0 as char
+    }
+
+    /// Views this ASCII character as a one-code-unit UTF-8 `str`.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        unsafe { str::from_utf8_unchecked(slice::from_raw_parts(&self.0, 1)) }
+    }
+
+    /// Views a slice of ASCII characters as a UTF-8 `str`.
+    ///
+    /// ASCII is a subset of UTF-8, so this can't fail and doesn't need to
+    /// look at the data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_char)]
+    /// use std::ascii::Char;
+    ///
+    /// let header = "Content-Length".as_ascii().unwrap();
+    /// assert_eq!(Char::slice_as_str(header), "Content-Length");
+    /// assert_eq!(Char::slice_as_bytes(header), b"Content-Length");
+    /// ```
+    #[inline]
+    pub fn slice_as_str(s: &[Char]) -> &str {
+        unsafe { str::from_utf8_unchecked(Char::slice_as_bytes(s)) }
+    }
+
+    /// Views a slice of ASCII characters as a slice of `u8` bytes.
+    #[inline]
+    pub fn slice_as_bytes(s: &[Char]) -> &[u8] {
+        // `Char` is a `repr(C)` wrapper around a `u8`.
+        unsafe { mem::transmute(s) }
+    }
+}
+
+#[unstable(feature = "ascii_char", issue = "0")]
+impl From<Char> for u8 {
+    #[inline]
+    fn from(c: Char) -> u8 {
+        c.to_u8()
+    }
+}
+
+#[unstable(feature = "ascii_char", issue = "0")]
+impl From<Char> for char {
+    #[inline]
+    fn from(c: Char) -> char {
+        c.to_char()
+    }
+}
+
+#[unstable(feature = "ascii_char", issue = "0")]
+impl fmt::Display for Char {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+#[unstable(feature = "ascii_char", issue = "0")]
+impl fmt::Debug for Char {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_char(), f)
+    }
+}
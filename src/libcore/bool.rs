@@ -0,0 +1,72 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! impl bool {}
+
+#[cfg(not(stage0))]
+#[lang = "bool"]
+impl bool {
+    /// Returns `Some(t)` if the `bool` is `true`, or `None` otherwise.
+    ///
+    /// The argument is evaluated eagerly; if you don't want to compute it
+    /// when the `bool` is `false`, use [`then`], which takes a closure.
+    ///
+    /// [`then`]: #method.then
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(bool_to_option)]
+    ///
+    /// assert_eq!(false.then_some(0), None);
+    /// assert_eq!(true.then_some(0), Some(0));
+    /// ```
+    ///
+    /// Keeping only the even numbers:
+    ///
+    /// ```
+    /// #![feature(bool_to_option)]
+    ///
+    /// let evens: Vec<i32> = (1..7).filter_map(|x| (x % 2 == 0).then_some(x)).collect();
+    /// assert_eq!(evens, [2, 4, 6]);
+    /// ```
+    #[unstable(feature = "bool_to_option", issue = "0")]
+    #[inline]
+    pub fn then_some<T>(self, t: T) -> Option<T> {
+        if self { Some(t) } else { None }
+    }
+
+    /// Returns `Some(f())` if the `bool` is `true`, or `None` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(bool_to_option)]
+    ///
+    /// assert_eq!(false.then(|| 0), None);
+    /// assert_eq!(true.then(|| 0), Some(0));
+    /// ```
+    ///
+    /// The closure is only called when the `bool` is `true`:
+    ///
+    /// ```
+    /// #![feature(bool_to_option)]
+    ///
+    /// let mut calls = 0;
+    /// assert_eq!(false.then(|| { calls += 1; calls }), None);
+    /// assert_eq!(true.then(|| { calls += 1; calls }), Some(1));
+    /// assert_eq!(calls, 1);
+    /// ```
+    #[unstable(feature = "bool_to_option", issue = "0")]
+    #[inline]
+    pub fn then<T, F: FnOnce() -> T>(self, f: F) -> Option<T> {
+        if self { Some(f()) } else { None }
+    }
+}
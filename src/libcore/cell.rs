@@ -218,7 +218,7 @@ use ptr;
 ///
 /// See the [module-level documentation](index.html) for more.
 #[stable(feature = "rust1", since = "1.0.0")]
-pub struct Cell<T> {
+pub struct Cell<T: ?Sized> {
     value: UnsafeCell<T>,
 }
 
@@ -239,13 +239,38 @@ impl<T:Copy> Cell<T> {
     pub fn get(&self) -> T {
         unsafe{ *self.value.get() }
     }
+
+    /// Updates the contained value using a function and returns the new value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(cell_update)]
+    ///
+    /// use std::cell::Cell;
+    ///
+    /// let c = Cell::new(5);
+    /// let new = c.update(|x| x + 1);
+    ///
+    /// assert_eq!(new, 6);
+    /// assert_eq!(c.get(), 6);
+    /// ```
+    #[inline]
+    #[unstable(feature = "cell_update", issue = "0")]
+    pub fn update<F>(&self, f: F) -> T
+        where F: FnOnce(T) -> T
+    {
+        let new = f(self.get());
+        self.set(new);
+        new
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
-unsafe impl<T> Send for Cell<T> where T: Send {}
+unsafe impl<T: ?Sized> Send for Cell<T> where T: Send {}
 
 #[stable(feature = "rust1", since = "1.0.0")]
-impl<T> !Sync for Cell<T> {}
+impl<T: ?Sized> !Sync for Cell<T> {}
 
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<T:Copy> Clone for Cell<T> {
@@ -477,6 +502,36 @@ impl<T: Default> Cell<T> {
 #[unstable(feature = "coerce_unsized", issue = "27732")]
 impl<T: CoerceUnsized<U>, U> CoerceUnsized<Cell<U>> for Cell<T> {}
 
+impl<T> Cell<[T]> {
+    /// Returns a `&[Cell<T>]` from a `&Cell<[T]>`.
+    ///
+    /// This lets each element of a shared slice be set separately.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(as_cell)]
+    ///
+    /// use std::cell::Cell;
+    ///
+    /// let cell = Cell::new([1, 2, 3]);
+    /// let slice: &Cell<[i32]> = &cell;
+    /// let slice_cell: &[Cell<i32>] = slice.as_slice_of_cells();
+    ///
+    /// slice_cell[1].set(5);
+    /// assert_eq!(slice_cell.len(), 3);
+    /// assert_eq!(cell.get(), [1, 5, 3]);
+    /// ```
+    #[unstable(feature = "as_cell", issue = "0")]
+    #[inline]
+    pub fn as_slice_of_cells(&self) -> &[Cell<T>] {
+        // `Cell<T>` has the same memory layout as `T`.
+        unsafe {
+            &*(self as *const Cell<[T]> as *const [Cell<T>])
+        }
+    }
+}
+
 /// A mutable memory location with dynamically checked borrow rules
 ///
 /// See the [module-level documentation](index.html) for more.
@@ -571,6 +626,57 @@ impl<T> RefCell<T> {
         debug_assert!(self.borrow.get() == UNUSED);
         unsafe { self.value.into_inner() }
     }
+
+    /// Replaces the wrapped value with a new one computed from `f`, returning
+    /// the old value, without deinitializing either one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is currently borrowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(refcell_replace_with)]
+    /// use std::cell::RefCell;
+    ///
+    /// let cell = RefCell::new(5);
+    /// let old_value = cell.replace_with(|&mut old| old + 1);
+    /// assert_eq!(old_value, 5);
+    /// assert_eq!(cell, RefCell::new(6));
+    /// ```
+    #[unstable(feature = "refcell_replace_with", issue = "0")]
+    #[inline]
+    pub fn replace_with<F: FnOnce(&mut T) -> T>(&self, f: F) -> T {
+        let mut_borrow = &mut *self.borrow_mut();
+        let replacement = f(mut_borrow);
+        mem::replace(mut_borrow, replacement)
+    }
+}
+
+impl<T: Default> RefCell<T> {
+    /// Takes the wrapped value, leaving `Default::default()` in its place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is currently borrowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(refcell_take)]
+    /// use std::cell::RefCell;
+    ///
+    /// let c = RefCell::new(5);
+    /// let five = c.take();
+    ///
+    /// assert_eq!(five, 5);
+    /// assert_eq!(c.into_inner(), 0);
+    /// ```
+    #[unstable(feature = "refcell_take", issue = "0")]
+    pub fn take(&self) -> T {
+        self.replace_with(|_| Default::default())
+    }
 }
 
 impl<T: ?Sized> RefCell<T> {
@@ -1076,6 +1182,350 @@ impl<'a, T: ?Sized + fmt::Display> fmt::Display for RefMut<'a, T> {
     }
 }
 
+/// A cell which can be written to only once.
+///
+/// Unlike `RefCell<T>`, a `OnceCell<T>` only hands out shared references to its value, and unlike
+/// `Cell<T>`, getting at the value doesn't require copying or replacing it. Once the cell has been
+/// initialized, the value can't be changed without a `&mut` reference to the cell.
+///
+/// Like `Cell<T>` and `RefCell<T>`, `OnceCell<T>` is for single-threaded use and is not `Sync`.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(once_cell)]
+/// use std::cell::OnceCell;
+///
+/// let cell = OnceCell::new();
+/// assert!(cell.get().is_none());
+///
+/// let value: &String = cell.get_or_init(|| "Hello, World!".to_string());
+/// assert_eq!(value, "Hello, World!");
+/// assert!(cell.get().is_some());
+/// ```
+#[unstable(feature = "once_cell", issue = "0")]
+pub struct OnceCell<T> {
+    // Invariant: written to at most once, and never while a reference to the value exists.
+    inner: UnsafeCell<Option<T>>,
+}
+
+impl<T> OnceCell<T> {
+    /// Creates a new empty cell.
+    #[unstable(feature = "once_cell", issue = "0")]
+    #[inline]
+    pub const fn new() -> OnceCell<T> {
+        OnceCell { inner: UnsafeCell::new(None) }
+    }
+
+    /// Gets a reference to the value, or `None` if the cell is empty.
+    #[unstable(feature = "once_cell", issue = "0")]
+    #[inline]
+    pub fn get(&self) -> Option<&T> {
+        // Safe because the value is never written to once it's there.
+        unsafe { (*self.inner.get()).as_ref() }
+    }
+
+    /// Gets a mutable reference to the value, or `None` if the cell is empty.
+    ///
+    /// This call borrows the cell mutably, so no other reference to the value can exist.
+    #[unstable(feature = "once_cell", issue = "0")]
+    #[inline]
+    pub fn get_mut(&mut self) -> Option<&mut T> {
+        unsafe { (*self.inner.get()).as_mut() }
+    }
+
+    /// Sets the contents of the cell to `value`.
+    ///
+    /// Returns `Err(value)` if the cell was already initialized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(once_cell)]
+    /// use std::cell::OnceCell;
+    ///
+    /// let cell = OnceCell::new();
+    /// assert!(cell.get().is_none());
+    ///
+    /// assert_eq!(cell.set(92), Ok(()));
+    /// assert_eq!(cell.set(62), Err(62));
+    ///
+    /// assert_eq!(cell.get(), Some(&92));
+    /// ```
+    #[unstable(feature = "once_cell", issue = "0")]
+    pub fn set(&self, value: T) -> Result<(), T> {
+        if self.get().is_some() {
+            return Err(value);
+        }
+        // Safe because the cell is empty, so there is no reference to its value.
+        unsafe { *self.inner.get() = Some(value) };
+        Ok(())
+    }
+
+    /// Gets the contents of the cell, initializing it with `f` if the cell was empty.
+    ///
+    /// # Panics
+    ///
+    /// If `f` panics, the panic is propagated to the caller, and the cell remains
+    /// uninitialized.
+    ///
+    /// It is an error to reentrantly initialize the cell from `f`. Doing so results in a panic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(once_cell)]
+    /// use std::cell::OnceCell;
+    ///
+    /// let cell = OnceCell::new();
+    /// let value = cell.get_or_init(|| 92);
+    /// assert_eq!(value, &92);
+    /// let value = cell.get_or_init(|| unreachable!());
+    /// assert_eq!(value, &92);
+    /// ```
+    #[unstable(feature = "once_cell", issue = "0")]
+    pub fn get_or_init<F>(&self, f: F) -> &T
+        where F: FnOnce() -> T
+    {
+        if let Some(value) = self.get() {
+            return value;
+        }
+        let value = f();
+        if self.set(value).is_err() {
+            panic!("reentrant init");
+        }
+        self.get().unwrap()
+    }
+
+    /// Gets the contents of the cell, initializing it with `f` if the cell was empty. If the
+    /// cell was empty and `f` failed, the error is returned and the cell stays empty.
+    ///
+    /// # Panics
+    ///
+    /// If `f` panics, the panic is propagated to the caller, and the cell remains
+    /// uninitialized.
+    ///
+    /// It is an error to reentrantly initialize the cell from `f`. Doing so results in a panic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(once_cell)]
+    /// use std::cell::OnceCell;
+    ///
+    /// let cell = OnceCell::new();
+    /// assert_eq!(cell.get_or_try_init(|| Err(())), Err(()));
+    /// assert!(cell.get().is_none());
+    /// let value = cell.get_or_try_init(|| -> Result<i32, ()> {
+    ///     Ok(92)
+    /// });
+    /// assert_eq!(value, Ok(&92));
+    /// assert_eq!(cell.get(), Some(&92))
+    /// ```
+    #[unstable(feature = "once_cell", issue = "0")]
+    pub fn get_or_try_init<F, E>(&self, f: F) -> Result<&T, E>
+        where F: FnOnce() -> Result<T, E>
+    {
+        if let Some(value) = self.get() {
+            return Ok(value);
+        }
+        let value = f()?;
+        if self.set(value).is_err() {
+            panic!("reentrant init");
+        }
+        Ok(self.get().unwrap())
+    }
+
+    /// Consumes the cell, returning the wrapped value.
+    ///
+    /// Returns `None` if the cell was empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(once_cell)]
+    /// use std::cell::OnceCell;
+    ///
+    /// let cell: OnceCell<String> = OnceCell::new();
+    /// assert_eq!(cell.into_inner(), None);
+    ///
+    /// let cell = OnceCell::new();
+    /// cell.set("hello".to_string()).unwrap();
+    /// assert_eq!(cell.into_inner(), Some("hello".to_string()));
+    /// ```
+    #[unstable(feature = "once_cell", issue = "0")]
+    #[inline]
+    pub fn into_inner(self) -> Option<T> {
+        unsafe { self.inner.into_inner() }
+    }
+
+    /// Takes the value out of this `OnceCell`, moving it back to an uninitialized state.
+    ///
+    /// Has no effect and returns `None` if the `OnceCell` hasn't been initialized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(once_cell)]
+    /// use std::cell::OnceCell;
+    ///
+    /// let mut cell: OnceCell<String> = OnceCell::new();
+    /// assert_eq!(cell.take(), None);
+    ///
+    /// let mut cell = OnceCell::new();
+    /// cell.set("hello".to_string()).unwrap();
+    /// assert_eq!(cell.take(), Some("hello".to_string()));
+    /// assert_eq!(cell.get(), None);
+    /// ```
+    #[unstable(feature = "once_cell", issue = "0")]
+    #[inline]
+    pub fn take(&mut self) -> Option<T> {
+        mem::replace(self, OnceCell::new()).into_inner()
+    }
+}
+
+#[unstable(feature = "once_cell", issue = "0")]
+impl<T> Default for OnceCell<T> {
+    fn default() -> OnceCell<T> {
+        OnceCell::new()
+    }
+}
+
+#[unstable(feature = "once_cell", issue = "0")]
+impl<T: Debug> Debug for OnceCell<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.get() {
+            Some(value) => f.debug_tuple("OnceCell").field(value).finish(),
+            None => f.write_str("OnceCell(<uninit>)"),
+        }
+    }
+}
+
+#[unstable(feature = "once_cell", issue = "0")]
+impl<T: Clone> Clone for OnceCell<T> {
+    fn clone(&self) -> OnceCell<T> {
+        let cell = OnceCell::new();
+        if let Some(value) = self.get() {
+            let _ = cell.set(value.clone());
+        }
+        cell
+    }
+}
+
+#[unstable(feature = "once_cell", issue = "0")]
+impl<T: PartialEq> PartialEq for OnceCell<T> {
+    fn eq(&self, other: &OnceCell<T>) -> bool {
+        self.get() == other.get()
+    }
+}
+
+#[unstable(feature = "once_cell", issue = "0")]
+impl<T: Eq> Eq for OnceCell<T> {}
+
+#[unstable(feature = "once_cell", issue = "0")]
+impl<T> From<T> for OnceCell<T> {
+    fn from(value: T) -> OnceCell<T> {
+        OnceCell { inner: UnsafeCell::new(Some(value)) }
+    }
+}
+
+/// A value which is initialized on the first access.
+///
+/// The initialization function is run at most once, by the first dereference or call to
+/// [`force`]. Like `OnceCell<T>`, a `LazyCell` is for single-threaded use.
+///
+/// [`force`]: #method.force
+///
+/// # Examples
+///
+/// ```
+/// #![feature(once_cell)]
+/// use std::cell::LazyCell;
+///
+/// let lazy = LazyCell::new(|| {
+///     println!("initializing");
+///     92
+/// });
+/// println!("ready");
+/// println!("{}", *lazy);
+/// println!("{}", *lazy);
+///
+/// // Prints:
+/// //   ready
+/// //   initializing
+/// //   92
+/// //   92
+/// ```
+#[unstable(feature = "once_cell", issue = "0")]
+pub struct LazyCell<T, F = fn() -> T> {
+    cell: OnceCell<T>,
+    init: Cell<Option<F>>,
+}
+
+impl<T, F> LazyCell<T, F> {
+    /// Creates a new lazy value with the given initializing function.
+    #[unstable(feature = "once_cell", issue = "0")]
+    #[inline]
+    pub const fn new(init: F) -> LazyCell<T, F> {
+        LazyCell { cell: OnceCell::new(), init: Cell::new(Some(init)) }
+    }
+}
+
+impl<T, F: FnOnce() -> T> LazyCell<T, F> {
+    /// Forces the evaluation of this lazy value and returns a reference to the result.
+    ///
+    /// This is equivalent to the `Deref` impl, but is explicit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the initializing function panicked on an earlier access, since it has been
+    /// consumed and the cell was left empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(once_cell)]
+    /// use std::cell::LazyCell;
+    ///
+    /// let lazy = LazyCell::new(|| 92);
+    ///
+    /// assert_eq!(LazyCell::force(&lazy), &92);
+    /// assert_eq!(&*lazy, &92);
+    /// ```
+    #[unstable(feature = "once_cell", issue = "0")]
+    pub fn force(this: &LazyCell<T, F>) -> &T {
+        this.cell.get_or_init(|| match this.init.take() {
+            Some(f) => f(),
+            None => panic!("`LazyCell` instance has previously been poisoned"),
+        })
+    }
+}
+
+#[unstable(feature = "once_cell", issue = "0")]
+impl<T, F: FnOnce() -> T> Deref for LazyCell<T, F> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        LazyCell::force(self)
+    }
+}
+
+#[unstable(feature = "once_cell", issue = "0")]
+impl<T: Default> Default for LazyCell<T> {
+    /// Creates a new lazy value using `Default` as the initializing function.
+    fn default() -> LazyCell<T> {
+        let init: fn() -> T = T::default;
+        LazyCell::new(init)
+    }
+}
+
+#[unstable(feature = "once_cell", issue = "0")]
+impl<T: Debug, F> Debug for LazyCell<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("LazyCell").field("cell", &self.cell).finish()
+    }
+}
+
 /// The core primitive for interior mutability in Rust.
 ///
 /// `UnsafeCell<T>` is a type that wraps some `T` and indicates unsafe interior operations on the
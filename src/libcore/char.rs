@@ -15,6 +15,7 @@
 #![allow(non_snake_case)]
 #![stable(feature = "core_char", since = "1.2.0")]
 
+use ascii;
 use char_private::is_printable;
 use convert::TryFrom;
 use fmt::{self, Write};
@@ -301,9 +302,16 @@ impl fmt::Display for CharTryFromError {
 /// `from_digit()` will return `None` if the input is not a digit in
 /// the given radix.
 ///
+/// Up to radix 36, digits past `9` are the lowercase letters `a-z`. Radices
+/// from 37 to 62 are case-sensitive and use the alphabet `0-9`, `A-Z`, `a-z`
+/// in that order, so `A` is ten and `a` is thirty-six; this is the alphabet
+/// of most base-62 encodings. See [`to_digit`] for the inverse.
+///
+/// [`to_digit`]: ../../std/primitive.char.html#method.to_digit
+///
 /// # Panics
 ///
-/// Panics if given a radix larger than 36.
+/// Panics if given a radix larger than 62.
 ///
 /// # Examples
 ///
@@ -332,6 +340,17 @@ impl fmt::Display for CharTryFromError {
 /// assert_eq!(None, c);
 /// ```
 ///
+/// Radices above 36 tell the letter cases apart:
+///
+/// ```
+/// use std::char;
+///
+/// assert_eq!(char::from_digit(10, 36), Some('a'));
+/// assert_eq!(char::from_digit(10, 62), Some('A'));
+/// assert_eq!(char::from_digit(36, 62), Some('a'));
+/// assert_eq!(char::from_digit(61, 62), Some('z'));
+/// ```
+///
 /// Passing a large radix, causing a panic:
 ///
 /// ```
@@ -340,7 +359,7 @@ impl fmt::Display for CharTryFromError {
 ///
 /// let result = thread::spawn(|| {
 ///     // this panics
-///     let c = char::from_digit(1, 37);
+///     let c = char::from_digit(1, 63);
 /// }).join();
 ///
 /// assert!(result.is_err());
@@ -348,15 +367,19 @@ impl fmt::Display for CharTryFromError {
 #[inline]
 #[stable(feature = "rust1", since = "1.0.0")]
 pub fn from_digit(num: u32, radix: u32) -> Option<char> {
-    if radix > 36 {
-        panic!("from_digit: radix is too high (maximum 36)");
+    if radix > 62 {
+        panic!("from_digit: radix is too high (maximum 62)");
     }
     if num < radix {
         let num = num as u8;
         if num < 10 {
             Some((b'0' + num) as char)
-        } else {
+        } else if radix <= 36 {
             Some((b'a' + num - 10) as char)
+        } else if num < 36 {
+            Some((b'A' + num - 10) as char)
+        } else {
+            Some((b'a' + num - 36) as char)
         }
     } else {
         None
@@ -389,6 +412,12 @@ pub trait CharExt {
     fn encode_utf8(self, dst: &mut [u8]) -> &mut str;
     #[stable(feature = "unicode_encode_char", since = "1.15.0")]
     fn encode_utf16(self, dst: &mut [u16]) -> &mut [u16];
+    #[unstable(feature = "try_encode_char", issue = "0")]
+    fn try_encode_utf8(self, dst: &mut [u8]) -> Option<&mut str>;
+    #[unstable(feature = "try_encode_char", issue = "0")]
+    fn try_encode_utf16(self, dst: &mut [u16]) -> Option<&mut [u16]>;
+    #[unstable(feature = "ascii_char", issue = "0")]
+    fn as_ascii(&self) -> Option<ascii::Char>;
 }
 
 #[stable(feature = "core", since = "1.6.0")]
@@ -400,16 +429,20 @@ impl CharExt for char {
 
     #[inline]
     fn to_digit(self, radix: u32) -> Option<u32> {
-        if radix > 36 {
-            panic!("to_digit: radix is too high (maximum 36)");
+        // If not a digit, a number greater than radix will be created.
+        let mut digit = (self as u32).wrapping_sub('0' as u32);
+        if radix > 10 {
+            if radix > 36 {
+                return to_digit_case_sensitive(self, radix);
+            }
+            if digit < 10 {
+                return Some(digit);
+            }
+            // Setting the 6th bit maps ASCII uppercase letters onto lowercase
+            // ones, and anything that wasn't a letter stays out of range.
+            digit = (self as u32 | 0x20).wrapping_sub('a' as u32).saturating_add(10);
         }
-        let val = match self {
-          '0' ... '9' => self as u32 - '0' as u32,
-          'a' ... 'z' => self as u32 - 'a' as u32 + 10,
-          'A' ... 'Z' => self as u32 - 'A' as u32 + 10,
-          _ => return None,
-        };
-        if val < radix { Some(val) }
+        if digit < radix { Some(digit) }
         else { None }
     }
 
@@ -479,6 +512,30 @@ impl CharExt for char {
 
     #[inline]
     fn encode_utf8(self, dst: &mut [u8]) -> &mut str {
+        let dst_len = dst.len();
+        match self.try_encode_utf8(dst) {
+            Some(s) => s,
+            None => panic!("encode_utf8: need {} bytes to encode U+{:X}, but the buffer has {}",
+                           self.len_utf8(),
+                           self as u32,
+                           dst_len),
+        }
+    }
+
+    #[inline]
+    fn encode_utf16(self, dst: &mut [u16]) -> &mut [u16] {
+        let dst_len = dst.len();
+        match self.try_encode_utf16(dst) {
+            Some(s) => s,
+            None => panic!("encode_utf16: need {} units to encode U+{:X}, but the buffer has {}",
+                           self.len_utf16(),
+                           self as u32,
+                           dst_len),
+        }
+    }
+
+    #[inline]
+    fn try_encode_utf8(self, dst: &mut [u8]) -> Option<&mut str> {
         let code = self as u32;
         unsafe {
             let len =
@@ -501,37 +558,57 @@ impl CharExt for char {
                 *dst.get_unchecked_mut(3) = (code & 0x3F) as u8 | TAG_CONT;
                 4
             } else {
-                panic!("encode_utf8: need {} bytes to encode U+{:X}, but the buffer has {}",
-                    from_u32_unchecked(code).len_utf8(),
-                    code,
-                    dst.len())
+                return None;
             };
-            from_utf8_unchecked_mut(dst.get_unchecked_mut(..len))
+            Some(from_utf8_unchecked_mut(dst.get_unchecked_mut(..len)))
         }
     }
 
     #[inline]
-    fn encode_utf16(self, dst: &mut [u16]) -> &mut [u16] {
+    fn try_encode_utf16(self, dst: &mut [u16]) -> Option<&mut [u16]> {
         let mut code = self as u32;
         unsafe {
             if (code & 0xFFFF) == code && !dst.is_empty() {
                 // The BMP falls through (assuming non-surrogate, as it should)
                 *dst.get_unchecked_mut(0) = code as u16;
-                slice::from_raw_parts_mut(dst.as_mut_ptr(), 1)
+                Some(slice::from_raw_parts_mut(dst.as_mut_ptr(), 1))
             } else if dst.len() >= 2 {
                 // Supplementary planes break into surrogates.
                 code -= 0x1_0000;
                 *dst.get_unchecked_mut(0) = 0xD800 | ((code >> 10) as u16);
                 *dst.get_unchecked_mut(1) = 0xDC00 | ((code as u16) & 0x3FF);
-                slice::from_raw_parts_mut(dst.as_mut_ptr(), 2)
+                Some(slice::from_raw_parts_mut(dst.as_mut_ptr(), 2))
             } else {
-                panic!("encode_utf16: need {} units to encode U+{:X}, but the buffer has {}",
-                    from_u32_unchecked(code).len_utf16(),
-                    code,
-                    dst.len())
+                None
             }
         }
     }
+
+    #[inline]
+    fn as_ascii(&self) -> Option<ascii::Char> {
+        if (*self as u32) < 128 {
+            Some(unsafe { ascii::Char::from_u8_unchecked(*self as u8) })
+        } else {
+            None
+        }
+    }
+}
+
+/// The slow path of `to_digit` for radices above 36, where the letter cases
+/// are distinct digits, with the lowercase ones following the uppercase ones.
+#[inline(never)]
+fn to_digit_case_sensitive(c: char, radix: u32) -> Option<u32> {
+    if radix > 62 {
+        panic!("to_digit: radix is too high (maximum 62)");
+    }
+    let val = match c {
+        '0' ... '9' => c as u32 - '0' as u32,
+        'A' ... 'Z' => c as u32 - 'A' as u32 + 10,
+        'a' ... 'z' => c as u32 - 'a' as u32 + 36,
+        _ => return None,
+    };
+    if val < radix { Some(val) }
+    else { None }
 }
 
 /// Returns an iterator that yields the hexadecimal Unicode escape of a
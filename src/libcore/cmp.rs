@@ -217,6 +217,114 @@ pub enum Ordering {
 }
 
 impl Ordering {
+    /// Returns `true` if the ordering is the `Equal` variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ordering_helpers)]
+    /// use std::cmp::Ordering;
+    ///
+    /// assert_eq!(Ordering::Less.is_eq(), false);
+    /// assert_eq!(Ordering::Equal.is_eq(), true);
+    /// assert_eq!(Ordering::Greater.is_eq(), false);
+    /// ```
+    #[inline]
+    #[unstable(feature = "ordering_helpers", issue = "0")]
+    pub fn is_eq(self) -> bool {
+        match self { Equal => true, _ => false }
+    }
+
+    /// Returns `true` if the ordering is not the `Equal` variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ordering_helpers)]
+    /// use std::cmp::Ordering;
+    ///
+    /// assert_eq!(Ordering::Less.is_ne(), true);
+    /// assert_eq!(Ordering::Equal.is_ne(), false);
+    /// assert_eq!(Ordering::Greater.is_ne(), true);
+    /// ```
+    #[inline]
+    #[unstable(feature = "ordering_helpers", issue = "0")]
+    pub fn is_ne(self) -> bool {
+        match self { Equal => false, _ => true }
+    }
+
+    /// Returns `true` if the ordering is the `Less` variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ordering_helpers)]
+    /// use std::cmp::Ordering;
+    ///
+    /// assert_eq!(Ordering::Less.is_lt(), true);
+    /// assert_eq!(Ordering::Equal.is_lt(), false);
+    /// assert_eq!(Ordering::Greater.is_lt(), false);
+    /// ```
+    #[inline]
+    #[unstable(feature = "ordering_helpers", issue = "0")]
+    pub fn is_lt(self) -> bool {
+        match self { Less => true, _ => false }
+    }
+
+    /// Returns `true` if the ordering is the `Greater` variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ordering_helpers)]
+    /// use std::cmp::Ordering;
+    ///
+    /// assert_eq!(Ordering::Less.is_gt(), false);
+    /// assert_eq!(Ordering::Equal.is_gt(), false);
+    /// assert_eq!(Ordering::Greater.is_gt(), true);
+    /// ```
+    #[inline]
+    #[unstable(feature = "ordering_helpers", issue = "0")]
+    pub fn is_gt(self) -> bool {
+        match self { Greater => true, _ => false }
+    }
+
+    /// Returns `true` if the ordering is either the `Less` or `Equal` variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ordering_helpers)]
+    /// use std::cmp::Ordering;
+    ///
+    /// assert_eq!(Ordering::Less.is_le(), true);
+    /// assert_eq!(Ordering::Equal.is_le(), true);
+    /// assert_eq!(Ordering::Greater.is_le(), false);
+    /// ```
+    #[inline]
+    #[unstable(feature = "ordering_helpers", issue = "0")]
+    pub fn is_le(self) -> bool {
+        match self { Greater => false, _ => true }
+    }
+
+    /// Returns `true` if the ordering is either the `Greater` or `Equal` variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ordering_helpers)]
+    /// use std::cmp::Ordering;
+    ///
+    /// assert_eq!(Ordering::Less.is_ge(), false);
+    /// assert_eq!(Ordering::Equal.is_ge(), true);
+    /// assert_eq!(Ordering::Greater.is_ge(), true);
+    /// ```
+    #[inline]
+    #[unstable(feature = "ordering_helpers", issue = "0")]
+    pub fn is_ge(self) -> bool {
+        match self { Less => false, _ => true }
+    }
+
     /// Reverses the `Ordering`.
     ///
     /// * `Less` becomes `Greater`.
@@ -758,6 +866,195 @@ pub fn max<T: Ord>(v1: T, v2: T) -> T {
     v1.max(v2)
 }
 
+/// Returns the minimum of two values with respect to the specified comparison function.
+///
+/// Returns the first argument if the comparison determines them to be equal.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(cmp_min_max_by)]
+/// use std::cmp;
+///
+/// let cmp_float = |x: &f64, y: &f64| x.partial_cmp(y).unwrap();
+/// assert_eq!(cmp::min_by(2.5, -1.0, &cmp_float), -1.0);
+/// assert_eq!(cmp::min_by(-0.0, 0.0, &cmp_float).is_sign_negative(), true);
+/// ```
+#[inline]
+#[unstable(feature = "cmp_min_max_by", issue = "0")]
+pub fn min_by<T, F: FnOnce(&T, &T) -> Ordering>(v1: T, v2: T, compare: F) -> T {
+    match compare(&v1, &v2) {
+        Ordering::Less | Ordering::Equal => v1,
+        Ordering::Greater => v2,
+    }
+}
+
+/// Returns the element that gives the minimum value from the specified function.
+///
+/// Returns the first argument if the comparison determines them to be equal.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(cmp_min_max_by)]
+/// use std::cmp;
+///
+/// assert_eq!(cmp::min_by_key(-2, 1, |x: &i32| x.abs()), 1);
+/// assert_eq!(cmp::min_by_key(-2, 2, |x: &i32| x.abs()), -2);
+/// ```
+#[inline]
+#[unstable(feature = "cmp_min_max_by", issue = "0")]
+pub fn min_by_key<T, F: FnMut(&T) -> K, K: Ord>(v1: T, v2: T, mut f: F) -> T {
+    let (k1, k2) = (f(&v1), f(&v2));
+    if k1 <= k2 { v1 } else { v2 }
+}
+
+/// Returns the maximum of two values with respect to the specified comparison function.
+///
+/// Returns the second argument if the comparison determines them to be equal.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(cmp_min_max_by)]
+/// use std::cmp;
+///
+/// let cmp_float = |x: &f64, y: &f64| x.partial_cmp(y).unwrap();
+/// assert_eq!(cmp::max_by(2.5, -1.0, &cmp_float), 2.5);
+/// assert_eq!(cmp::max_by(-0.0, 0.0, &cmp_float).is_sign_positive(), true);
+/// ```
+#[inline]
+#[unstable(feature = "cmp_min_max_by", issue = "0")]
+pub fn max_by<T, F: FnOnce(&T, &T) -> Ordering>(v1: T, v2: T, compare: F) -> T {
+    match compare(&v1, &v2) {
+        Ordering::Less | Ordering::Equal => v2,
+        Ordering::Greater => v1,
+    }
+}
+
+/// Returns the element that gives the maximum value from the specified function.
+///
+/// Returns the second argument if the comparison determines them to be equal.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(cmp_min_max_by)]
+/// use std::cmp;
+///
+/// assert_eq!(cmp::max_by_key(-2, 1, |x: &i32| x.abs()), -2);
+/// assert_eq!(cmp::max_by_key(-2, 2, |x: &i32| x.abs()), 2);
+/// ```
+#[inline]
+#[unstable(feature = "cmp_min_max_by", issue = "0")]
+pub fn max_by_key<T, F: FnMut(&T) -> K, K: Ord>(v1: T, v2: T, mut f: F) -> T {
+    let (k1, k2) = (f(&v1), f(&v2));
+    if k2 >= k1 { v2 } else { v1 }
+}
+
+/// Compares and sorts two values, returning `[min, max]`.
+///
+/// Both extremes come out of a single comparison. Returns `[v1, v2]` if the
+/// comparison determines them to be equal, which agrees with [`min`] and
+/// [`max`].
+///
+/// [`min`]: fn.min.html
+/// [`max`]: fn.max.html
+///
+/// # Examples
+///
+/// ```
+/// #![feature(cmp_minmax)]
+/// use std::cmp;
+///
+/// assert_eq!(cmp::minmax(1, 2), [1, 2]);
+/// assert_eq!(cmp::minmax(2, 1), [1, 2]);
+///
+/// let extremes = cmp::minmax(42, 17);
+/// assert_eq!((extremes[0], extremes[1]), (17, 42));
+/// ```
+#[inline]
+#[unstable(feature = "cmp_minmax", issue = "0")]
+pub fn minmax<T: Ord>(v1: T, v2: T) -> [T; 2] {
+    if v2 < v1 { [v2, v1] } else { [v1, v2] }
+}
+
+/// Returns `[min, max]` of two values with respect to the specified
+/// comparison function.
+///
+/// Returns `[v1, v2]` if the comparison determines them to be equal.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(cmp_minmax)]
+/// use std::cmp;
+///
+/// let cmp_float = |x: &f64, y: &f64| x.partial_cmp(y).unwrap();
+/// assert_eq!(cmp::minmax_by(3.5, -1.0, cmp_float), [-1.0, 3.5]);
+/// ```
+#[inline]
+#[unstable(feature = "cmp_minmax", issue = "0")]
+pub fn minmax_by<T, F: FnOnce(&T, &T) -> Ordering>(v1: T, v2: T, compare: F) -> [T; 2] {
+    match compare(&v1, &v2) {
+        Ordering::Less | Ordering::Equal => [v1, v2],
+        Ordering::Greater => [v2, v1],
+    }
+}
+
+/// Returns `[min, max]` of two values, as determined by the specified key
+/// function.
+///
+/// Returns `[v1, v2]` if the keys are equal.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(cmp_minmax)]
+/// use std::cmp;
+///
+/// assert_eq!(cmp::minmax_by_key(-3, 2, |x: &i32| x.abs()), [2, -3]);
+/// assert_eq!(cmp::minmax_by_key(-2, 2, |x: &i32| x.abs()), [-2, 2]);
+/// ```
+#[inline]
+#[unstable(feature = "cmp_minmax", issue = "0")]
+pub fn minmax_by_key<T, F: FnMut(&T) -> K, K: Ord>(v1: T, v2: T, mut f: F) -> [T; 2] {
+    let (k1, k2) = (f(&v1), f(&v2));
+    if k2 < k1 { [v2, v1] } else { [v1, v2] }
+}
+
+/// Restricts a value to the interval between `min` and `max`.
+///
+/// Returns `max` if `v` is greater than `max`, and `min` if `v` is less than
+/// `min`. Otherwise this returns `v`.
+///
+/// # Panics
+///
+/// Panics in debug builds if `min > max`.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(clamp)]
+/// use std::cmp;
+///
+/// assert_eq!(cmp::clamp(7, 0, 5), 5);
+/// assert_eq!(cmp::clamp(-1, 0, 5), 0);
+/// assert_eq!(cmp::clamp(3, 0, 5), 3);
+/// ```
+#[inline]
+#[unstable(feature = "clamp", issue = "0")]
+pub fn clamp<T: Ord>(v: T, min: T, max: T) -> T {
+    debug_assert!(min <= max, "clamp: min is greater than max");
+    if v < min {
+        min
+    } else if v > max {
+        max
+    } else {
+        v
+    }
+}
+
 // Implementation of PartialEq, Eq, PartialOrd and Ord for primitive types
 mod impls {
     use cmp::Ordering::{self, Less, Greater, Equal};
@@ -350,6 +350,76 @@ pub trait Hasher {
     fn write_isize(&mut self, i: isize) {
         self.write_usize(i as usize)
     }
+
+    /// Writes a length prefix into this hasher, as part of being prefix-free.
+    ///
+    /// If you're implementing [`Hash`] for a custom collection, call this with
+    /// the number of elements before hashing them, instead of hashing the
+    /// length as a `usize`. That way `(vec![1], vec![2, 3])` and
+    /// `(vec![1, 2], vec![3])` feed different data to the hasher.
+    ///
+    /// Hashers that know their input is only ever used with one collection
+    /// type may encode the length more cheaply than the default, which writes
+    /// it with [`write_usize`].
+    ///
+    /// [`Hash`]: trait.Hash.html
+    /// [`write_usize`]: #method.write_usize
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(hasher_prefixfree_extras)]
+    /// use std::hash::{Hash, Hasher};
+    ///
+    /// struct Chain(Vec<u32>, Vec<u32>);
+    ///
+    /// impl Hash for Chain {
+    ///     fn hash<H: Hasher>(&self, state: &mut H) {
+    ///         state.write_length_prefix(self.0.len() + self.1.len());
+    ///         for x in self.0.iter().chain(&self.1) {
+    ///             x.hash(state);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    #[unstable(feature = "hasher_prefixfree_extras", issue = "0")]
+    fn write_length_prefix(&mut self, len: usize) {
+        self.write_usize(len);
+    }
+
+    /// Writes a single `str` into this hasher.
+    ///
+    /// This is what the [`Hash`] impl for `str` uses. The data written must be
+    /// prefix-free, so that `("a", "bc")` and `("ab", "c")` hash differently.
+    /// The default writes the bytes of the string followed by `0xff`, which
+    /// can't occur in UTF-8. A hasher may override this with any other
+    /// prefix-free encoding, e.g. a length prefix, or a single call that
+    /// combines both writes.
+    ///
+    /// [`Hash`]: trait.Hash.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(hasher_prefixfree_extras)]
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::{Hash, Hasher};
+    ///
+    /// let mut a = DefaultHasher::new();
+    /// a.write_str("hello");
+    ///
+    /// let mut b = DefaultHasher::new();
+    /// "hello".hash(&mut b);
+    ///
+    /// assert_eq!(a.finish(), b.finish());
+    /// ```
+    #[inline]
+    #[unstable(feature = "hasher_prefixfree_extras", issue = "0")]
+    fn write_str(&mut self, s: &str) {
+        self.write(s.as_bytes());
+        self.write_u8(0xff);
+    }
 }
 
 /// A trait for creating instances of [`Hasher`].
@@ -544,8 +614,7 @@ mod impls {
     #[stable(feature = "rust1", since = "1.0.0")]
     impl Hash for str {
         fn hash<H: Hasher>(&self, state: &mut H) {
-            state.write(self.as_bytes());
-            state.write_u8(0xff)
+            state.write_str(self);
         }
     }
 
@@ -591,7 +660,7 @@ mod impls {
     #[stable(feature = "rust1", since = "1.0.0")]
     impl<T: Hash> Hash for [T] {
         fn hash<H: Hasher>(&self, state: &mut H) {
-            self.len().hash(state);
+            state.write_length_prefix(self.len());
             Hash::hash_slice(self, state)
         }
     }
@@ -0,0 +1,110 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Hints to the compiler that affect how code should be emitted or optimized.
+
+#![unstable(feature = "core_hint", issue = "0")]
+
+/// An identity function that is opaque to the optimizer.
+///
+/// The compiler has to assume that `black_box` can read and modify `dummy`
+/// in any way it likes, so it can't constant-fold the returned value or
+/// drop the computation that produced the argument. This makes it useful in
+/// benchmarks, to keep the code under test from being optimized away.
+///
+/// This is a best-effort hint: on targets without inline assembly it is
+/// only an `#[inline(never)]` function, and no guarantee is made about what
+/// the optimizer does with it. Don't rely on it for correctness, and in
+/// particular not for cryptographic constant-time code.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(core_hint, hint_black_box)]
+/// use std::hint::black_box;
+///
+/// fn contains(haystack: &[&str], needle: &str) -> bool {
+///     haystack.iter().any(|x| x == &needle)
+/// }
+///
+/// let haystack = vec!["abc", "def", "ghi", "jkl", "mno"];
+/// let needle = "ghi";
+/// for _ in 0..10 {
+///     // Without `black_box`, the whole search could be computed once
+///     // at compile time and the loop removed.
+///     let found = contains(black_box(&haystack), black_box(needle));
+///     black_box(found);
+/// }
+/// ```
+#[cfg(not(any(all(target_os = "nacl", target_arch = "le32"),
+              target_arch = "asmjs", target_arch = "wasm32")))]
+#[inline]
+#[unstable(feature = "hint_black_box", issue = "0")]
+pub fn black_box<T>(dummy: T) -> T {
+    // we need to "use" the argument in some way LLVM can't
+    // introspect.
+    unsafe { asm!("" : : "r"(&dummy)) }
+    dummy
+}
+
+/// An identity function that is opaque to the optimizer.
+///
+/// See the documentation on the other targets; here this is only an
+/// `#[inline(never)]` function.
+#[cfg(any(all(target_os = "nacl", target_arch = "le32"),
+          target_arch = "asmjs", target_arch = "wasm32"))]
+#[inline(never)]
+#[unstable(feature = "hint_black_box", issue = "0")]
+pub fn black_box<T>(dummy: T) -> T {
+    dummy
+}
+
+/// Signals the processor that it is inside a busy-wait spin-loop.
+///
+/// On x86 and x86_64 this emits a `pause` instruction, and on aarch64 a
+/// `yield` instruction. This lets the processor save power or give way to
+/// another hyperthread while the loop waits for some other thread to make
+/// progress. On other platforms this function does nothing.
+///
+/// Unlike `std::thread::yield_now`, this doesn't go through the operating
+/// system's scheduler, so it is also available to `no_std` code. Careful
+/// benchmarking is advised before picking one or a combination of both.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(core_hint, hint_spin_loop)]
+/// use std::hint;
+/// use std::sync::Arc;
+/// use std::sync::atomic::{AtomicBool, Ordering};
+/// use std::thread;
+///
+/// let ready = Arc::new(AtomicBool::new(false));
+/// let ready2 = ready.clone();
+/// let t = thread::spawn(move || ready2.store(true, Ordering::Release));
+///
+/// while !ready.load(Ordering::Acquire) {
+///     hint::spin_loop();
+/// }
+/// t.join().unwrap();
+/// ```
+#[inline]
+#[unstable(feature = "hint_spin_loop", issue = "0")]
+pub fn spin_loop() {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    unsafe {
+        asm!("pause" ::: "memory" : "volatile");
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    unsafe {
+        asm!("yield" ::: "memory" : "volatile");
+    }
+}
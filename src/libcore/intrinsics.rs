@@ -674,6 +674,11 @@ extern "rust-intrinsic" {
     pub fn min_align_of<T>() -> usize;
     pub fn pref_align_of<T>() -> usize;
 
+    /// The number of variants of the enum type `T`, or 0 if `T` is not an
+    /// enum.
+    #[cfg(not(stage0))]
+    pub fn variant_count<T>() -> usize;
+
     pub fn size_of_val<T: ?Sized>(_: &T) -> usize;
     pub fn min_align_of_val<T: ?Sized>(_: &T) -> usize;
 
@@ -9,8 +9,11 @@
 // except according to those terms.
 
 use cmp::Ordering;
+use ops::Try;
+#[cfg(not(stage0))]
+use ops::{ControlFlow, FromResidual, Residual};
 
-use super::{Chain, Cycle, Cloned, Enumerate, Filter, FilterMap, FlatMap, Fuse};
+use super::{Chain, Cycle, Cloned, Copied, Enumerate, Filter, FilterMap, FlatMap, Fuse};
 use super::{Inspect, Map, Peekable, Scan, Skip, SkipWhile, StepBy, Take, TakeWhile, Rev};
 use super::{Zip, Sum, Product};
 use super::{ChainState, FromIterator, ZipImpl};
@@ -1417,6 +1420,112 @@ pub trait Iterator {
         accum
     }
 
+    /// Reduces the elements to a single one by repeatedly applying a fallible
+    /// reducing operation, stopping at the first failure.
+    ///
+    /// The first element is used as the initial accumulator value, and each
+    /// subsequent element is combined with it using the closure. If the closure
+    /// ever fails, the error is returned immediately and the rest of the
+    /// iterator is left unconsumed.
+    ///
+    /// Returns `Ok(None)` if the iterator is empty, `Ok(Some(value))` with the
+    /// reduced value otherwise, and `Err(e)` as soon as the closure fails.
+    ///
+    /// # Examples
+    ///
+    /// Safely summing a list of numbers:
+    ///
+    /// ```
+    /// #![feature(iterator_try_reduce)]
+    ///
+    /// let numbers: Vec<u8> = vec![10, 20, 5, 23, 0];
+    /// let sum = numbers.into_iter().try_reduce(|x, y| x.checked_add(y).ok_or(()));
+    /// assert_eq!(sum, Ok(Some(58)));
+    ///
+    /// let numbers: Vec<u8> = vec![100, 200, 5, 23, 0];
+    /// let sum = numbers.into_iter().try_reduce(|x, y| x.checked_add(y).ok_or(()));
+    /// assert_eq!(sum, Err(()));
+    ///
+    /// let numbers: Vec<u8> = Vec::new();
+    /// let sum = numbers.into_iter().try_reduce(|x, y| x.checked_add(y).ok_or(()));
+    /// assert_eq!(sum, Ok(None));
+    /// ```
+    #[inline]
+    #[unstable(feature = "iterator_try_reduce", issue = "0")]
+    #[cfg(stage0)]
+    fn try_reduce<F, R>(&mut self, mut f: F) -> Result<Option<Self::Item>, R::Error> where
+        Self: Sized,
+        F: FnMut(Self::Item, Self::Item) -> R,
+        R: Try<Ok = Self::Item>,
+    {
+        let mut accum = match self.next() {
+            Some(first) => first,
+            None => return Ok(None),
+        };
+        while let Some(x) = self.next() {
+            accum = f(accum, x).into_result()?;
+        }
+        Ok(Some(accum))
+    }
+
+    /// Reduces the elements to a single one by repeatedly applying a fallible
+    /// reducing operation, stopping at the first failure.
+    ///
+    /// The first element is used as the initial accumulator value, and each
+    /// subsequent element is combined with it using the closure. If the closure
+    /// ever fails, the failure is returned immediately and the rest of the
+    /// iterator is left unconsumed.
+    ///
+    /// The return type follows the closure's: a closure returning
+    /// `Result<T, E>` makes this return `Result<Option<T>, E>`, and likewise
+    /// for any other [`Try`] type whose residual implements [`Residual`]. The
+    /// inner `Option` is `None` only if the iterator is empty.
+    ///
+    /// [`Try`]: ../../std/ops/trait.Try.html
+    /// [`Residual`]: ../../std/ops/trait.Residual.html
+    ///
+    /// # Examples
+    ///
+    /// Safely summing a list of numbers:
+    ///
+    /// ```
+    /// #![feature(iterator_try_reduce)]
+    ///
+    /// let numbers: Vec<u8> = vec![10, 20, 5, 23, 0];
+    /// let sum = numbers.into_iter().try_reduce(|x, y| x.checked_add(y).ok_or(()));
+    /// assert_eq!(sum, Ok(Some(58)));
+    ///
+    /// let numbers: Vec<u8> = vec![100, 200, 5, 23, 0];
+    /// let sum = numbers.into_iter().try_reduce(|x, y| x.checked_add(y).ok_or(()));
+    /// assert_eq!(sum, Err(()));
+    ///
+    /// let numbers: Vec<u8> = Vec::new();
+    /// let sum = numbers.into_iter().try_reduce(|x, y| x.checked_add(y).ok_or(()));
+    /// assert_eq!(sum, Ok(None));
+    /// ```
+    #[inline]
+    #[unstable(feature = "iterator_try_reduce", issue = "0")]
+    #[cfg(not(stage0))]
+    fn try_reduce<F, R>(&mut self, mut f: F)
+        -> <R::Residual as Residual<Option<Self::Item>>>::TryType where
+        Self: Sized,
+        F: FnMut(Self::Item, Self::Item) -> R,
+        R: Try<Output = Self::Item>,
+        R::Residual: Residual<Option<Self::Item>>,
+    {
+        let mut accum = match self.next() {
+            Some(first) => first,
+            None => return Try::from_output(None),
+        };
+        while let Some(x) = self.next() {
+            accum = match f(accum, x).branch() {
+                ControlFlow::Continue(v) => v,
+                ControlFlow::Break(r) => return FromResidual::from_residual(r),
+            };
+        }
+        Try::from_output(Some(accum))
+    }
+
     /// Tests if every element of the iterator matches a predicate.
     ///
     /// `all()` takes a closure that returns `true` or `false`. It applies
@@ -1572,6 +1681,99 @@ pub trait Iterator {
         None
     }
 
+    /// Applies a fallible predicate to the elements of the iterator and
+    /// returns the first element for which it succeeds with `true`, or the
+    /// first error it produces.
+    ///
+    /// This is the fallible counterpart of [`find`]: the predicate returns a
+    /// `Result<bool, E>` (or another [`Try`] type wrapping a `bool`), and the
+    /// search short-circuits on the first error as well as on the first match.
+    ///
+    /// [`find`]: #method.find
+    /// [`Try`]: ../../std/ops/trait.Try.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(try_find)]
+    ///
+    /// let a = ["1", "2", "lol", "NaN", "5"];
+    ///
+    /// let is_my_num = |s: &str, search: i32| -> Result<bool, std::num::ParseIntError> {
+    ///     Ok(s.parse::<i32>()? == search)
+    /// };
+    ///
+    /// let result = a.iter().try_find(|&&s| is_my_num(s, 2));
+    /// assert_eq!(result, Ok(Some(&"2")));
+    ///
+    /// let result = a.iter().try_find(|&&s| is_my_num(s, 5));
+    /// assert!(result.is_err());
+    /// ```
+    #[inline]
+    #[unstable(feature = "try_find", issue = "0")]
+    #[cfg(stage0)]
+    fn try_find<F, R>(&mut self, mut f: F) -> Result<Option<Self::Item>, R::Error> where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = bool>,
+    {
+        while let Some(x) = self.next() {
+            if f(&x).into_result()? { return Ok(Some(x)) }
+        }
+        Ok(None)
+    }
+
+    /// Applies a fallible predicate to the elements of the iterator and
+    /// returns the first element for which it succeeds with `true`, or the
+    /// first failure it produces.
+    ///
+    /// This is the fallible counterpart of [`find`]: the predicate returns a
+    /// `Result<bool, E>` (or another [`Try`] type wrapping a `bool`), and the
+    /// search short-circuits on the first failure as well as on the first
+    /// match. The result is wrapped in the same kind of type as the
+    /// predicate's, so a `Result<bool, E>` predicate gives a
+    /// `Result<Option<Self::Item>, E>`.
+    ///
+    /// [`find`]: #method.find
+    /// [`Try`]: ../../std/ops/trait.Try.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(try_find)]
+    ///
+    /// let a = ["1", "2", "lol", "NaN", "5"];
+    ///
+    /// let is_my_num = |s: &str, search: i32| -> Result<bool, std::num::ParseIntError> {
+    ///     Ok(s.parse::<i32>()? == search)
+    /// };
+    ///
+    /// let result = a.iter().try_find(|&&s| is_my_num(s, 2));
+    /// assert_eq!(result, Ok(Some(&"2")));
+    ///
+    /// let result = a.iter().try_find(|&&s| is_my_num(s, 5));
+    /// assert!(result.is_err());
+    /// ```
+    #[inline]
+    #[unstable(feature = "try_find", issue = "0")]
+    #[cfg(not(stage0))]
+    fn try_find<F, R>(&mut self, mut f: F)
+        -> <R::Residual as Residual<Option<Self::Item>>>::TryType where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Output = bool>,
+        R::Residual: Residual<Option<Self::Item>>,
+    {
+        while let Some(x) = self.next() {
+            match f(&x).branch() {
+                ControlFlow::Continue(false) => {}
+                ControlFlow::Continue(true) => return Try::from_output(Some(x)),
+                ControlFlow::Break(r) => return FromResidual::from_residual(r),
+            }
+        }
+        Try::from_output(None)
+    }
+
     /// Searches for an element in an iterator, returning its index.
     ///
     /// `position()` takes a closure that returns `true` or `false`. It applies
@@ -1963,6 +2165,40 @@ pub trait Iterator {
         Cloned { it: self }
     }
 
+    /// Creates an iterator which copies all of its elements.
+    ///
+    /// This is useful when you have an iterator over `&T`, but you need an
+    /// iterator over `T`. Unlike [`cloned`], it is only available when `T`
+    /// is [`Copy`], which makes it clear at the call site that every element
+    /// is a cheap bitwise copy.
+    ///
+    /// [`cloned`]: #method.cloned
+    /// [`Copy`]: ../../std/marker/trait.Copy.html
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(iter_copied)]
+    ///
+    /// let a = [1, 2, 3];
+    ///
+    /// let v_copied: Vec<_> = a.iter().copied().collect();
+    ///
+    /// // copied is the same as .map(|&x| x)
+    /// let v_map: Vec<_> = a.iter().map(|&x| x).collect();
+    ///
+    /// assert_eq!(v_copied, vec![1, 2, 3]);
+    /// assert_eq!(v_map, vec![1, 2, 3]);
+    /// ```
+    #[unstable(feature = "iter_copied", issue = "0")]
+    fn copied<'a, T: 'a>(self) -> Copied<Self>
+        where Self: Sized + Iterator<Item=&'a T>, T: Copy
+    {
+        Copied { it: self }
+    }
+
     /// Repeats an iterator endlessly.
     ///
     /// Instead of stopping at [`None`], the iterator will instead start again,
@@ -2243,6 +2479,100 @@ pub trait Iterator {
             }
         }
     }
+
+    /// Checks if the elements of this iterator are sorted.
+    ///
+    /// That is, for each element `a` and its following element `b`, `a <= b`
+    /// must hold. If the iterator yields exactly zero or one element, `true`
+    /// is returned.
+    ///
+    /// Note that if `Self::Item` is only `PartialOrd`, but not `Ord`, the
+    /// above definition implies that this function returns `false` if any two
+    /// consecutive items are not comparable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(is_sorted)]
+    ///
+    /// assert!([1, 2, 2, 9].iter().is_sorted());
+    /// assert!(![1, 3, 2, 4].iter().is_sorted());
+    /// assert!([0].iter().is_sorted());
+    /// assert!(std::iter::empty::<i32>().is_sorted());
+    /// assert!(![0.0, 1.0, std::f32::NAN].iter().is_sorted());
+    /// ```
+    #[inline]
+    #[unstable(feature = "is_sorted", issue = "0")]
+    fn is_sorted(self) -> bool
+        where Self: Sized, Self::Item: PartialOrd
+    {
+        self.is_sorted_by(|a, b| a <= b)
+    }
+
+    /// Checks if the elements of this iterator are sorted using the given
+    /// comparator function.
+    ///
+    /// Instead of using `PartialOrd::le`, this function uses the given
+    /// `compare` function to determine whether two elements are to be
+    /// considered in sorted order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(is_sorted)]
+    ///
+    /// assert!([1, 2, 2, 9].iter().is_sorted_by(|a, b| a <= b));
+    /// assert!(![1, 2, 2, 9].iter().is_sorted_by(|a, b| a < b));
+    ///
+    /// assert!([0].iter().is_sorted_by(|a, b| true));
+    /// assert!([0].iter().is_sorted_by(|a, b| false));
+    ///
+    /// assert!(std::iter::empty::<i32>().is_sorted_by(|a, b| false));
+    /// assert!(std::iter::empty::<i32>().is_sorted_by(|a, b| true));
+    /// ```
+    #[unstable(feature = "is_sorted", issue = "0")]
+    fn is_sorted_by<F>(mut self, mut compare: F) -> bool
+        where Self: Sized, F: FnMut(&Self::Item, &Self::Item) -> bool
+    {
+        let mut last = match self.next() {
+            Some(e) => e,
+            None => return true,
+        };
+
+        self.all(move |curr| {
+            if !compare(&last, &curr) {
+                return false;
+            }
+            last = curr;
+            true
+        })
+    }
+
+    /// Checks if the elements of this iterator are sorted using the given
+    /// key extraction function.
+    ///
+    /// Instead of comparing the iterator's elements directly, this function
+    /// compares the keys of the elements, as determined by `f`. Apart from
+    /// that, it's equivalent to [`is_sorted`]; see its documentation for
+    /// more information.
+    ///
+    /// [`is_sorted`]: trait.Iterator.html#method.is_sorted
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(is_sorted)]
+    ///
+    /// assert!(["c", "bb", "aaa"].iter().is_sorted_by_key(|s| s.len()));
+    /// assert!(![-2i32, -1, 0, 3].iter().is_sorted_by_key(|n| n.abs()));
+    /// ```
+    #[inline]
+    #[unstable(feature = "is_sorted", issue = "0")]
+    fn is_sorted_by_key<F, K>(self, f: F) -> bool
+        where Self: Sized, F: FnMut(Self::Item) -> K, K: PartialOrd
+    {
+        self.map(f).is_sorted()
+    }
 }
 
 /// Select an element from an iterator based on the given "projection"
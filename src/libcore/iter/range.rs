@@ -44,6 +44,9 @@ pub trait Step: Clone + PartialOrd + Sized {
 
     /// Add an usize, returning None on overflow
     fn add_usize(&self, n: usize) -> Option<Self>;
+
+    /// Subtract an usize, returning None on underflow
+    fn sub_usize(&self, n: usize) -> Option<Self>;
 }
 
 // These are still macro-generated because the integer literals resolve to different types.
@@ -96,6 +99,14 @@ macro_rules! step_impl_unsigned {
                 }
             }
 
+            #[inline]
+            fn sub_usize(&self, n: usize) -> Option<Self> {
+                match <$t>::try_from(n) {
+                    Ok(n_as_t) => self.checked_sub(n_as_t),
+                    Err(_) => None,
+                }
+            }
+
             step_identical_methods!();
         }
     )*)
@@ -137,6 +148,24 @@ macro_rules! step_impl_signed {
                 }
             }
 
+            #[inline]
+            fn sub_usize(&self, n: usize) -> Option<Self> {
+                match <$unsigned>::try_from(n) {
+                    Ok(n_as_unsigned) => {
+                        // Wrapping in unsigned space handles cases like
+                        // `80_i8.sub_usize(200) == Some(-120_i8)`,
+                        // even though 200_usize is out of range for i8.
+                        let wrapped = (*self as $unsigned).wrapping_sub(n_as_unsigned) as $t;
+                        if wrapped <= *self {
+                            Some(wrapped)
+                        } else {
+                            None  // Subtraction underflowed
+                        }
+                    }
+                    Err(_) => None,
+                }
+            }
+
             step_identical_methods!();
         }
     )*)
@@ -158,6 +187,11 @@ macro_rules! step_impl_no_between {
                 self.checked_add(n as $t)
             }
 
+            #[inline]
+            fn sub_usize(&self, n: usize) -> Option<Self> {
+                self.checked_sub(n as $t)
+            }
+
             step_identical_methods!();
         }
     )*)
@@ -276,6 +310,19 @@ impl<A: Step> DoubleEndedIterator for ops::Range<A> {
             None
         }
     }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<A> {
+        if let Some(minus_n) = self.end.sub_usize(n) {
+            if minus_n > self.start {
+                self.end = minus_n.sub_one();
+                return Some(self.end.clone())
+            }
+        }
+
+        self.end = self.start.clone();
+        None
+    }
 }
 
 #[unstable(feature = "fused", issue = "35602")]
@@ -386,6 +433,30 @@ impl<A: Step> DoubleEndedIterator for ops::RangeInclusive<A> {
             _ => None,
         }
     }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<A> {
+        if let Some(minus_n) = self.end.sub_usize(n) {
+            use cmp::Ordering::*;
+
+            match self.start.partial_cmp(&minus_n) {
+                Some(Less) => {
+                    self.end = minus_n.sub_one();
+                    return Some(minus_n)
+                }
+                Some(Equal) => {
+                    self.end.replace_zero();
+                    self.start.replace_one();
+                    return Some(minus_n)
+                }
+                _ => {}
+            }
+        }
+
+        self.start.replace_one();
+        self.end.replace_zero();
+        None
+    }
 }
 
 #[unstable(feature = "fused", issue = "35602")]
@@ -96,6 +96,119 @@ pub fn repeat<T: Clone>(elt: T) -> Repeat<T> {
     Repeat{element: elt}
 }
 
+/// An iterator that repeats an element an exact number of times.
+///
+/// This `struct` is created by the [`repeat_n`] function. See its documentation for more.
+///
+/// [`repeat_n`]: fn.repeat_n.html
+#[derive(Clone, Debug)]
+#[unstable(feature = "iter_repeat_n", issue = "0")]
+pub struct RepeatN<A> {
+    // `None` once the last copy has been handed out (or if `count` was zero)
+    element: Option<A>,
+    count: usize,
+}
+
+#[unstable(feature = "iter_repeat_n", issue = "0")]
+impl<A: Clone> Iterator for RepeatN<A> {
+    type Item = A;
+
+    #[inline]
+    fn next(&mut self) -> Option<A> {
+        match self.count {
+            0 => None,
+            1 => {
+                self.count = 0;
+                self.element.take()
+            }
+            _ => {
+                self.count -= 1;
+                self.element.clone()
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.count, Some(self.count))
+    }
+}
+
+#[unstable(feature = "iter_repeat_n", issue = "0")]
+impl<A: Clone> DoubleEndedIterator for RepeatN<A> {
+    #[inline]
+    fn next_back(&mut self) -> Option<A> {
+        self.next()
+    }
+}
+
+#[unstable(feature = "iter_repeat_n", issue = "0")]
+impl<A: Clone> ExactSizeIterator for RepeatN<A> {
+    fn len(&self) -> usize {
+        self.count
+    }
+}
+
+#[unstable(feature = "trusted_len", issue = "37572")]
+unsafe impl<A: Clone> TrustedLen for RepeatN<A> {}
+
+#[unstable(feature = "fused", issue = "35602")]
+impl<A: Clone> FusedIterator for RepeatN<A> {}
+
+/// Creates a new iterator that repeats a single element a given number of times.
+///
+/// The `repeat_n()` function repeats a single value exactly `n` times.
+///
+/// This is very similar to using [`repeat()`] with [`Iterator::take()`],
+/// but there are two differences:
+/// - `repeat_n()` can return the original value, rather than always cloning.
+/// - `repeat_n()` produces an [`ExactSizeIterator`].
+///
+/// [`repeat()`]: fn.repeat.html
+/// [`Iterator::take()`]: trait.Iterator.html#method.take
+/// [`ExactSizeIterator`]: trait.ExactSizeIterator.html
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// #![feature(iter_repeat_n)]
+/// use std::iter;
+///
+/// // four of the number four:
+/// let mut four_fours = iter::repeat_n(4, 4);
+///
+/// assert_eq!(Some(4), four_fours.next());
+/// assert_eq!(Some(4), four_fours.next());
+/// assert_eq!(Some(4), four_fours.next());
+/// assert_eq!(Some(4), four_fours.next());
+///
+/// // no more fours
+/// assert_eq!(None, four_fours.next());
+/// ```
+///
+/// For non-`Copy` types, the last copy handed out is the original value:
+///
+/// ```
+/// #![feature(iter_repeat_n)]
+/// use std::iter;
+///
+/// let v: Vec<Vec<i32>> = iter::repeat_n(Vec::with_capacity(123), 3).collect();
+/// assert_eq!(v.len(), 3);
+///
+/// // The clones are empty vectors without any capacity, but the original
+/// // value is moved out at the end, allocation and all.
+/// assert_eq!(v[2].capacity(), 123);
+/// ```
+#[inline]
+#[unstable(feature = "iter_repeat_n", issue = "0")]
+pub fn repeat_n<T: Clone>(element: T, count: usize) -> RepeatN<T> {
+    // With a zero count the element is never handed out, so drop it right away.
+    let element = if count == 0 { None } else { Some(element) };
+    RepeatN { element: element, count: count }
+}
+
 /// An iterator that yields nothing.
 ///
 /// This `struct` is created by the [`empty`] function. See its documentation for more.
@@ -280,3 +393,263 @@ impl<T> FusedIterator for Once<T> {}
 pub fn once<T>(value: T) -> Once<T> {
     Once { inner: Some(value).into_iter() }
 }
+
+/// An iterator that yields a single element of type `A` by
+/// applying the provided closure `F: FnOnce() -> A`.
+///
+/// This `struct` is created by the [`once_with`] function.
+/// See its documentation for more.
+///
+/// [`once_with`]: fn.once_with.html
+#[derive(Copy, Clone, Debug)]
+#[unstable(feature = "iter_once_with", issue = "0")]
+pub struct OnceWith<F> {
+    make: Option<F>,
+}
+
+#[unstable(feature = "iter_once_with", issue = "0")]
+impl<A, F: FnOnce() -> A> Iterator for OnceWith<F> {
+    type Item = A;
+
+    #[inline]
+    fn next(&mut self) -> Option<A> {
+        self.make.take().map(|f| f())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.make.iter().size_hint()
+    }
+}
+
+#[unstable(feature = "iter_once_with", issue = "0")]
+impl<A, F: FnOnce() -> A> DoubleEndedIterator for OnceWith<F> {
+    fn next_back(&mut self) -> Option<A> {
+        self.next()
+    }
+}
+
+#[unstable(feature = "iter_once_with", issue = "0")]
+impl<A, F: FnOnce() -> A> ExactSizeIterator for OnceWith<F> {
+    fn len(&self) -> usize {
+        self.make.iter().len()
+    }
+}
+
+#[unstable(feature = "trusted_len", issue = "37572")]
+unsafe impl<A, F: FnOnce() -> A> TrustedLen for OnceWith<F> {}
+
+#[unstable(feature = "fused", issue = "35602")]
+impl<A, F: FnOnce() -> A> FusedIterator for OnceWith<F> {}
+
+/// Creates an iterator that lazily generates a value exactly once by invoking
+/// the provided closure.
+///
+/// This is commonly used to adapt a single value generator into a [`chain`] of
+/// other kinds of iteration. Unlike [`once`], the value is only computed if
+/// the iterator is actually advanced, which makes it a good fit for expensive
+/// or side-effecting defaults.
+///
+/// [`chain`]: trait.Iterator.html#method.chain
+/// [`once`]: fn.once.html
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// #![feature(iter_once_with)]
+///
+/// use std::iter;
+///
+/// // one is the loneliest number
+/// let mut one = iter::once_with(|| 1);
+///
+/// assert_eq!(Some(1), one.next());
+///
+/// // just one, that's all we get
+/// assert_eq!(None, one.next());
+/// ```
+#[inline]
+#[unstable(feature = "iter_once_with", issue = "0")]
+pub fn once_with<A, F: FnOnce() -> A>(make: F) -> OnceWith<F> {
+    OnceWith { make: Some(make) }
+}
+
+/// An iterator where each iteration calls the provided closure
+/// `F: FnMut() -> Option<T>`.
+///
+/// This `struct` is created by the [`from_fn`] function.
+/// See its documentation for more.
+///
+/// [`from_fn`]: fn.from_fn.html
+#[derive(Clone)]
+#[unstable(feature = "iter_from_fn", issue = "0")]
+pub struct FromFn<F>(F);
+
+#[unstable(feature = "iter_from_fn", issue = "0")]
+impl<T, F> Iterator for FromFn<F>
+    where F: FnMut() -> Option<T>
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        (self.0)()
+    }
+}
+
+#[unstable(feature = "iter_from_fn", issue = "0")]
+impl<F> fmt::Debug for FromFn<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FromFn").finish()
+    }
+}
+
+/// Creates a new iterator where each iteration calls the provided closure
+/// `F: FnMut() -> Option<T>`.
+///
+/// This allows creating a custom iterator with any behavior
+/// without using the more verbose syntax of creating a dedicated type
+/// and implementing the [`Iterator`] trait for it.
+///
+/// Note that the `FromFn` iterator doesn't make assumptions about the behavior
+/// of the closure, and therefore conservatively does not implement
+/// [`FusedIterator`], or override [`Iterator::size_hint`] from its default
+/// `(0, None)`.
+///
+/// The closure can use captures and its environment to track state across
+/// iterations. Depending on how the iterator is used, this may require
+/// specifying the `move` keyword on the closure.
+///
+/// [`Iterator`]: trait.Iterator.html
+/// [`FusedIterator`]: trait.FusedIterator.html
+/// [`Iterator::size_hint`]: trait.Iterator.html#method.size_hint
+///
+/// # Examples
+///
+/// Let's re-implement the counter iterator from the [module-level
+/// documentation](index.html):
+///
+/// ```
+/// #![feature(iter_from_fn)]
+///
+/// let mut count = 0;
+/// let counter = std::iter::from_fn(move || {
+///     // Increment our count. This is why we started at zero.
+///     count += 1;
+///
+///     // Check to see if we've finished counting or not.
+///     if count < 6 {
+///         Some(count)
+///     } else {
+///         None
+///     }
+/// });
+/// assert_eq!(counter.collect::<Vec<_>>(), &[1, 2, 3, 4, 5]);
+/// ```
+#[inline]
+#[unstable(feature = "iter_from_fn", issue = "0")]
+pub fn from_fn<T, F>(f: F) -> FromFn<F>
+    where F: FnMut() -> Option<T>
+{
+    FromFn(f)
+}
+
+/// An iterator where each successive item is computed based on the preceding one.
+///
+/// This `struct` is created by the [`successors`] function.
+/// See its documentation for more.
+///
+/// [`successors`]: fn.successors.html
+#[derive(Clone)]
+#[unstable(feature = "iter_successors", issue = "0")]
+pub struct Successors<T, F> {
+    next: Option<T>,
+    succ: F,
+}
+
+#[unstable(feature = "iter_successors", issue = "0")]
+impl<T, F> Iterator for Successors<T, F>
+    where F: FnMut(&T) -> Option<T>
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        let item = match self.next.take() {
+            Some(item) => item,
+            None => return None,
+        };
+        self.next = (self.succ)(&item);
+        Some(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.next.is_some() {
+            (1, None)
+        } else {
+            (0, Some(0))
+        }
+    }
+}
+
+#[unstable(feature = "iter_successors", issue = "0")]
+impl<T, F> FusedIterator for Successors<T, F>
+    where F: FnMut(&T) -> Option<T>
+{}
+
+#[unstable(feature = "iter_successors", issue = "0")]
+impl<T: fmt::Debug, F> fmt::Debug for Successors<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Successors")
+            .field("next", &self.next)
+            .finish()
+    }
+}
+
+/// Creates a new iterator where each successive item is computed based on the preceding one.
+///
+/// The iterator starts with the given first item (if any)
+/// and calls the given `FnMut(&T) -> Option<T>` closure to compute each item's successor.
+/// The iterator ends as soon as the closure returns `None`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// #![feature(iter_successors)]
+///
+/// use std::iter::successors;
+///
+/// let powers_of_10 = successors(Some(1_u16), |n| n.checked_mul(10));
+/// assert_eq!(powers_of_10.collect::<Vec<_>>(), &[1, 10, 100, 1_000, 10_000]);
+/// ```
+///
+/// Walking a Collatz sequence down to one:
+///
+/// ```
+/// #![feature(iter_successors)]
+///
+/// use std::iter::successors;
+///
+/// let collatz = successors(Some(6_u32), |&n| {
+///     if n == 1 {
+///         None
+///     } else if n % 2 == 0 {
+///         Some(n / 2)
+///     } else {
+///         Some(3 * n + 1)
+///     }
+/// });
+/// assert_eq!(collatz.collect::<Vec<_>>(), &[6, 3, 10, 5, 16, 8, 4, 2, 1]);
+/// ```
+#[inline]
+#[unstable(feature = "iter_successors", issue = "0")]
+pub fn successors<T, F>(first: Option<T>, succ: F) -> Successors<T, F>
+    where F: FnMut(&T) -> Option<T>
+{
+    Successors { next: first, succ: succ }
+}
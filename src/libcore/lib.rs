@@ -85,8 +85,10 @@
 #![feature(on_unimplemented)]
 #![feature(optin_builtin_traits)]
 #![feature(prelude_import)]
+#![feature(repr_align)]
 #![feature(repr_simd, platform_intrinsics)]
 #![feature(rustc_attrs)]
+#![feature(slice_patterns)]
 #![feature(specialization)]
 #![feature(staged_api)]
 #![feature(unboxed_closures)]
@@ -98,7 +100,11 @@
 use prelude::v1::*;
 
 #[macro_use]
-mod macros;
+#[doc(hidden)]
+#[unstable(feature = "core_macros_internals",
+           reason = "implementation details of libcore's macros",
+           issue = "0")]
+pub mod macros;
 
 #[macro_use]
 mod internal_macros;
@@ -137,6 +143,7 @@ pub mod prelude;
 
 /* Core modules for ownership management */
 
+pub mod hint;
 pub mod intrinsics;
 pub mod mem;
 pub mod nonzero;
@@ -156,9 +163,11 @@ pub mod borrow;
 
 pub mod any;
 pub mod array;
+pub mod ascii;
 pub mod sync;
 pub mod cell;
 pub mod char;
+pub mod net;
 pub mod panicking;
 pub mod iter;
 pub mod option;
@@ -169,8 +178,10 @@ pub mod slice;
 pub mod str;
 pub mod hash;
 pub mod fmt;
+pub mod simd;
 
 // note: does not need to be public
+mod bool;
 mod char_private;
 mod iter_private;
 mod tuple;
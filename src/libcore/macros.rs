@@ -197,6 +197,122 @@ macro_rules! assert_ne {
     });
 }
 
+/// Returns whether the given expression matches any of the given patterns.
+///
+/// Like in a `match` expression, the pattern can be optionally followed by
+/// `if` and a guard expression that has access to names bound by the
+/// pattern.
+///
+/// The compiler doesn't check the stability of macros, so the expansion
+/// calls an unstable function instead: using this macro requires
+/// `#![feature(matches_macro)]`. It is not re-exported from libstd yet and
+/// has to be imported from `core` explicitly.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(matches_macro)]
+///
+/// #[macro_use(matches)]
+/// extern crate core;
+///
+/// fn main() {
+///     let foo = 'f';
+///     assert!(matches!(foo, 'A'...'Z' | 'a'...'z'));
+///
+///     let bar = Some(4);
+///     assert!(matches!(bar, Some(x) if x > 2));
+/// }
+/// ```
+#[macro_export]
+#[unstable(feature = "matches_macro", issue = "0")]
+macro_rules! matches {
+    ($expression:expr, $($pattern:pat)|+ $(if $guard:expr)*) => ({
+        $crate::macros::__matches_macro_gate();
+        match $expression {
+            $($pattern)|+ $(if $guard)* => true,
+            _ => false
+        }
+    })
+}
+
+// Macros aren't checked for stability, so `matches!` calls this function to
+// require `#![feature(matches_macro)]` from the crates that use it.
+#[doc(hidden)]
+#[unstable(feature = "matches_macro", issue = "0")]
+#[inline(always)]
+pub fn __matches_macro_gate() {}
+
+/// Asserts that an expression matches any of the given patterns.
+///
+/// Like in a `match` expression, the pattern can be optionally followed by
+/// `if` and a guard expression that has access to names bound by the
+/// pattern.
+///
+/// On panic, this macro will print the value of the expression with its
+/// debug representation, along with the pattern it was expected to match.
+///
+/// Like [`assert!`], this macro has a second form, where a custom
+/// panic message can be provided.
+///
+/// Like [`matches!`], this macro requires a feature gate,
+/// `#![feature(assert_matches)]`, and is not re-exported from libstd yet.
+///
+/// [`assert!`]: macro.assert.html
+/// [`matches!`]: macro.matches.html
+///
+/// # Examples
+///
+/// ```
+/// #![feature(assert_matches)]
+///
+/// #[macro_use(assert_matches)]
+/// extern crate core;
+///
+/// fn main() {
+///     let a = 1u32.checked_add(2);
+///     let b = 1u32.checked_sub(2);
+///     assert_matches!(a, Some(_));
+///     assert_matches!(b, None);
+///
+///     let c: Result<String, String> = Ok("abc".to_string());
+///     assert_matches!(c, Ok(x) | Err(x) if x.len() < 100);
+/// }
+/// ```
+#[macro_export]
+#[unstable(feature = "assert_matches", issue = "0")]
+macro_rules! assert_matches {
+    ($left:expr, $($pattern:pat)|+ $(if $guard:expr)*) => ({
+        $crate::macros::__assert_matches_gate();
+        match $left {
+            $($pattern)|+ $(if $guard)* => {}
+            ref left_val => {
+                panic!(r#"assertion failed: `(left matches right)`
+  left: `{:?}`,
+ right: `{}`"#, left_val, stringify!($($pattern)|+ $(if $guard)*))
+            }
+        }
+    });
+    ($left:expr, $($pattern:pat)|+ $(if $guard:expr)*, $($arg:tt)+) => ({
+        $crate::macros::__assert_matches_gate();
+        match $left {
+            $($pattern)|+ $(if $guard)* => {}
+            ref left_val => {
+                panic!(r#"assertion failed: `(left matches right)`
+  left: `{:?}`,
+ right: `{}`: {}"#, left_val, stringify!($($pattern)|+ $(if $guard)*),
+                           format_args!($($arg)+))
+            }
+        }
+    });
+}
+
+// See `__matches_macro_gate`.
+#[doc(hidden)]
+#[unstable(feature = "assert_matches", issue = "0")]
+#[inline(always)]
+pub fn __assert_matches_gate() {}
+
 /// Ensure that a boolean expression is `true` at runtime.
 ///
 /// This will invoke the [`panic!`] macro if the provided expression cannot be
@@ -570,6 +686,68 @@ macro_rules! unimplemented {
     ($($arg:tt)+) => (panic!("not yet implemented: {}", format_args!($($arg)*)));
 }
 
+/// Indicates unfinished code. It panics with the message
+/// `"not yet implemented"` when executed.
+///
+/// This behaves exactly like [`unimplemented!`], but conveys that the code
+/// is meant to be written later, rather than deliberately left out. That
+/// makes remaining work easy to search for.
+///
+/// Like [`matches!`], this macro requires a feature gate,
+/// `#![feature(todo_macro)]`, and is not re-exported from libstd yet.
+///
+/// [`unimplemented!`]: macro.unimplemented.html
+/// [`matches!`]: macro.matches.html
+///
+/// # Examples
+///
+/// ```
+/// #![feature(todo_macro)]
+///
+/// #[macro_use(todo)]
+/// extern crate core;
+///
+/// trait Foo {
+///     fn bar(&self);
+///     fn baz(&self);
+/// }
+///
+/// struct MyStruct;
+///
+/// impl Foo for MyStruct {
+///     fn bar(&self) {
+///         // implementation goes here
+///     }
+///
+///     fn baz(&self) {
+///         todo!("baz needs the config loader first");
+///     }
+/// }
+///
+/// fn main() {
+///     let s = MyStruct;
+///     s.bar();
+/// }
+/// ```
+#[macro_export]
+#[unstable(feature = "todo_macro", issue = "0")]
+macro_rules! todo {
+    () => ({
+        $crate::macros::__todo_macro_gate();
+        panic!("not yet implemented")
+    });
+    ($($arg:tt)+) => ({
+        $crate::macros::__todo_macro_gate();
+        panic!("not yet implemented: {}", format_args!($($arg)*))
+    });
+}
+
+// See `__matches_macro_gate`.
+#[doc(hidden)]
+#[unstable(feature = "todo_macro", issue = "0")]
+#[inline(always)]
+pub fn __todo_macro_gate() {}
+
 /// Built-in macros to the compiler itself.
 ///
 /// These macros do not have any corresponding definition with a `macro_rules!`
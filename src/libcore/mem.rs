@@ -212,6 +212,44 @@ pub const fn size_of<T>() -> usize {
     unsafe { intrinsics::size_of::<T>() }
 }
 
+/// Returns the number of variants in the enum type `T`.
+///
+/// If `T` is not an enum, the return value is 0. Uninhabited variants are
+/// counted like any other.
+///
+/// This is a `const fn`, so it can be used to size arrays indexed by an
+/// enum's variants.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(never_type)]
+/// #![feature(variant_count)]
+/// use std::mem;
+///
+/// enum Void {}
+/// enum Foo { A(&'static str), B(i32), C(i32) }
+///
+/// assert_eq!(mem::variant_count::<Void>(), 0);
+/// assert_eq!(mem::variant_count::<Foo>(), 3);
+///
+/// assert_eq!(mem::variant_count::<Option<!>>(), 2);
+/// assert_eq!(mem::variant_count::<Result<!, !>>(), 2);
+///
+/// #[derive(Copy, Clone)]
+/// enum Opcode { Load, Store, Add }
+///
+/// let mut counts = [0; mem::variant_count::<Opcode>()];
+/// counts[Opcode::Add as usize] += 1;
+/// assert_eq!(counts, [0, 0, 1]);
+/// ```
+#[inline]
+#[unstable(feature = "variant_count", issue = "0")]
+#[cfg(not(stage0))]
+pub const fn variant_count<T>() -> usize {
+    unsafe { intrinsics::variant_count::<T>() }
+}
+
 /// Returns the size of the pointed-to value in bytes.
 ///
 /// This is usually the same as `size_of::<T>()`. However, when `T` *has* no
@@ -553,6 +591,63 @@ pub fn swap<T>(x: &mut T, y: &mut T) {
     }
 }
 
+/// Replaces `dest` with the default value of `T`, and returns the previous `dest` value.
+///
+/// # Examples
+///
+/// A simple example:
+///
+/// ```
+/// #![feature(mem_take)]
+/// use std::mem;
+///
+/// let mut v: Vec<i32> = vec![1, 2];
+///
+/// let old_v = mem::take(&mut v);
+/// assert_eq!(vec![1, 2], old_v);
+/// assert!(v.is_empty());
+/// ```
+///
+/// `take` allows taking ownership of a struct field by replacing it with an "empty" value.
+/// Without `take` you can run into issues like these:
+///
+/// ```compile_fail,E0507
+/// struct Buffer<T> { buf: Vec<T> }
+///
+/// impl<T> Buffer<T> {
+///     fn get_and_reset(&mut self) -> Vec<T> {
+///         // error: cannot move out of dereference of `&mut`-pointer
+///         let buf = self.buf;
+///         self.buf = Vec::new();
+///         buf
+///     }
+/// }
+/// ```
+///
+/// Note that `T` does not necessarily implement [`Clone`], so it can't even clone and reset
+/// `self.buf`. But `take` can be used to disassociate the original value of `self.buf` from
+/// `self`, allowing it to be returned:
+///
+/// ```
+/// #![feature(mem_take)]
+/// # #![allow(dead_code)]
+/// use std::mem;
+///
+/// # struct Buffer<T> { buf: Vec<T> }
+/// impl<T> Buffer<T> {
+///     fn get_and_reset(&mut self) -> Vec<T> {
+///         mem::take(&mut self.buf)
+///     }
+/// }
+/// ```
+///
+/// [`Clone`]: ../../std/clone/trait.Clone.html
+#[inline]
+#[unstable(feature = "mem_take", issue = "0")]
+pub fn take<T: Default>(dest: &mut T) -> T {
+    replace(dest, T::default())
+}
+
 /// Replaces the value at a mutable location with a new one, returning the old value, without
 /// deinitializing either one.
 ///
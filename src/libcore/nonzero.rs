@@ -13,6 +13,7 @@
             reason = "needs an RFC to flesh out the design",
             issue = "27730")]
 
+use mem;
 use ops::CoerceUnsized;
 
 /// Unsafe trait to indicate what types are usable with the NonZero struct
@@ -45,6 +46,21 @@ macro_rules! impl_zeroable_for_integer_types {
                     *self == 0
                 }
             }
+
+            impl Option<NonZero<$Int>> {
+                /// Returns the contained value, or 0 if the option is `None`.
+                ///
+                /// This is the inverse of [`NonZero::new`], and compiles to
+                /// a plain copy since `None` is already represented by 0.
+                ///
+                /// [`NonZero::new`]: struct.NonZero.html#method.new
+                #[inline]
+                pub fn map_or_zero(self) -> $Int {
+                    // `Option<NonZero<$Int>>` has the same layout as `$Int`,
+                    // with `None` stored as 0.
+                    unsafe { mem::transmute(self) }
+                }
+            }
         )+
     }
 }
@@ -61,6 +77,14 @@ impl_zeroable_for_integer_types! {
 
 /// A wrapper type for raw pointers and integers that will never be
 /// NULL or 0 that might allow certain optimizations.
+///
+/// `Option<NonZero<T>>` is guaranteed to have the same size and layout as
+/// `T`, with `None` represented by zero (or NULL). For integers this makes
+/// `Option<NonZero<usize>>` and a `usize` using 0 as a "no value" sentinel
+/// interchangeable: [`new`] converts from the sentinel form, and
+/// `map_or_zero` converts back without branching.
+///
+/// [`new`]: #method.new
 #[lang = "non_zero"]
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash)]
 pub struct NonZero<T: Zeroable>(T);
@@ -2253,6 +2253,237 @@ impl u8 {
         intrinsics::add_with_overflow,
         intrinsics::sub_with_overflow,
         intrinsics::mul_with_overflow }
+
+    /// If the value of this byte is within the ASCII range, returns it as an
+    /// [ASCII character](ascii/struct.Char.html). Otherwise, returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_char)]
+    ///
+    /// assert_eq!(b'a'.as_ascii().map(|c| c.to_char()), Some('a'));
+    /// assert_eq!(0xE9u8.as_ascii(), None);
+    /// ```
+    #[unstable(feature = "ascii_char", issue = "0")]
+    #[inline]
+    pub fn as_ascii(&self) -> Option<::ascii::Char> {
+        ::ascii::Char::from_u8(*self)
+    }
+
+    /// Checks if the value is an ASCII alphabetic character:
+    ///
+    /// - U+0041 'A' ... U+005A 'Z', or
+    /// - U+0061 'a' ... U+007A 'z'.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_ctype)]
+    ///
+    /// assert!(b'G'.is_ascii_alphabetic());
+    /// assert!(!b'0'.is_ascii_alphabetic());
+    /// ```
+    #[unstable(feature = "ascii_ctype", issue = "39658")]
+    #[inline]
+    pub const fn is_ascii_alphabetic(&self) -> bool {
+        self.is_ascii_uppercase() | self.is_ascii_lowercase()
+    }
+
+    /// Checks if the value is an ASCII uppercase character:
+    /// U+0041 'A' ... U+005A 'Z'.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_ctype)]
+    ///
+    /// assert!(b'G'.is_ascii_uppercase());
+    /// assert!(!b'g'.is_ascii_uppercase());
+    /// ```
+    #[unstable(feature = "ascii_ctype", issue = "39658")]
+    #[inline]
+    pub const fn is_ascii_uppercase(&self) -> bool {
+        (*self >= b'A') & (*self <= b'Z')
+    }
+
+    /// Checks if the value is an ASCII lowercase character:
+    /// U+0061 'a' ... U+007A 'z'.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_ctype)]
+    ///
+    /// assert!(b'g'.is_ascii_lowercase());
+    /// assert!(!b'G'.is_ascii_lowercase());
+    /// ```
+    #[unstable(feature = "ascii_ctype", issue = "39658")]
+    #[inline]
+    pub const fn is_ascii_lowercase(&self) -> bool {
+        (*self >= b'a') & (*self <= b'z')
+    }
+
+    /// Checks if the value is an ASCII alphanumeric character:
+    ///
+    /// - U+0041 'A' ... U+005A 'Z', or
+    /// - U+0061 'a' ... U+007A 'z', or
+    /// - U+0030 '0' ... U+0039 '9'.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_ctype)]
+    ///
+    /// assert!(b'0'.is_ascii_alphanumeric());
+    /// assert!(!b'%'.is_ascii_alphanumeric());
+    /// ```
+    #[unstable(feature = "ascii_ctype", issue = "39658")]
+    #[inline]
+    pub const fn is_ascii_alphanumeric(&self) -> bool {
+        self.is_ascii_alphabetic() | self.is_ascii_digit()
+    }
+
+    /// Checks if the value is an ASCII decimal digit:
+    /// U+0030 '0' ... U+0039 '9'.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_ctype)]
+    ///
+    /// assert!(b'7'.is_ascii_digit());
+    /// assert!(!b'a'.is_ascii_digit());
+    /// ```
+    #[unstable(feature = "ascii_ctype", issue = "39658")]
+    #[inline]
+    pub const fn is_ascii_digit(&self) -> bool {
+        (*self >= b'0') & (*self <= b'9')
+    }
+
+    /// Checks if the value is an ASCII octal digit:
+    /// U+0030 '0' ... U+0037 '7'.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(is_ascii_octdigit)]
+    ///
+    /// assert!(b'7'.is_ascii_octdigit());
+    /// assert!(!b'8'.is_ascii_octdigit());
+    /// ```
+    #[unstable(feature = "is_ascii_octdigit", issue = "0")]
+    #[inline]
+    pub const fn is_ascii_octdigit(&self) -> bool {
+        (*self >= b'0') & (*self <= b'7')
+    }
+
+    /// Checks if the value is an ASCII hexadecimal digit:
+    ///
+    /// - U+0030 '0' ... U+0039 '9', or
+    /// - U+0041 'A' ... U+0046 'F', or
+    /// - U+0061 'a' ... U+0066 'f'.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_ctype)]
+    ///
+    /// assert!(b'f'.is_ascii_hexdigit());
+    /// assert!(!b'g'.is_ascii_hexdigit());
+    /// ```
+    #[unstable(feature = "ascii_ctype", issue = "39658")]
+    #[inline]
+    pub const fn is_ascii_hexdigit(&self) -> bool {
+        self.is_ascii_digit() |
+            ((*self >= b'A') & (*self <= b'F')) |
+            ((*self >= b'a') & (*self <= b'f'))
+    }
+
+    /// Checks if the value is an ASCII punctuation character:
+    ///
+    /// - U+0021 ... U+002F `! " # $ % & ' ( ) * + , - . /`, or
+    /// - U+003A ... U+0040 `: ; < = > ? @`, or
+    /// - U+005B ... U+0060 ``[ \ ] ^ _ ` ``, or
+    /// - U+007B ... U+007E `{ | } ~`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_ctype)]
+    ///
+    /// assert!(b'%'.is_ascii_punctuation());
+    /// assert!(!b'a'.is_ascii_punctuation());
+    /// ```
+    #[unstable(feature = "ascii_ctype", issue = "39658")]
+    #[inline]
+    pub const fn is_ascii_punctuation(&self) -> bool {
+        ((*self >= b'!') & (*self <= b'/')) |
+            ((*self >= b':') & (*self <= b'@')) |
+            ((*self >= b'[') & (*self <= b'`')) |
+            ((*self >= b'{') & (*self <= b'~'))
+    }
+
+    /// Checks if the value is an ASCII graphic character:
+    /// U+0021 '!' ... U+007E '~'.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_ctype)]
+    ///
+    /// assert!(b'%'.is_ascii_graphic());
+    /// assert!(!b' '.is_ascii_graphic());
+    /// ```
+    #[unstable(feature = "ascii_ctype", issue = "39658")]
+    #[inline]
+    pub const fn is_ascii_graphic(&self) -> bool {
+        (*self >= b'!') & (*self <= b'~')
+    }
+
+    /// Checks if the value is an ASCII whitespace character:
+    /// U+0020 SPACE, U+0009 HORIZONTAL TAB, U+000A LINE FEED,
+    /// U+000C FORM FEED, or U+000D CARRIAGE RETURN.
+    ///
+    /// Rust uses the WhatWG Infra Standard's [definition of ASCII
+    /// whitespace][infra-aw]. Note that this differs from C's `isspace`,
+    /// which also accepts U+000B VERTICAL TAB.
+    ///
+    /// [infra-aw]: https://infra.spec.whatwg.org/#ascii-whitespace
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_ctype)]
+    ///
+    /// assert!(b'\n'.is_ascii_whitespace());
+    /// assert!(!b'\x0B'.is_ascii_whitespace());
+    /// ```
+    #[unstable(feature = "ascii_ctype", issue = "39658")]
+    #[inline]
+    pub const fn is_ascii_whitespace(&self) -> bool {
+        (*self == b' ') | (*self == b'\t') | (*self == b'\n') |
+            (*self == b'\x0C') | (*self == b'\r')
+    }
+
+    /// Checks if the value is an ASCII control character:
+    /// U+0000 NUL ... U+001F UNIT SEPARATOR, or U+007F DELETE.
+    /// Note that most ASCII whitespace characters are control
+    /// characters, but SPACE is not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_ctype)]
+    ///
+    /// assert!(b'\x1b'.is_ascii_control());
+    /// assert!(!b' '.is_ascii_control());
+    /// ```
+    #[unstable(feature = "ascii_ctype", issue = "39658")]
+    #[inline]
+    pub const fn is_ascii_control(&self) -> bool {
+        (*self < b' ') | (*self == b'\x7F')
+    }
 }
 
 #[lang = "u16"]
@@ -0,0 +1,193 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use ops::Try;
+#[cfg(not(stage0))]
+use ops::{FromResidual, Residual};
+
+/// Used to tell an operation whether it should exit early or go on as usual.
+///
+/// This is the result type for internal iteration and visitor-style APIs,
+/// where stopping early is a normal outcome rather than an error. Unlike
+/// `Result`, neither variant claims to be a failure, and it still works with
+/// the `?` operator: `?` on a `Break` returns it from the enclosing function.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(control_flow_enum)]
+/// use std::ops::ControlFlow;
+///
+/// struct TreeNode<T> {
+///     value: T,
+///     left: Option<Box<TreeNode<T>>>,
+///     right: Option<Box<TreeNode<T>>>,
+/// }
+///
+/// impl<T> TreeNode<T> {
+///     fn traverse_inorder<B, F>(&self, f: &mut F) -> ControlFlow<B>
+///         where F: FnMut(&T) -> ControlFlow<B>
+///     {
+///         if let Some(ref left) = self.left {
+///             left.traverse_inorder(f)?;
+///         }
+///         f(&self.value)?;
+///         if let Some(ref right) = self.right {
+///             right.traverse_inorder(f)?;
+///         }
+///         ControlFlow::Continue(())
+///     }
+/// }
+///
+/// let leaf = |value| Some(Box::new(TreeNode { value: value, left: None, right: None }));
+/// let tree = TreeNode { value: 2, left: leaf(1), right: leaf(3) };
+///
+/// let mut seen = Vec::new();
+/// let res = tree.traverse_inorder(&mut |&x| {
+///     seen.push(x);
+///     if x >= 2 { ControlFlow::Break(x) } else { ControlFlow::Continue(()) }
+/// });
+/// assert_eq!(res, ControlFlow::Break(2));
+/// assert_eq!(seen, [1, 2]);
+/// ```
+#[unstable(feature = "control_flow_enum", issue = "0")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ControlFlow<B, C = ()> {
+    /// Move on to the next phase of the operation as normal.
+    #[unstable(feature = "control_flow_enum", issue = "0")]
+    Continue(C),
+    /// Exit the operation without running subsequent phases.
+    #[unstable(feature = "control_flow_enum", issue = "0")]
+    Break(B),
+}
+
+impl<B, C> ControlFlow<B, C> {
+    /// Returns `true` if this is a `Break` variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(control_flow_enum)]
+    /// use std::ops::ControlFlow;
+    ///
+    /// assert!(ControlFlow::<i32, String>::Break(3).is_break());
+    /// assert!(!ControlFlow::<String, i32>::Continue(3).is_break());
+    /// ```
+    #[inline]
+    #[unstable(feature = "control_flow_enum", issue = "0")]
+    pub fn is_break(&self) -> bool {
+        match *self {
+            ControlFlow::Break(_) => true,
+            ControlFlow::Continue(_) => false,
+        }
+    }
+
+    /// Returns `true` if this is a `Continue` variant.
+    #[inline]
+    #[unstable(feature = "control_flow_enum", issue = "0")]
+    pub fn is_continue(&self) -> bool {
+        !self.is_break()
+    }
+
+    /// Converts the `ControlFlow` into an `Option` which is `Some` if the
+    /// `ControlFlow` was `Break` and `None` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(control_flow_enum)]
+    /// use std::ops::ControlFlow;
+    ///
+    /// assert_eq!(ControlFlow::<i32, String>::Break(3).break_value(), Some(3));
+    /// assert_eq!(ControlFlow::<String, i32>::Continue(3).break_value(), None);
+    /// ```
+    #[inline]
+    #[unstable(feature = "control_flow_enum", issue = "0")]
+    pub fn break_value(self) -> Option<B> {
+        match self {
+            ControlFlow::Break(b) => Some(b),
+            ControlFlow::Continue(_) => None,
+        }
+    }
+
+    /// Converts the `ControlFlow` into an `Option` which is `Some` if the
+    /// `ControlFlow` was `Continue` and `None` otherwise.
+    #[inline]
+    #[unstable(feature = "control_flow_enum", issue = "0")]
+    pub fn continue_value(self) -> Option<C> {
+        match self {
+            ControlFlow::Continue(c) => Some(c),
+            ControlFlow::Break(_) => None,
+        }
+    }
+}
+
+#[cfg(stage0)]
+#[unstable(feature = "control_flow_enum", issue = "0")]
+impl<B, C> Try for ControlFlow<B, C> {
+    type Ok = C;
+    type Error = B;
+
+    #[inline]
+    fn into_result(self) -> Result<C, B> {
+        match self {
+            ControlFlow::Continue(c) => Ok(c),
+            ControlFlow::Break(b) => Err(b),
+        }
+    }
+
+    #[inline]
+    fn from_error(v: B) -> Self {
+        ControlFlow::Break(v)
+    }
+
+    #[inline]
+    fn from_ok(v: C) -> Self {
+        ControlFlow::Continue(v)
+    }
+}
+
+#[cfg(not(stage0))]
+#[unstable(feature = "control_flow_enum", issue = "0")]
+impl<B, C> Try for ControlFlow<B, C> {
+    type Output = C;
+    type Residual = ControlFlow<B, !>;
+
+    #[inline]
+    fn from_output(output: C) -> Self {
+        ControlFlow::Continue(output)
+    }
+
+    #[inline]
+    fn branch(self) -> ControlFlow<ControlFlow<B, !>, C> {
+        match self {
+            ControlFlow::Continue(c) => ControlFlow::Continue(c),
+            ControlFlow::Break(b) => ControlFlow::Break(ControlFlow::Break(b)),
+        }
+    }
+}
+
+#[cfg(not(stage0))]
+#[unstable(feature = "control_flow_enum", issue = "0")]
+impl<B, C> FromResidual<ControlFlow<B, !>> for ControlFlow<B, C> {
+    #[inline]
+    fn from_residual(residual: ControlFlow<B, !>) -> Self {
+        match residual {
+            ControlFlow::Break(b) => ControlFlow::Break(b),
+            ControlFlow::Continue(never) => never,
+        }
+    }
+}
+
+#[cfg(not(stage0))]
+#[unstable(feature = "control_flow_enum", issue = "0")]
+impl<B, C> Residual<C> for ControlFlow<B, !> {
+    type TryType = ControlFlow<B, C>;
+}
@@ -149,6 +149,7 @@
 
 mod arith;
 mod bit;
+mod control_flow;
 mod deref;
 mod drop;
 mod function;
@@ -188,6 +189,12 @@ pub use self::range::{RangeInclusive, RangeToInclusive};
 
 #[unstable(feature = "try_trait", issue = "42327")]
 pub use self::try::Try;
+#[cfg(not(stage0))]
+#[unstable(feature = "try_trait", issue = "42327")]
+pub use self::try::{FromResidual, Residual};
+
+#[unstable(feature = "control_flow_enum", issue = "0")]
+pub use self::control_flow::ControlFlow;
 
 #[unstable(feature = "placement_new_protocol", issue = "27779")]
 pub use self::place::{Place, Placer, InPlace, Boxed, BoxPlace};
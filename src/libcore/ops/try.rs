@@ -8,12 +8,16 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+#[cfg(not(stage0))]
+use ops::ControlFlow;
+
 /// A trait for customizing the behaviour of the `?` operator.
 ///
 /// A type implementing `Try` is one that has a canonical way to view it
 /// in terms of a success/failure dichotomy.  This trait allows both
 /// extracting those success or failure values from an existing instance and
 /// creating a new instance from a success or failure value.
+#[cfg(stage0)]
 #[unstable(feature = "try_trait", issue = "42327")]
 #[rustc_on_unimplemented = "the `?` operator can only be used in a function that returns `Result` \
                             (or another type that implements `{Try}`)"]
@@ -47,3 +51,159 @@ pub trait Try {
     #[unstable(feature = "try_trait", issue = "42327")]
     fn from_ok(v: Self::Ok) -> Self;
 }
+
+/// The `?` operator and `do catch {}` blocks.
+///
+/// `Try` views a value as either a value to carry on with, its *output*, or
+/// as a *residual* that should short-circuit. `<expr>?` is equivalent to
+///
+/// ```ignore
+/// match Try::branch(<expr>) {
+///     ControlFlow::Continue(output) => output,
+///     ControlFlow::Break(residual) => return FromResidual::from_residual(residual),
+/// }
+/// ```
+///
+/// Splitting the early exit out into [`FromResidual`] is what lets different
+/// types interoperate: a type decides which residuals it can be built from,
+/// so `?` on a `Result` can work in a function returning a custom outcome
+/// enum without either type having to pretend to be the other.
+///
+/// The residual usually has the same shape as the type itself, with the
+/// output made uninhabited: `Result<T, E>` has the residual `Result<!, E>`,
+/// and [`ControlFlow<B, C>`] has `ControlFlow<B, !>`. That keeps it
+/// impossible to construct a residual that holds an output value.
+///
+/// [`FromResidual`]: trait.FromResidual.html
+/// [`ControlFlow<B, C>`]: enum.ControlFlow.html
+///
+/// # Examples
+///
+/// A three-way outcome that can be used with `?`, and that accepts `?` on
+/// a `Result` too:
+///
+/// ```
+/// #![feature(try_trait, never_type)]
+/// use std::ops::{ControlFlow, FromResidual, Try};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Outcome<T> {
+///     Done(T),
+///     Retry,
+///     Failed(String),
+/// }
+///
+/// // What an `Outcome` short-circuits with: anything but `Done`.
+/// enum OutcomeResidual {
+///     Retry,
+///     Failed(String),
+/// }
+///
+/// impl<T> Try for Outcome<T> {
+///     type Output = T;
+///     type Residual = OutcomeResidual;
+///
+///     fn from_output(output: T) -> Self {
+///         Outcome::Done(output)
+///     }
+///
+///     fn branch(self) -> ControlFlow<OutcomeResidual, T> {
+///         match self {
+///             Outcome::Done(t) => ControlFlow::Continue(t),
+///             Outcome::Retry => ControlFlow::Break(OutcomeResidual::Retry),
+///             Outcome::Failed(e) => ControlFlow::Break(OutcomeResidual::Failed(e)),
+///         }
+///     }
+/// }
+///
+/// impl<T> FromResidual<OutcomeResidual> for Outcome<T> {
+///     fn from_residual(residual: OutcomeResidual) -> Self {
+///         match residual {
+///             OutcomeResidual::Retry => Outcome::Retry,
+///             OutcomeResidual::Failed(e) => Outcome::Failed(e),
+///         }
+///     }
+/// }
+///
+/// // `?` on a `Result` inside a function returning an `Outcome`.
+/// impl<T, E: ToString> FromResidual<Result<!, E>> for Outcome<T> {
+///     fn from_residual(residual: Result<!, E>) -> Self {
+///         match residual {
+///             Ok(never) => never,
+///             Err(e) => Outcome::Failed(e.to_string()),
+///         }
+///     }
+/// }
+///
+/// fn fetch(attempt: u32) -> Outcome<&'static str> {
+///     if attempt == 0 { Outcome::Retry } else { Outcome::Done("42") }
+/// }
+///
+/// fn parse_fetched(attempt: u32) -> Outcome<i32> {
+///     let text = fetch(attempt)?;
+///     let n = text.parse::<i32>()?;
+///     Outcome::Done(n)
+/// }
+///
+/// assert_eq!(parse_fetched(0), Outcome::Retry);
+/// assert_eq!(parse_fetched(1), Outcome::Done(42));
+/// ```
+#[cfg(not(stage0))]
+#[unstable(feature = "try_trait", issue = "42327")]
+#[rustc_on_unimplemented = "the `?` operator can only be applied to values \
+                            that implement `{Try}`"]
+pub trait Try {
+    /// The type of the value produced by `?` when *not* short-circuiting.
+    #[unstable(feature = "try_trait", issue = "42327")]
+    type Output;
+
+    /// The type of the value passed to [`FromResidual::from_residual`]
+    /// as part of `?` when short-circuiting.
+    ///
+    /// [`FromResidual::from_residual`]: trait.FromResidual.html#tymethod.from_residual
+    #[unstable(feature = "try_trait", issue = "42327")]
+    type Residual;
+
+    /// Constructs the type from its `Output` type. For example,
+    /// `Result::from_output(x)` and `Ok(x)` are equivalent.
+    #[unstable(feature = "try_trait", issue = "42327")]
+    fn from_output(output: Self::Output) -> Self;
+
+    /// Used in `?` to decide whether the operator should produce a value
+    /// (`Continue`) or propagate a value up to the caller (`Break`).
+    #[unstable(feature = "try_trait", issue = "42327")]
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output>;
+}
+
+/// Used to specify which residuals can be converted into which [`Try`] types.
+///
+/// This is the conversion `?` performs when it short-circuits: in a function
+/// returning `T`, `x?` requires `T: FromResidual<R>` where `R` is the
+/// residual of `x`.
+///
+/// [`Try`]: trait.Try.html
+#[cfg(not(stage0))]
+#[unstable(feature = "try_trait", issue = "42327")]
+#[rustc_on_unimplemented = "the `?` operator can only be used in a function that returns `Result` \
+                            (or another type that implements `{FromResidual}`)"]
+pub trait FromResidual<R> {
+    /// Constructs the type from a compatible `Residual` type.
+    #[unstable(feature = "try_trait", issue = "42327")]
+    fn from_residual(residual: R) -> Self;
+}
+
+/// Allows getting back to a [`Try`] type from one of its residuals, with a
+/// different output type.
+///
+/// Generic code that runs a fallible closure and wants to return the
+/// closure's "kind" of failure with its own output type uses this, as
+/// `Iterator::try_find` does.
+///
+/// [`Try`]: trait.Try.html
+#[cfg(not(stage0))]
+#[unstable(feature = "try_trait", issue = "42327")]
+pub trait Residual<O>: Sized {
+    /// The "return" type of this meta-function.
+    #[unstable(feature = "try_trait", issue = "42327")]
+    type TryType: Try<Output = O, Residual = Self> + FromResidual<Self>;
+}
@@ -13,6 +13,24 @@
 //! Raw, unsafe pointers, `*const T`, and `*mut T`.
 //!
 //! *[See also the pointer primitive types](../../std/primitive.pointer.html).*
+//!
+//! # Provenance
+//!
+//! A pointer is more than its address: it also carries *provenance*, the
+//! permission to access the allocation it was derived from. Casting a
+//! pointer to an integer keeps the address but drops the provenance, and
+//! optimizations are allowed to assume that a pointer cast back from an
+//! integer doesn't alias pointers it wasn't obviously computed from.
+//!
+//! Code that needs to inspect or change the address of a pointer, such as
+//! pointer tagging, should use [`addr`] and [`with_addr`] (or [`map_addr`]),
+//! which keep the provenance of the original pointer. Pointers that are
+//! never dereferenced, like sentinels, can be made with [`invalid`].
+//!
+//! [`addr`]: ../../std/primitive.pointer.html#method.addr
+//! [`with_addr`]: ../../std/primitive.pointer.html#method.with_addr
+//! [`map_addr`]: ../../std/primitive.pointer.html#method.map_addr
+//! [`invalid`]: fn.invalid.html
 
 #![stable(feature = "rust1", since = "1.0.0")]
 
@@ -26,6 +44,7 @@ use mem;
 use nonzero::NonZero;
 
 use cmp::Ordering::{self, Less, Equal, Greater};
+use cmp;
 
 // FIXME #19649: intrinsic docs don't render, so these have no docs :(
 
@@ -92,6 +111,86 @@ pub const fn null<T>() -> *const T { 0 as *const T }
 #[stable(feature = "rust1", since = "1.0.0")]
 pub const fn null_mut<T>() -> *mut T { 0 as *mut T }
 
+/// Creates a pointer with the given address and no [provenance].
+///
+/// The resulting pointer may not be used to access memory. It is useful
+/// for sentinel values and for dangling-but-aligned pointers, where an
+/// integer-to-pointer cast would otherwise be used.
+///
+/// [provenance]: ../../std/ptr/index.html#provenance
+///
+/// # Examples
+///
+/// ```
+/// #![feature(strict_provenance)]
+/// use std::ptr;
+///
+/// let sentinel: *const u64 = ptr::invalid(8);
+/// assert_eq!(sentinel.addr(), 8);
+/// ```
+#[inline]
+#[unstable(feature = "strict_provenance", issue = "0")]
+pub const fn invalid<T>(addr: usize) -> *const T {
+    addr as *const T
+}
+
+/// Creates a mutable pointer with the given address and no [provenance].
+///
+/// See [`invalid`] for details.
+///
+/// [provenance]: ../../std/ptr/index.html#provenance
+/// [`invalid`]: fn.invalid.html
+#[inline]
+#[unstable(feature = "strict_provenance", issue = "0")]
+pub const fn invalid_mut<T>(addr: usize) -> *mut T {
+    addr as *mut T
+}
+
+#[repr(C)]
+struct FatPtr<T> {
+    data: *const T,
+    len: usize,
+}
+
+/// Forms a raw slice from a pointer and a length.
+///
+/// The `len` argument is the number of **elements**, not the number of bytes.
+///
+/// This function is safe, but actually using the return value is unsafe.
+/// See the documentation of [`slice::from_raw_parts`] for slice safety
+/// requirements.
+///
+/// [`slice::from_raw_parts`]: ../../std/slice/fn.from_raw_parts.html
+///
+/// # Examples
+///
+/// ```
+/// #![feature(slice_from_raw_parts)]
+/// use std::ptr;
+///
+/// // create a slice pointer when starting out with a pointer to the first element
+/// let x = [5, 6, 7];
+/// let slice = ptr::slice_from_raw_parts(x.as_ptr(), 3);
+/// assert_eq!(unsafe { &*slice }[2], 7);
+/// ```
+#[inline]
+#[unstable(feature = "slice_from_raw_parts", issue = "0")]
+pub fn slice_from_raw_parts<T>(data: *const T, len: usize) -> *const [T] {
+    unsafe { mem::transmute(FatPtr { data: data, len: len }) }
+}
+
+/// Performs the same functionality as [`slice_from_raw_parts`], except that a
+/// raw mutable slice is returned, as opposed to a raw immutable slice.
+///
+/// See the documentation of [`slice_from_raw_parts`] for more details.
+///
+/// [`slice_from_raw_parts`]: fn.slice_from_raw_parts.html
+#[inline]
+#[unstable(feature = "slice_from_raw_parts", issue = "0")]
+pub fn slice_from_raw_parts_mut<T>(data: *mut T, len: usize) -> *mut [T] {
+    unsafe { mem::transmute(FatPtr { data: data as *const T, len: len }) }
+}
+
 /// Swaps the values at two mutable locations of the same type, without
 /// deinitializing either. They may overlap, unlike `mem::swap` which is
 /// otherwise equivalent.
@@ -620,6 +719,160 @@ impl<T: ?Sized> *const T {
             Some(diff / size as isize)
         }
     }
+
+    /// Computes the number of elements of `T` that need to be added to the
+    /// pointer so that it becomes aligned to `align` bytes.
+    ///
+    /// If it is not possible to align the pointer by stepping whole `T`s,
+    /// `usize::max_value()` is returned. Zero-sized `T`s can only be
+    /// "aligned" if the pointer already is.
+    ///
+    /// The offset is computed from the address alone, so the result does not
+    /// depend on the pointer being valid or in bounds of anything; actually
+    /// offsetting by it is only sound if the result stays within the same
+    /// allocation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `align` is not a power of two.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(pointer_is_aligned)]
+    ///
+    /// let data = [0u8; 16];
+    /// let ptr: *const u8 = &data[0];
+    /// let offset = ptr.align_offset(8);
+    /// assert!(offset < 8);
+    /// assert!(ptr.wrapping_offset(offset as isize).is_aligned_to(8));
+    /// ```
+    #[unstable(feature = "pointer_is_aligned", issue = "0")]
+    #[inline]
+    pub fn align_offset(self, align: usize) -> usize where T: Sized {
+        if !align.is_power_of_two() {
+            panic!("align_offset: align is not a power-of-two");
+        }
+        align_offset(self as *const T as usize, mem::size_of::<T>(), align)
+    }
+
+    /// Returns whether the pointer is properly aligned for `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(pointer_is_aligned)]
+    ///
+    /// let data = [0u32; 2];
+    /// let ptr: *const u32 = &data[0];
+    /// assert!(ptr.is_aligned());
+    /// assert!(!(ptr as *const u8).wrapping_offset(1).is_aligned_to(4));
+    /// ```
+    #[unstable(feature = "pointer_is_aligned", issue = "0")]
+    #[inline]
+    pub fn is_aligned(self) -> bool where T: Sized {
+        self.is_aligned_to(mem::align_of::<T>())
+    }
+
+    /// Returns whether the pointer is aligned to `align` bytes.
+    ///
+    /// For non-`Sized` pointees, only the address part of the pointer is
+    /// considered.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `align` is not a power of two.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(pointer_is_aligned)]
+    ///
+    /// let data = [0u32; 2];
+    /// let ptr = &data[0] as *const u32 as *const u8;
+    /// assert!(ptr.is_aligned_to(1));
+    /// assert!(ptr.is_aligned_to(4));
+    /// assert!(!ptr.wrapping_offset(2).is_aligned_to(4));
+    /// assert!(ptr.wrapping_offset(2).is_aligned_to(2));
+    /// ```
+    #[unstable(feature = "pointer_is_aligned", issue = "0")]
+    #[inline]
+    pub fn is_aligned_to(self, align: usize) -> bool {
+        if !align.is_power_of_two() {
+            panic!("is_aligned_to: align is not a power-of-two");
+        }
+        self as *const u8 as usize & (align - 1) == 0
+    }
+
+    /// Gets the "address" portion of the pointer.
+    ///
+    /// This is similar to `self as usize`, except that the [provenance] of
+    /// the pointer is discarded explicitly. Going back to a pointer with
+    /// `addr as *const T` is not guaranteed to produce a usable pointer; use
+    /// [`with_addr`] on the original pointer instead.
+    ///
+    /// For fat pointers, only the data address is returned.
+    ///
+    /// [provenance]: ../../std/ptr/index.html#provenance
+    /// [`with_addr`]: #method.with_addr
+    #[unstable(feature = "strict_provenance", issue = "0")]
+    #[inline]
+    pub fn addr(self) -> usize {
+        self as *const u8 as usize
+    }
+
+    /// Creates a new pointer with the given address.
+    ///
+    /// The result has the [provenance] of `self`: it may only be used to
+    /// access memory that `self` could have accessed. This is the sanctioned
+    /// way to store extra bits in the unused bits of a pointer, for example
+    /// in its low bits when `T` is aligned.
+    ///
+    /// [provenance]: ../../std/ptr/index.html#provenance
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(strict_provenance)]
+    ///
+    /// let x = 7u32;
+    /// let ptr: *const u32 = &x;
+    /// let tagged = ptr.with_addr(ptr.addr() | 1);
+    /// assert_eq!(tagged.addr() & 1, 1);
+    /// let untagged = tagged.with_addr(tagged.addr() & !1);
+    /// assert_eq!(unsafe { *untagged }, 7);
+    /// ```
+    #[unstable(feature = "strict_provenance", issue = "0")]
+    #[inline]
+    pub fn with_addr(self, addr: usize) -> Self where T: Sized {
+        // Offsetting from `self` is what keeps the provenance; going through
+        // an integer would drop it.
+        let offset = (addr as isize).wrapping_sub(self.addr() as isize);
+        (self as *const u8).wrapping_offset(offset) as *const T
+    }
+
+    /// Creates a new pointer by mapping `self`'s address to a new one.
+    ///
+    /// This is a convenience for [`with_addr`], see that method for details.
+    ///
+    /// [`with_addr`]: #method.with_addr
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(strict_provenance)]
+    ///
+    /// let x = [1u16, 2, 3];
+    /// let ptr: *const u16 = &x[0];
+    /// let tagged = ptr.map_addr(|a| a | 1);
+    /// let untagged = tagged.map_addr(|a| a & !1);
+    /// assert_eq!(unsafe { *untagged }, 1);
+    /// ```
+    #[unstable(feature = "strict_provenance", issue = "0")]
+    #[inline]
+    pub fn map_addr<F: FnOnce(usize) -> usize>(self, f: F) -> Self where T: Sized {
+        self.with_addr(f(self.addr()))
+    }
 }
 
 #[lang = "mut_ptr"]
@@ -811,6 +1064,196 @@ impl<T: ?Sized> *mut T {
             Some(diff / size as isize)
         }
     }
+
+    /// Computes the number of elements of `T` that need to be added to the
+    /// pointer so that it becomes aligned to `align` bytes.
+    ///
+    /// If it is not possible to align the pointer by stepping whole `T`s,
+    /// `usize::max_value()` is returned. Zero-sized `T`s can only be
+    /// "aligned" if the pointer already is.
+    ///
+    /// The offset is computed from the address alone, so the result does not
+    /// depend on the pointer being valid or in bounds of anything; actually
+    /// offsetting by it is only sound if the result stays within the same
+    /// allocation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `align` is not a power of two.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(pointer_is_aligned)]
+    ///
+    /// let mut data = [0u8; 16];
+    /// let ptr: *mut u8 = &mut data[0];
+    /// let offset = ptr.align_offset(8);
+    /// assert!(offset < 8);
+    /// assert!(ptr.wrapping_offset(offset as isize).is_aligned_to(8));
+    /// ```
+    #[unstable(feature = "pointer_is_aligned", issue = "0")]
+    #[inline]
+    pub fn align_offset(self, align: usize) -> usize where T: Sized {
+        if !align.is_power_of_two() {
+            panic!("align_offset: align is not a power-of-two");
+        }
+        align_offset(self as *const T as usize, mem::size_of::<T>(), align)
+    }
+
+    /// Returns whether the pointer is properly aligned for `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(pointer_is_aligned)]
+    ///
+    /// let mut data = [0u32; 2];
+    /// let ptr: *mut u32 = &mut data[0];
+    /// assert!(ptr.is_aligned());
+    /// assert!(!(ptr as *mut u8).wrapping_offset(1).is_aligned_to(4));
+    /// ```
+    #[unstable(feature = "pointer_is_aligned", issue = "0")]
+    #[inline]
+    pub fn is_aligned(self) -> bool where T: Sized {
+        self.is_aligned_to(mem::align_of::<T>())
+    }
+
+    /// Returns whether the pointer is aligned to `align` bytes.
+    ///
+    /// For non-`Sized` pointees, only the address part of the pointer is
+    /// considered.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `align` is not a power of two.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(pointer_is_aligned)]
+    ///
+    /// let mut data = [0u32; 2];
+    /// let ptr = &mut data[0] as *mut u32 as *mut u8;
+    /// assert!(ptr.is_aligned_to(1));
+    /// assert!(ptr.is_aligned_to(4));
+    /// assert!(!ptr.wrapping_offset(2).is_aligned_to(4));
+    /// assert!(ptr.wrapping_offset(2).is_aligned_to(2));
+    /// ```
+    #[unstable(feature = "pointer_is_aligned", issue = "0")]
+    #[inline]
+    pub fn is_aligned_to(self, align: usize) -> bool {
+        if !align.is_power_of_two() {
+            panic!("is_aligned_to: align is not a power-of-two");
+        }
+        self as *const u8 as usize & (align - 1) == 0
+    }
+
+    /// Gets the "address" portion of the pointer.
+    ///
+    /// This is similar to `self as usize`, except that the [provenance] of
+    /// the pointer is discarded explicitly. Going back to a pointer with
+    /// `addr as *mut T` is not guaranteed to produce a usable pointer; use
+    /// [`with_addr`] on the original pointer instead.
+    ///
+    /// For fat pointers, only the data address is returned.
+    ///
+    /// [provenance]: ../../std/ptr/index.html#provenance
+    /// [`with_addr`]: #method.with_addr
+    #[unstable(feature = "strict_provenance", issue = "0")]
+    #[inline]
+    pub fn addr(self) -> usize {
+        self as *mut u8 as usize
+    }
+
+    /// Creates a new pointer with the given address.
+    ///
+    /// The result has the [provenance] of `self`: it may only be used to
+    /// access memory that `self` could have accessed. This is the sanctioned
+    /// way to store extra bits in the unused bits of a pointer, for example
+    /// in its low bits when `T` is aligned.
+    ///
+    /// [provenance]: ../../std/ptr/index.html#provenance
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(strict_provenance)]
+    ///
+    /// let mut x = 7u32;
+    /// let ptr: *mut u32 = &mut x;
+    /// let tagged = ptr.with_addr(ptr.addr() | 1);
+    /// assert_eq!(tagged.addr() & 1, 1);
+    /// let untagged = tagged.with_addr(tagged.addr() & !1);
+    /// assert_eq!(unsafe { *untagged }, 7);
+    /// ```
+    #[unstable(feature = "strict_provenance", issue = "0")]
+    #[inline]
+    pub fn with_addr(self, addr: usize) -> Self where T: Sized {
+        // Offsetting from `self` is what keeps the provenance; going through
+        // an integer would drop it.
+        let offset = (addr as isize).wrapping_sub(self.addr() as isize);
+        (self as *mut u8).wrapping_offset(offset) as *mut T
+    }
+
+    /// Creates a new pointer by mapping `self`'s address to a new one.
+    ///
+    /// This is a convenience for [`with_addr`], see that method for details.
+    ///
+    /// [`with_addr`]: #method.with_addr
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(strict_provenance)]
+    ///
+    /// let mut x = [1u16, 2, 3];
+    /// let ptr: *mut u16 = &mut x[0];
+    /// let tagged = ptr.map_addr(|a| a | 1);
+    /// let untagged = tagged.map_addr(|a| a & !1);
+    /// assert_eq!(unsafe { *untagged }, 1);
+    /// ```
+    #[unstable(feature = "strict_provenance", issue = "0")]
+    #[inline]
+    pub fn map_addr<F: FnOnce(usize) -> usize>(self, f: F) -> Self where T: Sized {
+        self.with_addr(f(self.addr()))
+    }
+}
+
+/// Returns the smallest `k` such that `addr + k * stride` is a multiple of
+/// `align`, or `usize::max_value()` if there is none.
+///
+/// `align` must be a power of two.
+fn align_offset(addr: usize, stride: usize, align: usize) -> usize {
+    let mask = align - 1;
+    let misalign = addr & mask;
+    if misalign == 0 {
+        return 0;
+    }
+    if stride == 0 {
+        return usize::max_value();
+    }
+
+    // Solve `k * stride = -addr (mod align)`. Only the low bits of `stride`
+    // up to its lowest set bit (capped at `align`) can be cancelled out by the
+    // modular inverse, so first check that `addr` is a multiple of them.
+    let gcd = cmp::min(1 << stride.trailing_zeros(), align);
+    let needed = align - misalign;
+    if needed & (gcd - 1) != 0 {
+        return usize::max_value();
+    }
+    let align = align / gcd;
+    let stride = stride / gcd;
+    let needed = needed / gcd;
+
+    // `stride` is now odd and so invertible modulo any power of two. Newton's
+    // iteration doubles the number of correct low bits on each step, starting
+    // from the 3 bits that `stride` itself gets right.
+    let mut inverse = stride;
+    while stride.wrapping_mul(inverse) & (align - 1) != 1 {
+        inverse = inverse.wrapping_mul(2usize.wrapping_sub(stride.wrapping_mul(inverse)));
+    }
+    needed.wrapping_mul(inverse) & (align - 1)
 }
 
 // Equality for pointers
@@ -1235,6 +1678,88 @@ impl<T: Sized> Shared<T> {
             Shared::new_unchecked(ptr)
         }
     }
+
+    /// Creates a new `Shared` that is dangling, but well-aligned.
+    ///
+    /// This is the same as [`empty`], but usable in constants.
+    ///
+    /// [`empty`]: #method.empty
+    #[cfg(not(stage0))]
+    pub const fn dangling() -> Self {
+        Shared {
+            pointer: unsafe { NonZero::new_unchecked(mem::align_of::<T>() as *const T) },
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates a new `Shared` that is dangling, but well-aligned.
+    #[cfg(stage0)]
+    pub fn dangling() -> Self {
+        Shared::empty()
+    }
+
+    /// Creates a `Shared<[T]>` from a pointer to the first element and a
+    /// length.
+    ///
+    /// This function is safe, but dereferencing the return value is unsafe.
+    /// See the documentation of [`slice::from_raw_parts`] for slice safety
+    /// requirements.
+    ///
+    /// [`slice::from_raw_parts`]: ../../std/slice/fn.from_raw_parts.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(shared)]
+    /// use std::ptr::Shared;
+    ///
+    /// let mut x = [5, 6, 7];
+    /// let first = Shared::new(x.as_mut_ptr()).unwrap();
+    /// let slice = Shared::slice_from_raw_parts(first, 3);
+    /// assert_eq!(slice.len(), 3);
+    /// assert_eq!(unsafe { slice.as_ref() }[2], 7);
+    /// ```
+    pub fn slice_from_raw_parts(data: Shared<T>, len: usize) -> Shared<[T]> {
+        unsafe { Shared::new_unchecked(slice_from_raw_parts_mut(data.as_ptr(), len)) }
+    }
+}
+
+#[unstable(feature = "shared", issue = "27730")]
+impl<T> Shared<[T]> {
+    /// Returns the length of a non-null raw slice.
+    ///
+    /// The returned value is the number of **elements**, not the number of
+    /// bytes. It's read from the pointer itself, so this is safe even if the
+    /// slice is dangling.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(shared)]
+    /// use std::ptr::Shared;
+    ///
+    /// let slice: Shared<[i8]> = Shared::slice_from_raw_parts(Shared::dangling(), 3);
+    /// assert_eq!(slice.len(), 3);
+    /// ```
+    pub fn len(self) -> usize {
+        unsafe { mem::transmute::<*mut [T], FatPtr<T>>(self.as_ptr()).len }
+    }
+
+    /// Returns a `Shared` pointer to the slice's buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(shared)]
+    /// use std::ptr::Shared;
+    ///
+    /// let first: Shared<i8> = Shared::dangling();
+    /// let slice = Shared::slice_from_raw_parts(first, 3);
+    /// assert_eq!(slice.as_non_null_ptr().as_ptr(), first.as_ptr());
+    /// ```
+    pub fn as_non_null_ptr(self) -> Shared<T> {
+        unsafe { Shared::new_unchecked(self.as_ptr() as *mut T) }
+    }
 }
 
 #[unstable(feature = "shared", issue = "27730")]
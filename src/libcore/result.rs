@@ -241,6 +241,7 @@
 #![stable(feature = "rust1", since = "1.0.0")]
 
 use fmt;
+use intrinsics;
 use iter::{FromIterator, FusedIterator, TrustedLen};
 use ops;
 
@@ -291,6 +292,33 @@ impl<T, E> Result<T, E> {
         }
     }
 
+    /// Returns `true` if the result is `Ok` and the value inside of it matches
+    /// a predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(is_some_and)]
+    ///
+    /// let x: Result<u32, &str> = Ok(2);
+    /// assert_eq!(x.is_ok_and(|x| x > 1), true);
+    ///
+    /// let x: Result<u32, &str> = Ok(0);
+    /// assert_eq!(x.is_ok_and(|x| x > 1), false);
+    ///
+    /// let x: Result<u32, &str> = Err("hey");
+    /// assert_eq!(x.is_ok_and(|x| x > 1), false);
+    /// ```
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "is_some_and", issue = "0")]
+    pub fn is_ok_and<F: FnOnce(T) -> bool>(self, f: F) -> bool {
+        match self {
+            Ok(x) => f(x),
+            Err(_) => false,
+        }
+    }
+
     /// Returns `true` if the result is `Err`.
     ///
     /// # Examples
@@ -310,6 +338,34 @@ impl<T, E> Result<T, E> {
         !self.is_ok()
     }
 
+    /// Returns `true` if the result is `Err` and the value inside of it matches
+    /// a predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(is_some_and)]
+    /// use std::io::{Error, ErrorKind};
+    ///
+    /// let x: Result<u32, Error> = Err(Error::new(ErrorKind::NotFound, "!"));
+    /// assert_eq!(x.is_err_and(|x| x.kind() == ErrorKind::NotFound), true);
+    ///
+    /// let x: Result<u32, Error> = Err(Error::new(ErrorKind::PermissionDenied, "!"));
+    /// assert_eq!(x.is_err_and(|x| x.kind() == ErrorKind::NotFound), false);
+    ///
+    /// let x: Result<u32, Error> = Ok(123);
+    /// assert_eq!(x.is_err_and(|x| x.kind() == ErrorKind::NotFound), false);
+    /// ```
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "is_some_and", issue = "0")]
+    pub fn is_err_and<F: FnOnce(E) -> bool>(self, f: F) -> bool {
+        match self {
+            Ok(_) => false,
+            Err(e) => f(e),
+        }
+    }
+
     /////////////////////////////////////////////////////////////////////////
     // Adapter for each variant
     /////////////////////////////////////////////////////////////////////////
@@ -707,6 +763,67 @@ impl<T, E> Result<T, E> {
             Err(e) => op(e)
         }
     }
+
+    /// Unwraps a result, yielding the content of an `Ok`, without checking
+    /// that the value is not an `Err`.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method on an `Err` is *[undefined behavior]*.
+    ///
+    /// [undefined behavior]: ../../reference/behavior-considered-undefined.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(option_result_unwrap_unchecked)]
+    ///
+    /// let x: Result<u32, &str> = Ok(2);
+    /// assert_eq!(unsafe { x.unwrap_unchecked() }, 2);
+    /// ```
+    ///
+    /// ```no_run
+    /// #![feature(option_result_unwrap_unchecked)]
+    ///
+    /// let x: Result<u32, &str> = Err("emergency failure");
+    /// unsafe { x.unwrap_unchecked(); } // Undefined behavior!
+    /// ```
+    #[inline]
+    #[unstable(feature = "option_result_unwrap_unchecked", issue = "0")]
+    pub unsafe fn unwrap_unchecked(self) -> T {
+        debug_assert!(self.is_ok());
+        match self {
+            Ok(t) => t,
+            Err(_) => intrinsics::unreachable(),
+        }
+    }
+
+    /// Unwraps a result, yielding the content of an `Err`, without checking
+    /// that the value is not an `Ok`.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method on an `Ok` is *[undefined behavior]*.
+    ///
+    /// [undefined behavior]: ../../reference/behavior-considered-undefined.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(option_result_unwrap_unchecked)]
+    ///
+    /// let x: Result<u32, &str> = Err("emergency failure");
+    /// assert_eq!(unsafe { x.unwrap_err_unchecked() }, "emergency failure");
+    /// ```
+    #[inline]
+    #[unstable(feature = "option_result_unwrap_unchecked", issue = "0")]
+    pub unsafe fn unwrap_err_unchecked(self) -> E {
+        debug_assert!(self.is_err());
+        match self {
+            Ok(_) => intrinsics::unreachable(),
+            Err(e) => e,
+        }
+    }
 }
 
 impl<T, E: fmt::Debug> Result<T, E> {
@@ -853,6 +970,114 @@ impl<T: Default, E> Result<T, E> {
     }
 }
 
+impl<T> Result<T, !> {
+    /// Unwraps a result that can never be an `Err`, yielding the content of
+    /// the `Ok`.
+    ///
+    /// Unlike [`unwrap`], this method is known to never panic, since the
+    /// error type `!` has no values. It is a compile error to call it on a
+    /// result whose error type could be inhabited.
+    ///
+    /// [`unwrap`]: #method.unwrap
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(never_type)]
+    /// #![feature(unwrap_infallible)]
+    ///
+    /// fn only_good_news() -> Result<String, !> {
+    ///     Ok("this is fine".into())
+    /// }
+    ///
+    /// let s: String = only_good_news().into_ok();
+    /// println!("{}", s);
+    /// ```
+    #[inline]
+    #[unstable(feature = "unwrap_infallible", issue = "0")]
+    pub fn into_ok(self) -> T {
+        match self {
+            Ok(x) => x,
+            Err(e) => e,
+        }
+    }
+}
+
+impl<E> Result<!, E> {
+    /// Unwraps a result that can never be an `Ok`, yielding the content of
+    /// the `Err`.
+    ///
+    /// Unlike [`unwrap_err`], this method is known to never panic, since the
+    /// success type `!` has no values.
+    ///
+    /// [`unwrap_err`]: #method.unwrap_err
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(never_type)]
+    /// #![feature(unwrap_infallible)]
+    ///
+    /// fn only_bad_news() -> Result<!, String> {
+    ///     Err("Oops, it failed".into())
+    /// }
+    ///
+    /// let error: String = only_bad_news().into_err();
+    /// println!("{}", error);
+    /// ```
+    #[inline]
+    #[unstable(feature = "unwrap_infallible", issue = "0")]
+    pub fn into_err(self) -> E {
+        match self {
+            Ok(x) => x,
+            Err(e) => e,
+        }
+    }
+}
+
+impl<T, E> Result<Result<T, E>, E> {
+    /// Converts from `Result<Result<T, E>, E>` to `Result<T, E>`
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(result_flattening)]
+    ///
+    /// let x: Result<Result<&'static str, u32>, u32> = Ok(Ok("hello"));
+    /// assert_eq!(Ok("hello"), x.flatten());
+    ///
+    /// let x: Result<Result<&'static str, u32>, u32> = Ok(Err(6));
+    /// assert_eq!(Err(6), x.flatten());
+    ///
+    /// let x: Result<Result<&'static str, u32>, u32> = Err(6);
+    /// assert_eq!(Err(6), x.flatten());
+    /// ```
+    ///
+    /// Flattening only removes one level of nesting at a time:
+    ///
+    /// ```
+    /// #![feature(result_flattening)]
+    ///
+    /// let x: Result<Result<Result<&'static str, u32>, u32>, u32> = Ok(Ok(Ok("hello")));
+    /// assert_eq!(Ok(Ok("hello")), x.flatten());
+    /// assert_eq!(Ok("hello"), x.flatten().flatten());
+    /// ```
+    #[inline]
+    #[unstable(feature = "result_flattening", issue = "0")]
+    pub fn flatten(self) -> Result<T, E> {
+        match self {
+            Ok(inner) => inner,
+            Err(e) => Err(e),
+        }
+    }
+}
+
 // This is a separate function to reduce the code size of the methods
 #[inline(never)]
 #[cold]
@@ -1107,6 +1332,7 @@ impl<A, E, V: FromIterator<A>> FromIterator<Result<A, E>> for Result<V, E> {
     }
 }
 
+#[cfg(stage0)]
 #[unstable(feature = "try_trait", issue = "42327")]
 impl<T,E> ops::Try for Result<T, E> {
     type Ok = T;
@@ -1124,3 +1350,41 @@ impl<T,E> ops::Try for Result<T, E> {
         Err(v)
     }
 }
+
+#[cfg(not(stage0))]
+#[unstable(feature = "try_trait", issue = "42327")]
+impl<T, E> ops::Try for Result<T, E> {
+    type Output = T;
+    type Residual = Result<!, E>;
+
+    #[inline]
+    fn from_output(output: T) -> Self {
+        Ok(output)
+    }
+
+    #[inline]
+    fn branch(self) -> ops::ControlFlow<Result<!, E>, T> {
+        match self {
+            Ok(v) => ops::ControlFlow::Continue(v),
+            Err(e) => ops::ControlFlow::Break(Err(e)),
+        }
+    }
+}
+
+#[cfg(not(stage0))]
+#[unstable(feature = "try_trait", issue = "42327")]
+impl<T, E, F: From<E>> ops::FromResidual<Result<!, E>> for Result<T, F> {
+    #[inline]
+    fn from_residual(residual: Result<!, E>) -> Self {
+        match residual {
+            Ok(never) => never,
+            Err(e) => Err(From::from(e)),
+        }
+    }
+}
+
+#[cfg(not(stage0))]
+#[unstable(feature = "try_trait", issue = "42327")]
+impl<T, E> ops::Residual<T> for Result<!, E> {
+    type TryType = Result<T, E>;
+}
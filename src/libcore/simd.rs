@@ -0,0 +1,664 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Portable SIMD vector types.
+//!
+//! This module provides 128-bit vectors of integers and floats, such as
+//! [`f32x4`] or [`u8x16`], along with mask vectors like [`m32x4`] that
+//! are the result of lane-wise comparisons.
+//!
+//! The operations are written lane by lane, on types that are laid out as
+//! SIMD vectors, so that LLVM can turn them into the best vector
+//! instructions the target has. None of them depend on target features,
+//! and all of them are safe: loads and stores go through slices and panic
+//! if the slice is too short.
+//!
+//! Integer arithmetic wraps on overflow in every lane, as is usual for
+//! SIMD, while division by zero panics like it does for scalars.
+//!
+//! [`f32x4`]: struct.f32x4.html
+//! [`u8x16`]: struct.u8x16.html
+//! [`m32x4`]: struct.m32x4.html
+//!
+//! # Examples
+//!
+//! ```
+//! #![feature(portable_simd)]
+//! use std::simd::f32x4;
+//!
+//! fn dot(a: &[f32], b: &[f32]) -> f32 {
+//!     assert_eq!(a.len(), b.len());
+//!     let mut sum = f32x4::splat(0.0);
+//!     let mut i = 0;
+//!     while i + f32x4::LANES <= a.len() {
+//!         sum += f32x4::from_slice(&a[i..]) * f32x4::from_slice(&b[i..]);
+//!         i += f32x4::LANES;
+//!     }
+//!     let tail: f32 = a[i..].iter().zip(&b[i..]).map(|(x, y)| x * y).sum();
+//!     sum.reduce_sum() + tail
+//! }
+//!
+//! let a = [1.0, 2.0, 3.0, 4.0, 5.0];
+//! let b = [2.0, 2.0, 2.0, 2.0, 2.0];
+//! assert_eq!(dot(&a, &b), 30.0);
+//! ```
+
+#![unstable(feature = "portable_simd", issue = "0")]
+#![allow(non_camel_case_types)]
+
+use fmt;
+use mem;
+use ops::{Add, Sub, Mul, Div, Neg, Not, BitAnd, BitOr, BitXor};
+use ops::{AddAssign, SubAssign, MulAssign, DivAssign, BitAndAssign, BitOrAssign, BitXorAssign};
+use ptr;
+
+macro_rules! simd_binop {
+    ($name:ident, $n:expr, $Trait:ident $method:ident,
+     $TraitAssign:ident $method_assign:ident, |$a:ident, $b:ident| $lane:expr) => {
+        #[unstable(feature = "portable_simd", issue = "0")]
+        impl $Trait for $name {
+            type Output = $name;
+
+            #[inline]
+            fn $method(self, rhs: $name) -> $name {
+                let mut lanes = self.to_array();
+                let rhs = rhs.to_array();
+                for i in 0..$n {
+                    let ($a, $b) = (lanes[i], rhs[i]);
+                    lanes[i] = $lane;
+                }
+                $name::from_array(lanes)
+            }
+        }
+
+        #[unstable(feature = "portable_simd", issue = "0")]
+        impl $TraitAssign for $name {
+            #[inline]
+            fn $method_assign(&mut self, rhs: $name) {
+                *self = $Trait::$method(*self, rhs);
+            }
+        }
+    }
+}
+
+macro_rules! simd_mask {
+    ($(#[$attr:meta])* struct $name:ident($($field:ty),+): [$int:ty; $n:expr],
+     ($($lane:ident)+)) => {
+        $(#[$attr])*
+        ///
+        /// Each lane is either all ones (`true`) or all zeros (`false`).
+        #[unstable(feature = "portable_simd", issue = "0")]
+        // FIXME repr(simd) broken on emscripten and redox, and on big-endian targets. #42778
+        #[cfg_attr(not(any(target_os = "emscripten", target_os = "redox",
+                           target_endian = "big")),
+                   repr(simd))]
+        #[derive(Copy, Clone)]
+        pub struct $name($($field),+);
+
+        impl $name {
+            /// The number of lanes.
+            #[unstable(feature = "portable_simd", issue = "0")]
+            pub const LANES: usize = $n;
+
+            /// Creates a mask from the value of each lane.
+            #[unstable(feature = "portable_simd", issue = "0")]
+            #[inline]
+            pub fn new($($lane: bool),+) -> $name {
+                $name::from_array([$($lane),+])
+            }
+
+            /// Creates a mask with every lane set to `value`.
+            #[unstable(feature = "portable_simd", issue = "0")]
+            #[inline]
+            pub fn splat(value: bool) -> $name {
+                $name::from_array([value; $n])
+            }
+
+            /// Creates a mask from an array of lane values.
+            #[unstable(feature = "portable_simd", issue = "0")]
+            #[inline]
+            pub fn from_array(array: [bool; $n]) -> $name {
+                let mut lanes: [$int; $n] = [0; $n];
+                for i in 0..$n {
+                    lanes[i] = if array[i] { -1 } else { 0 };
+                }
+                unsafe { mem::transmute(lanes) }
+            }
+
+            /// Returns the value of each lane as an array.
+            #[unstable(feature = "portable_simd", issue = "0")]
+            #[inline]
+            pub fn to_array(self) -> [bool; $n] {
+                let lanes: [$int; $n] = unsafe { mem::transmute(self) };
+                let mut array = [false; $n];
+                for i in 0..$n {
+                    array[i] = lanes[i] != 0;
+                }
+                array
+            }
+
+            /// Returns the value of lane `index`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `index` is not less than `LANES`.
+            #[unstable(feature = "portable_simd", issue = "0")]
+            #[inline]
+            pub fn test(self, index: usize) -> bool {
+                self.to_array()[index]
+            }
+
+            /// Returns a copy of `self` with lane `index` set to `value`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `index` is not less than `LANES`.
+            #[unstable(feature = "portable_simd", issue = "0")]
+            #[inline]
+            pub fn replace(self, index: usize, value: bool) -> $name {
+                let mut array = self.to_array();
+                array[index] = value;
+                $name::from_array(array)
+            }
+
+            /// Returns `true` if every lane is set.
+            #[unstable(feature = "portable_simd", issue = "0")]
+            #[inline]
+            pub fn all(self) -> bool {
+                self.to_array().iter().all(|&lane| lane)
+            }
+
+            /// Returns `true` if any lane is set.
+            #[unstable(feature = "portable_simd", issue = "0")]
+            #[inline]
+            pub fn any(self) -> bool {
+                self.to_array().iter().any(|&lane| lane)
+            }
+
+            #[inline]
+            fn to_int_array(self) -> [$int; $n] {
+                unsafe { mem::transmute(self) }
+            }
+
+            #[inline]
+            fn from_int_array(lanes: [$int; $n]) -> $name {
+                unsafe { mem::transmute(lanes) }
+            }
+        }
+
+        #[unstable(feature = "portable_simd", issue = "0")]
+        impl Default for $name {
+            #[inline]
+            fn default() -> $name {
+                $name::splat(false)
+            }
+        }
+
+        #[unstable(feature = "portable_simd", issue = "0")]
+        impl PartialEq for $name {
+            #[inline]
+            fn eq(&self, other: &$name) -> bool {
+                self.to_array() == other.to_array()
+            }
+        }
+
+        #[unstable(feature = "portable_simd", issue = "0")]
+        impl Eq for $name {}
+
+        #[unstable(feature = "portable_simd", issue = "0")]
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let mut t = f.debug_tuple(stringify!($name));
+                for lane in self.to_array().iter() {
+                    t.field(lane);
+                }
+                t.finish()
+            }
+        }
+
+        #[unstable(feature = "portable_simd", issue = "0")]
+        impl Not for $name {
+            type Output = $name;
+
+            #[inline]
+            fn not(self) -> $name {
+                let mut lanes = self.to_int_array();
+                for i in 0..$n {
+                    lanes[i] = !lanes[i];
+                }
+                $name::from_int_array(lanes)
+            }
+        }
+
+        simd_mask_binop!($name, $n, BitAnd bitand, BitAndAssign bitand_assign, &);
+        simd_mask_binop!($name, $n, BitOr bitor, BitOrAssign bitor_assign, |);
+        simd_mask_binop!($name, $n, BitXor bitxor, BitXorAssign bitxor_assign, ^);
+    }
+}
+
+macro_rules! simd_mask_binop {
+    ($name:ident, $n:expr, $Trait:ident $method:ident,
+     $TraitAssign:ident $method_assign:ident, $op:tt) => {
+        #[unstable(feature = "portable_simd", issue = "0")]
+        impl $Trait for $name {
+            type Output = $name;
+
+            #[inline]
+            fn $method(self, rhs: $name) -> $name {
+                let mut lanes = self.to_int_array();
+                let rhs = rhs.to_int_array();
+                for i in 0..$n {
+                    lanes[i] = lanes[i] $op rhs[i];
+                }
+                $name::from_int_array(lanes)
+            }
+        }
+
+        #[unstable(feature = "portable_simd", issue = "0")]
+        impl $TraitAssign for $name {
+            #[inline]
+            fn $method_assign(&mut self, rhs: $name) {
+                *self = $Trait::$method(*self, rhs);
+            }
+        }
+    }
+}
+
+macro_rules! simd_vector {
+    ($(#[$attr:meta])* struct $name:ident($($field:ty),+): [$elem:ty; $n:expr],
+     $mask:ident, ($($lane:ident)+)) => {
+        $(#[$attr])*
+        #[unstable(feature = "portable_simd", issue = "0")]
+        // FIXME repr(simd) broken on emscripten and redox, and on big-endian targets. #42778
+        #[cfg_attr(not(any(target_os = "emscripten", target_os = "redox",
+                           target_endian = "big")),
+                   repr(simd))]
+        #[derive(Copy, Clone)]
+        pub struct $name($($field),+);
+
+        impl $name {
+            /// The number of lanes.
+            #[unstable(feature = "portable_simd", issue = "0")]
+            pub const LANES: usize = $n;
+
+            /// Creates a vector from the value of each lane.
+            #[unstable(feature = "portable_simd", issue = "0")]
+            #[inline]
+            pub fn new($($lane: $elem),+) -> $name {
+                $name($($lane),+)
+            }
+
+            /// Creates a vector with every lane set to `value`.
+            #[unstable(feature = "portable_simd", issue = "0")]
+            #[inline]
+            pub fn splat(value: $elem) -> $name {
+                $name::from_array([value; $n])
+            }
+
+            /// Creates a vector from an array of lane values.
+            #[unstable(feature = "portable_simd", issue = "0")]
+            #[inline]
+            pub fn from_array(array: [$elem; $n]) -> $name {
+                unsafe { mem::transmute(array) }
+            }
+
+            /// Returns the value of each lane as an array.
+            #[unstable(feature = "portable_simd", issue = "0")]
+            #[inline]
+            pub fn to_array(self) -> [$elem; $n] {
+                unsafe { mem::transmute(self) }
+            }
+
+            /// Loads a vector from the first `LANES` elements of `slice`.
+            ///
+            /// The slice doesn't need to be aligned.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `slice` has fewer than `LANES` elements.
+            #[unstable(feature = "portable_simd", issue = "0")]
+            #[inline]
+            pub fn from_slice(slice: &[$elem]) -> $name {
+                assert!(slice.len() >= $n, "slice is too short to load a vector from");
+                unsafe { ptr::read_unaligned(slice.as_ptr() as *const $name) }
+            }
+
+            /// Stores the vector into the first `LANES` elements of `slice`.
+            ///
+            /// The slice doesn't need to be aligned.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `slice` has fewer than `LANES` elements.
+            #[unstable(feature = "portable_simd", issue = "0")]
+            #[inline]
+            pub fn write_to_slice(self, slice: &mut [$elem]) {
+                assert!(slice.len() >= $n, "slice is too short to store a vector to");
+                unsafe { ptr::write_unaligned(slice.as_mut_ptr() as *mut $name, self) }
+            }
+
+            /// Returns the value of lane `index`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `index` is not less than `LANES`.
+            #[unstable(feature = "portable_simd", issue = "0")]
+            #[inline]
+            pub fn extract(self, index: usize) -> $elem {
+                self.to_array()[index]
+            }
+
+            /// Returns a copy of `self` with lane `index` set to `value`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `index` is not less than `LANES`.
+            #[unstable(feature = "portable_simd", issue = "0")]
+            #[inline]
+            pub fn replace(self, index: usize, value: $elem) -> $name {
+                let mut array = self.to_array();
+                array[index] = value;
+                $name::from_array(array)
+            }
+
+            /// Rearranges the lanes: lane `i` of the result is lane
+            /// `indices[i]` of `self`.
+            ///
+            /// When `indices` is a constant, this becomes a single shuffle
+            /// instruction.
+            ///
+            /// # Panics
+            ///
+            /// Panics if any index is not less than `LANES`.
+            #[unstable(feature = "portable_simd", issue = "0")]
+            #[inline]
+            pub fn swizzle(self, indices: [usize; $n]) -> $name {
+                let array = self.to_array();
+                let mut lanes = array;
+                for i in 0..$n {
+                    lanes[i] = array[indices[i]];
+                }
+                $name::from_array(lanes)
+            }
+
+            /// Reverses the order of the lanes.
+            #[unstable(feature = "portable_simd", issue = "0")]
+            #[inline]
+            pub fn reverse(self) -> $name {
+                let mut array = self.to_array();
+                array.reverse();
+                $name::from_array(array)
+            }
+
+            /// Picks each lane from `if_true` where `mask` is set, and from
+            /// `if_false` where it isn't.
+            #[unstable(feature = "portable_simd", issue = "0")]
+            #[inline]
+            pub fn select(mask: $mask, if_true: $name, if_false: $name) -> $name {
+                let mask = mask.to_array();
+                let if_true = if_true.to_array();
+                let mut lanes = if_false.to_array();
+                for i in 0..$n {
+                    if mask[i] {
+                        lanes[i] = if_true[i];
+                    }
+                }
+                $name::from_array(lanes)
+            }
+
+            /// Tests each pair of lanes for equality.
+            #[unstable(feature = "portable_simd", issue = "0")]
+            #[inline]
+            pub fn lanes_eq(self, other: $name) -> $mask {
+                self.compare(other, |a, b| a == b)
+            }
+
+            /// Tests each pair of lanes for inequality.
+            #[unstable(feature = "portable_simd", issue = "0")]
+            #[inline]
+            pub fn lanes_ne(self, other: $name) -> $mask {
+                self.compare(other, |a, b| a != b)
+            }
+
+            /// Tests whether each lane of `self` is less than the lane of
+            /// `other`.
+            #[unstable(feature = "portable_simd", issue = "0")]
+            #[inline]
+            pub fn lanes_lt(self, other: $name) -> $mask {
+                self.compare(other, |a, b| a < b)
+            }
+
+            /// Tests whether each lane of `self` is less than or equal to the
+            /// lane of `other`.
+            #[unstable(feature = "portable_simd", issue = "0")]
+            #[inline]
+            pub fn lanes_le(self, other: $name) -> $mask {
+                self.compare(other, |a, b| a <= b)
+            }
+
+            /// Tests whether each lane of `self` is greater than the lane of
+            /// `other`.
+            #[unstable(feature = "portable_simd", issue = "0")]
+            #[inline]
+            pub fn lanes_gt(self, other: $name) -> $mask {
+                self.compare(other, |a, b| a > b)
+            }
+
+            /// Tests whether each lane of `self` is greater than or equal to
+            /// the lane of `other`.
+            #[unstable(feature = "portable_simd", issue = "0")]
+            #[inline]
+            pub fn lanes_ge(self, other: $name) -> $mask {
+                self.compare(other, |a, b| a >= b)
+            }
+
+            #[inline]
+            fn compare<F: Fn($elem, $elem) -> bool>(self, other: $name, f: F) -> $mask {
+                let a = self.to_array();
+                let b = other.to_array();
+                let mut mask = [false; $n];
+                for i in 0..$n {
+                    mask[i] = f(a[i], b[i]);
+                }
+                $mask::from_array(mask)
+            }
+        }
+
+        #[unstable(feature = "portable_simd", issue = "0")]
+        impl Default for $name {
+            #[inline]
+            fn default() -> $name {
+                $name::splat(Default::default())
+            }
+        }
+
+        #[unstable(feature = "portable_simd", issue = "0")]
+        impl PartialEq for $name {
+            #[inline]
+            fn eq(&self, other: &$name) -> bool {
+                self.to_array() == other.to_array()
+            }
+        }
+
+        #[unstable(feature = "portable_simd", issue = "0")]
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let mut t = f.debug_tuple(stringify!($name));
+                for lane in self.to_array().iter() {
+                    t.field(lane);
+                }
+                t.finish()
+            }
+        }
+    }
+}
+
+macro_rules! simd_int_ops {
+    ($($name:ident: $elem:ty, $n:expr;)+) => {$(
+        impl $name {
+            /// Returns the wrapping sum of all lanes.
+            #[unstable(feature = "portable_simd", issue = "0")]
+            #[inline]
+            pub fn reduce_sum(self) -> $elem {
+                self.to_array().iter().fold(0, |sum, &x| sum.wrapping_add(x))
+            }
+        }
+
+        #[unstable(feature = "portable_simd", issue = "0")]
+        impl Eq for $name {}
+
+        #[unstable(feature = "portable_simd", issue = "0")]
+        impl Not for $name {
+            type Output = $name;
+
+            #[inline]
+            fn not(self) -> $name {
+                let mut lanes = self.to_array();
+                for i in 0..$n {
+                    lanes[i] = !lanes[i];
+                }
+                $name::from_array(lanes)
+            }
+        }
+
+        simd_binop!($name, $n, Add add, AddAssign add_assign, |a, b| a.wrapping_add(b));
+        simd_binop!($name, $n, Sub sub, SubAssign sub_assign, |a, b| a.wrapping_sub(b));
+        simd_binop!($name, $n, Mul mul, MulAssign mul_assign, |a, b| a.wrapping_mul(b));
+        simd_binop!($name, $n, Div div, DivAssign div_assign, |a, b| a.wrapping_div(b));
+        simd_binop!($name, $n, BitAnd bitand, BitAndAssign bitand_assign, |a, b| a & b);
+        simd_binop!($name, $n, BitOr bitor, BitOrAssign bitor_assign, |a, b| a | b);
+        simd_binop!($name, $n, BitXor bitxor, BitXorAssign bitxor_assign, |a, b| a ^ b);
+    )+}
+}
+
+macro_rules! simd_neg {
+    ($($name:ident: $n:expr, |$a:ident| $lane:expr;)+) => {$(
+        #[unstable(feature = "portable_simd", issue = "0")]
+        impl Neg for $name {
+            type Output = $name;
+
+            #[inline]
+            fn neg(self) -> $name {
+                let mut lanes = self.to_array();
+                for i in 0..$n {
+                    let $a = lanes[i];
+                    lanes[i] = $lane;
+                }
+                $name::from_array(lanes)
+            }
+        }
+    )+}
+}
+
+macro_rules! simd_float_ops {
+    ($($name:ident: $elem:ty, $n:expr;)+) => {$(
+        impl $name {
+            /// Returns the sum of all lanes, added in lane order.
+            #[unstable(feature = "portable_simd", issue = "0")]
+            #[inline]
+            pub fn reduce_sum(self) -> $elem {
+                self.to_array().iter().fold(0.0, |sum, &x| sum + x)
+            }
+        }
+
+        simd_binop!($name, $n, Add add, AddAssign add_assign, |a, b| a + b);
+        simd_binop!($name, $n, Sub sub, SubAssign sub_assign, |a, b| a - b);
+        simd_binop!($name, $n, Mul mul, MulAssign mul_assign, |a, b| a * b);
+        simd_binop!($name, $n, Div div, DivAssign div_assign, |a, b| a / b);
+        simd_neg!($name: $n, |a| -a;);
+    )+}
+}
+
+simd_mask! {
+    /// A 128-bit mask of sixteen 8-bit lanes.
+    struct m8x16(i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8): [i8; 16],
+    (x0 x1 x2 x3 x4 x5 x6 x7 x8 x9 x10 x11 x12 x13 x14 x15)
+}
+simd_mask! {
+    /// A 128-bit mask of eight 16-bit lanes.
+    struct m16x8(i16, i16, i16, i16, i16, i16, i16, i16): [i16; 8],
+    (x0 x1 x2 x3 x4 x5 x6 x7)
+}
+simd_mask! {
+    /// A 128-bit mask of four 32-bit lanes.
+    struct m32x4(i32, i32, i32, i32): [i32; 4], (x0 x1 x2 x3)
+}
+simd_mask! {
+    /// A 128-bit mask of two 64-bit lanes.
+    struct m64x2(i64, i64): [i64; 2], (x0 x1)
+}
+
+simd_vector! {
+    /// A 128-bit vector of sixteen `i8` lanes.
+    struct i8x16(i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8): [i8; 16],
+    m8x16, (x0 x1 x2 x3 x4 x5 x6 x7 x8 x9 x10 x11 x12 x13 x14 x15)
+}
+simd_vector! {
+    /// A 128-bit vector of sixteen `u8` lanes.
+    struct u8x16(u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8): [u8; 16],
+    m8x16, (x0 x1 x2 x3 x4 x5 x6 x7 x8 x9 x10 x11 x12 x13 x14 x15)
+}
+simd_vector! {
+    /// A 128-bit vector of eight `i16` lanes.
+    struct i16x8(i16, i16, i16, i16, i16, i16, i16, i16): [i16; 8],
+    m16x8, (x0 x1 x2 x3 x4 x5 x6 x7)
+}
+simd_vector! {
+    /// A 128-bit vector of eight `u16` lanes.
+    struct u16x8(u16, u16, u16, u16, u16, u16, u16, u16): [u16; 8],
+    m16x8, (x0 x1 x2 x3 x4 x5 x6 x7)
+}
+simd_vector! {
+    /// A 128-bit vector of four `i32` lanes.
+    struct i32x4(i32, i32, i32, i32): [i32; 4], m32x4, (x0 x1 x2 x3)
+}
+simd_vector! {
+    /// A 128-bit vector of four `u32` lanes.
+    struct u32x4(u32, u32, u32, u32): [u32; 4], m32x4, (x0 x1 x2 x3)
+}
+simd_vector! {
+    /// A 128-bit vector of four `f32` lanes.
+    struct f32x4(f32, f32, f32, f32): [f32; 4], m32x4, (x0 x1 x2 x3)
+}
+simd_vector! {
+    /// A 128-bit vector of two `i64` lanes.
+    struct i64x2(i64, i64): [i64; 2], m64x2, (x0 x1)
+}
+simd_vector! {
+    /// A 128-bit vector of two `u64` lanes.
+    struct u64x2(u64, u64): [u64; 2], m64x2, (x0 x1)
+}
+simd_vector! {
+    /// A 128-bit vector of two `f64` lanes.
+    struct f64x2(f64, f64): [f64; 2], m64x2, (x0 x1)
+}
+
+simd_int_ops! {
+    i8x16: i8, 16;
+    u8x16: u8, 16;
+    i16x8: i16, 8;
+    u16x8: u16, 8;
+    i32x4: i32, 4;
+    u32x4: u32, 4;
+    i64x2: i64, 2;
+    u64x2: u64, 2;
+}
+
+simd_neg! {
+    i8x16: 16, |a| a.wrapping_neg();
+    i16x8: 8, |a| a.wrapping_neg();
+    i32x4: 4, |a| a.wrapping_neg();
+    i64x2: 2, |a| a.wrapping_neg();
+}
+
+simd_float_ops! {
+    f32x4: f32, 4;
+    f64x2: f64, 2;
+}
@@ -35,6 +35,7 @@
 // * The `raw` and `bytes` submodules.
 // * Boilerplate trait implementations.
 
+use array::FixedSizeArray;
 use borrow::Borrow;
 use cmp::Ordering::{self, Less, Equal, Greater};
 use cmp;
@@ -53,6 +54,12 @@ use iter_private::TrustedRandomAccess;
 
 mod rotate;
 mod sort;
+mod specialize;
+
+#[unstable(feature = "slice_internals", issue = "0",
+           reason = "exposed from core to be reused in std; use the memchr crate")]
+/// Pure rust memchr implementation, taken from rust-memchr
+pub mod memchr;
 
 #[repr(C)]
 struct Repr<T> {
@@ -75,6 +82,9 @@ pub trait SliceExt {
     #[stable(feature = "core", since = "1.6.0")]
     fn split_at(&self, mid: usize) -> (&[Self::Item], &[Self::Item]);
 
+    #[unstable(feature = "slice_split_at_unchecked", issue = "0")]
+    unsafe fn split_at_unchecked(&self, mid: usize) -> (&[Self::Item], &[Self::Item]);
+
     #[stable(feature = "core", since = "1.6.0")]
     fn iter(&self) -> Iter<Self::Item>;
 
@@ -82,6 +92,10 @@ pub trait SliceExt {
     fn split<P>(&self, pred: P) -> Split<Self::Item, P>
         where P: FnMut(&Self::Item) -> bool;
 
+    #[unstable(feature = "split_inclusive", issue = "0")]
+    fn split_inclusive<P>(&self, pred: P) -> SplitInclusive<Self::Item, P>
+        where P: FnMut(&Self::Item) -> bool;
+
     #[unstable(feature = "slice_rsplit", issue = "41020")]
     fn rsplit<P>(&self, pred: P) -> RSplit<Self::Item, P>
         where P: FnMut(&Self::Item) -> bool;
@@ -94,12 +108,46 @@ pub trait SliceExt {
     fn rsplitn<P>(&self,  n: usize, pred: P) -> RSplitN<Self::Item, P>
         where P: FnMut(&Self::Item) -> bool;
 
+    #[unstable(feature = "slice_split_once", issue = "0")]
+    fn split_once<P>(&self, pred: P) -> Option<(&[Self::Item], &[Self::Item])>
+        where P: FnMut(&Self::Item) -> bool;
+
+    #[unstable(feature = "slice_split_once", issue = "0")]
+    fn rsplit_once<P>(&self, pred: P) -> Option<(&[Self::Item], &[Self::Item])>
+        where P: FnMut(&Self::Item) -> bool;
+
     #[stable(feature = "core", since = "1.6.0")]
     fn windows(&self, size: usize) -> Windows<Self::Item>;
 
     #[stable(feature = "core", since = "1.6.0")]
     fn chunks(&self, size: usize) -> Chunks<Self::Item>;
 
+    #[unstable(feature = "chunks_exact", issue = "0")]
+    fn chunks_exact(&self, chunk_size: usize) -> ChunksExact<Self::Item>;
+
+    #[unstable(feature = "chunks_exact", issue = "0")]
+    fn rchunks_exact(&self, chunk_size: usize) -> RChunksExact<Self::Item>;
+
+    #[unstable(feature = "slice_as_chunks", issue = "0")]
+    fn as_chunks<A>(&self) -> (&[A], &[Self::Item])
+        where A: FixedSizeArray<Self::Item>;
+
+    #[unstable(feature = "slice_as_chunks", issue = "0")]
+    fn as_rchunks<A>(&self) -> (&[Self::Item], &[A])
+        where A: FixedSizeArray<Self::Item>;
+
+    #[unstable(feature = "array_chunks", issue = "0")]
+    fn array_chunks<A>(&self) -> ArrayChunks<Self::Item, A>
+        where A: FixedSizeArray<Self::Item>;
+
+    #[unstable(feature = "array_windows", issue = "0")]
+    fn array_windows<A>(&self) -> ArrayWindows<Self::Item, A>
+        where A: FixedSizeArray<Self::Item>;
+
+    #[unstable(feature = "slice_group_by", issue = "0")]
+    fn chunk_by<F>(&self, pred: F) -> ChunkBy<Self::Item, F>
+        where F: FnMut(&Self::Item, &Self::Item) -> bool;
+
     #[stable(feature = "core", since = "1.6.0")]
     fn get<I>(&self, index: I) -> Option<&I::Output>
         where I: SliceIndex<Self>;
@@ -121,6 +169,9 @@ pub trait SliceExt {
     #[stable(feature = "core", since = "1.6.0")]
     fn as_ptr(&self) -> *const Self::Item;
 
+    #[unstable(feature = "slice_ptr_range", issue = "0")]
+    fn as_ptr_range(&self) -> ops::Range<*const Self::Item>;
+
     #[stable(feature = "core", since = "1.6.0")]
     fn binary_search<Q: ?Sized>(&self, x: &Q) -> Result<usize, usize>
         where Self::Item: Borrow<Q>,
@@ -136,6 +187,23 @@ pub trait SliceExt {
               B: Borrow<Q>,
               Q: Ord;
 
+    #[unstable(feature = "partition_point", issue = "0")]
+    fn partition_point<P>(&self, pred: P) -> usize
+        where P: FnMut(&Self::Item) -> bool;
+
+    #[unstable(feature = "is_sorted", issue = "0")]
+    fn is_sorted(&self) -> bool
+        where Self::Item: PartialOrd;
+
+    #[unstable(feature = "is_sorted", issue = "0")]
+    fn is_sorted_by<F>(&self, compare: F) -> bool
+        where F: FnMut(&Self::Item, &Self::Item) -> bool;
+
+    #[unstable(feature = "is_sorted", issue = "0")]
+    fn is_sorted_by_key<F, K>(&self, f: F) -> bool
+        where F: FnMut(&Self::Item) -> K,
+              K: PartialOrd;
+
     #[stable(feature = "core", since = "1.6.0")]
     fn len(&self) -> usize;
 
@@ -164,6 +232,10 @@ pub trait SliceExt {
     fn split_mut<P>(&mut self, pred: P) -> SplitMut<Self::Item, P>
         where P: FnMut(&Self::Item) -> bool;
 
+    #[unstable(feature = "split_inclusive", issue = "0")]
+    fn split_inclusive_mut<P>(&mut self, pred: P) -> SplitInclusiveMut<Self::Item, P>
+        where P: FnMut(&Self::Item) -> bool;
+
     #[unstable(feature = "slice_rsplit", issue = "41020")]
     fn rsplit_mut<P>(&mut self, pred: P) -> RSplitMut<Self::Item, P>
         where P: FnMut(&Self::Item) -> bool;
@@ -179,12 +251,41 @@ pub trait SliceExt {
     #[stable(feature = "core", since = "1.6.0")]
     fn chunks_mut(&mut self, chunk_size: usize) -> ChunksMut<Self::Item>;
 
+    #[unstable(feature = "chunks_exact", issue = "0")]
+    fn chunks_exact_mut(&mut self, chunk_size: usize) -> ChunksExactMut<Self::Item>;
+
+    #[unstable(feature = "chunks_exact", issue = "0")]
+    fn rchunks_exact_mut(&mut self, chunk_size: usize) -> RChunksExactMut<Self::Item>;
+
+    #[unstable(feature = "slice_as_chunks", issue = "0")]
+    fn as_chunks_mut<A>(&mut self) -> (&mut [A], &mut [Self::Item])
+        where A: FixedSizeArray<Self::Item>;
+
+    #[unstable(feature = "slice_as_chunks", issue = "0")]
+    fn as_rchunks_mut<A>(&mut self) -> (&mut [Self::Item], &mut [A])
+        where A: FixedSizeArray<Self::Item>;
+
+    #[unstable(feature = "array_chunks", issue = "0")]
+    fn array_chunks_mut<A>(&mut self) -> ArrayChunksMut<Self::Item, A>
+        where A: FixedSizeArray<Self::Item>;
+
+    #[unstable(feature = "slice_group_by", issue = "0")]
+    fn chunk_by_mut<F>(&mut self, pred: F) -> ChunkByMut<Self::Item, F>
+        where F: FnMut(&Self::Item, &Self::Item) -> bool;
+
     #[stable(feature = "core", since = "1.6.0")]
     fn swap(&mut self, a: usize, b: usize);
 
+    #[unstable(feature = "slice_swap_unchecked", issue = "0")]
+    unsafe fn swap_unchecked(&mut self, a: usize, b: usize);
+
     #[stable(feature = "core", since = "1.6.0")]
     fn split_at_mut(&mut self, mid: usize) -> (&mut [Self::Item], &mut [Self::Item]);
 
+    #[unstable(feature = "slice_split_at_unchecked", issue = "0")]
+    unsafe fn split_at_mut_unchecked(&mut self, mid: usize)
+        -> (&mut [Self::Item], &mut [Self::Item]);
+
     #[stable(feature = "core", since = "1.6.0")]
     fn reverse(&mut self);
 
@@ -194,6 +295,9 @@ pub trait SliceExt {
     #[stable(feature = "core", since = "1.6.0")]
     fn as_mut_ptr(&mut self) -> *mut Self::Item;
 
+    #[unstable(feature = "slice_ptr_range", issue = "0")]
+    fn as_mut_ptr_range(&mut self) -> ops::Range<*mut Self::Item>;
+
     #[stable(feature = "core", since = "1.6.0")]
     fn contains(&self, x: &Self::Item) -> bool where Self::Item: PartialEq;
 
@@ -203,6 +307,14 @@ pub trait SliceExt {
     #[stable(feature = "core", since = "1.6.0")]
     fn ends_with(&self, needle: &[Self::Item]) -> bool where Self::Item: PartialEq;
 
+    #[unstable(feature = "slice_strip", issue = "0")]
+    fn strip_prefix(&self, prefix: &[Self::Item]) -> Option<&[Self::Item]>
+        where Self::Item: PartialEq;
+
+    #[unstable(feature = "slice_strip", issue = "0")]
+    fn strip_suffix(&self, suffix: &[Self::Item]) -> Option<&[Self::Item]>
+        where Self::Item: PartialEq;
+
     #[unstable(feature = "slice_rotate", issue = "41891")]
     fn rotate(&mut self, mid: usize);
 
@@ -212,6 +324,28 @@ pub trait SliceExt {
     #[stable(feature = "copy_from_slice", since = "1.9.0")]
     fn copy_from_slice(&mut self, src: &[Self::Item]) where Self::Item: Copy;
 
+    #[unstable(feature = "slice_fill", issue = "0")]
+    fn fill(&mut self, value: Self::Item) where Self::Item: Clone;
+
+    #[unstable(feature = "slice_fill_with", issue = "0")]
+    fn fill_with<F>(&mut self, f: F) where F: FnMut() -> Self::Item;
+
+    #[unstable(feature = "copy_within", issue = "0")]
+    fn copy_within(&mut self, src_start: usize, src_end: usize, dest: usize)
+        where Self::Item: Copy;
+
+    #[unstable(feature = "slice_take", issue = "0")]
+    fn split_off_first<'a>(slice: &mut &'a Self) -> Option<&'a Self::Item>;
+
+    #[unstable(feature = "slice_take", issue = "0")]
+    fn split_off_first_mut<'a>(slice: &mut &'a mut Self) -> Option<&'a mut Self::Item>;
+
+    #[unstable(feature = "slice_take", issue = "0")]
+    fn split_off_last<'a>(slice: &mut &'a Self) -> Option<&'a Self::Item>;
+
+    #[unstable(feature = "slice_take", issue = "0")]
+    fn split_off_last_mut<'a>(slice: &mut &'a mut Self) -> Option<&'a mut Self::Item>;
+
     #[stable(feature = "sort_unstable", since = "1.20.0")]
     fn sort_unstable(&mut self)
         where Self::Item: Ord;
@@ -224,6 +358,56 @@ pub trait SliceExt {
     fn sort_unstable_by_key<B, F>(&mut self, f: F)
         where F: FnMut(&Self::Item) -> B,
               B: Ord;
+
+    #[unstable(feature = "slice_select_nth_unstable", issue = "0")]
+    fn select_nth_unstable(&mut self, index: usize)
+        -> (&mut [Self::Item], &mut Self::Item, &mut [Self::Item])
+        where Self::Item: Ord;
+
+    #[unstable(feature = "slice_select_nth_unstable", issue = "0")]
+    fn select_nth_unstable_by<F>(&mut self, index: usize, compare: F)
+        -> (&mut [Self::Item], &mut Self::Item, &mut [Self::Item])
+        where F: FnMut(&Self::Item, &Self::Item) -> Ordering;
+
+    #[unstable(feature = "slice_select_nth_unstable", issue = "0")]
+    fn select_nth_unstable_by_key<B, F>(&mut self, index: usize, f: F)
+        -> (&mut [Self::Item], &mut Self::Item, &mut [Self::Item])
+        where F: FnMut(&Self::Item) -> B,
+              B: Ord;
+
+    #[unstable(feature = "slice_align_to", issue = "0")]
+    unsafe fn align_to<U>(&self) -> (&[Self::Item], &[U], &[Self::Item]);
+
+    #[unstable(feature = "slice_align_to", issue = "0")]
+    unsafe fn align_to_mut<U>(&mut self) -> (&mut [Self::Item], &mut [U], &mut [Self::Item]);
+
+    #[unstable(feature = "slice_flatten", issue = "0")]
+    fn as_flattened<U>(&self) -> &[U]
+        where Self::Item: FixedSizeArray<U>;
+
+    #[unstable(feature = "slice_flatten", issue = "0")]
+    fn as_flattened_mut<U>(&mut self) -> &mut [U]
+        where Self::Item: FixedSizeArray<U>;
+
+    #[unstable(feature = "slice_first_last_chunk", issue = "0")]
+    fn split_first_chunk<A>(&self) -> Option<(&A, &[Self::Item])>
+        where A: FixedSizeArray<Self::Item>;
+
+    #[unstable(feature = "slice_first_last_chunk", issue = "0")]
+    fn split_first_chunk_mut<A>(&mut self) -> Option<(&mut A, &mut [Self::Item])>
+        where A: FixedSizeArray<Self::Item>;
+
+    #[unstable(feature = "slice_first_last_chunk", issue = "0")]
+    fn split_last_chunk<A>(&self) -> Option<(&[Self::Item], &A)>
+        where A: FixedSizeArray<Self::Item>;
+
+    #[unstable(feature = "slice_first_last_chunk", issue = "0")]
+    fn split_last_chunk_mut<A>(&mut self) -> Option<(&mut [Self::Item], &mut A)>
+        where A: FixedSizeArray<Self::Item>;
+
+    #[unstable(feature = "get_many_mut", issue = "0")]
+    fn get_many_mut<'a, I>(&'a mut self, indices: I) -> Option<I::Output>
+        where I: GetManyMutIndices<'a, Self::Item>;
 }
 
 // Use macros to be generic over const/mut
@@ -275,6 +459,15 @@ impl<T> SliceExt for [T] {
         (&self[..mid], &self[mid..])
     }
 
+    #[inline]
+    unsafe fn split_at_unchecked(&self, mid: usize) -> (&[T], &[T]) {
+        let len = self.len();
+        let ptr = self.as_ptr();
+        debug_assert!(mid <= len, "split_at_unchecked requires that mid <= len");
+
+        (from_raw_parts(ptr, mid), from_raw_parts(ptr.offset(mid as isize), len - mid))
+    }
+
     #[inline]
     fn iter(&self) -> Iter<T> {
         unsafe {
@@ -305,6 +498,14 @@ impl<T> SliceExt for [T] {
         }
     }
 
+    #[inline]
+    fn split_inclusive<P>(&self, pred: P) -> SplitInclusive<T, P>
+        where P: FnMut(&T) -> bool
+    {
+        let finished = self.is_empty();
+        SplitInclusive { v: self, pred: pred, finished: finished }
+    }
+
     #[inline]
     fn rsplit<P>(&self, pred: P) -> RSplit<T, P>
         where P: FnMut(&T) -> bool
@@ -336,6 +537,26 @@ impl<T> SliceExt for [T] {
         }
     }
 
+    #[inline]
+    fn split_once<P>(&self, pred: P) -> Option<(&[T], &[T])>
+        where P: FnMut(&T) -> bool
+    {
+        match self.iter().position(pred) {
+            Some(idx) => Some((&self[..idx], &self[idx + 1..])),
+            None => None,
+        }
+    }
+
+    #[inline]
+    fn rsplit_once<P>(&self, pred: P) -> Option<(&[T], &[T])>
+        where P: FnMut(&T) -> bool
+    {
+        match self.iter().rposition(pred) {
+            Some(idx) => Some((&self[..idx], &self[idx + 1..])),
+            None => None,
+        }
+    }
+
     #[inline]
     fn windows(&self, size: usize) -> Windows<T> {
         assert!(size != 0);
@@ -348,6 +569,77 @@ impl<T> SliceExt for [T] {
         Chunks { v: self, size: size }
     }
 
+    #[inline]
+    fn chunks_exact(&self, chunk_size: usize) -> ChunksExact<T> {
+        assert!(chunk_size != 0);
+        let rem = self.len() % chunk_size;
+        let len = self.len() - rem;
+        let (fst, snd) = self.split_at(len);
+        ChunksExact { v: fst, rem: snd, chunk_size: chunk_size }
+    }
+
+    #[inline]
+    fn rchunks_exact(&self, chunk_size: usize) -> RChunksExact<T> {
+        assert!(chunk_size != 0);
+        let rem = self.len() % chunk_size;
+        let (fst, snd) = self.split_at(rem);
+        RChunksExact { v: snd, rem: fst, chunk_size: chunk_size }
+    }
+
+    #[inline]
+    fn as_chunks<A>(&self) -> (&[A], &[T])
+        where A: FixedSizeArray<T>
+    {
+        let n = array_len::<T, A>();
+        assert!(n != 0);
+        let len = self.len() / n;
+        let (multiple_of_n, remainder) = self.split_at(len * n);
+        // `FixedSizeArray` guarantees that `A` is laid out as `[T; n]`, and
+        // `multiple_of_n` holds exactly `len * n` elements.
+        let array_slice = unsafe {
+            from_raw_parts(multiple_of_n.as_ptr() as *const A, len)
+        };
+        (array_slice, remainder)
+    }
+
+    #[inline]
+    fn as_rchunks<A>(&self) -> (&[T], &[A])
+        where A: FixedSizeArray<T>
+    {
+        let n = array_len::<T, A>();
+        assert!(n != 0);
+        let len = self.len() / n;
+        let (remainder, multiple_of_n) = self.split_at(self.len() - len * n);
+        let array_slice = unsafe {
+            from_raw_parts(multiple_of_n.as_ptr() as *const A, len)
+        };
+        (remainder, array_slice)
+    }
+
+    #[inline]
+    fn array_chunks<A>(&self) -> ArrayChunks<T, A>
+        where A: FixedSizeArray<T>
+    {
+        let (array_slice, rem) = self.as_chunks();
+        ArrayChunks { iter: array_slice.iter(), rem: rem }
+    }
+
+    #[inline]
+    fn array_windows<A>(&self) -> ArrayWindows<T, A>
+        where A: FixedSizeArray<T>
+    {
+        let size = array_len::<T, A>();
+        assert!(size != 0);
+        ArrayWindows { v: self, size: size, _marker: marker::PhantomData }
+    }
+
+    #[inline]
+    fn chunk_by<F>(&self, pred: F) -> ChunkBy<T, F>
+        where F: FnMut(&T, &T) -> bool
+    {
+        ChunkBy { v: self, pred: pred }
+    }
+
     #[inline]
     fn get<I>(&self, index: I) -> Option<&I::Output>
         where I: SliceIndex<[T]>
@@ -388,6 +680,15 @@ impl<T> SliceExt for [T] {
         self as *const [T] as *const T
     }
 
+    #[inline]
+    fn as_ptr_range(&self) -> ops::Range<*const T> {
+        let start = self.as_ptr();
+        // A slice never spans more than `isize::MAX` bytes, and the end
+        // pointer is one past its last element, so this stays in bounds.
+        let end = unsafe { start.offset(self.len() as isize) };
+        start..end
+    }
+
     fn binary_search_by<'a, F>(&'a self, mut f: F) -> Result<usize, usize>
         where F: FnMut(&'a T) -> Ordering
     {
@@ -437,6 +738,16 @@ impl<T> SliceExt for [T] {
         }
     }
 
+    #[inline]
+    unsafe fn split_at_mut_unchecked(&mut self, mid: usize) -> (&mut [T], &mut [T]) {
+        let len = self.len();
+        let ptr = self.as_mut_ptr();
+        debug_assert!(mid <= len, "split_at_mut_unchecked requires that mid <= len");
+
+        (from_raw_parts_mut(ptr, mid),
+         from_raw_parts_mut(ptr.offset(mid as isize), len - mid))
+    }
+
     #[inline]
     fn iter_mut(&mut self) -> IterMut<T> {
         unsafe {
@@ -492,6 +803,14 @@ impl<T> SliceExt for [T] {
         SplitMut { v: self, pred: pred, finished: false }
     }
 
+    #[inline]
+    fn split_inclusive_mut<P>(&mut self, pred: P) -> SplitInclusiveMut<T, P>
+        where P: FnMut(&T) -> bool
+    {
+        let finished = self.is_empty();
+        SplitInclusiveMut { v: self, pred: pred, finished: finished }
+    }
+
     #[inline]
     fn rsplit_mut<P>(&mut self, pred: P) -> RSplitMut<T, P>
         where P: FnMut(&T) -> bool
@@ -529,6 +848,67 @@ impl<T> SliceExt for [T] {
         ChunksMut { v: self, chunk_size: chunk_size }
     }
 
+    #[inline]
+    fn chunks_exact_mut(&mut self, chunk_size: usize) -> ChunksExactMut<T> {
+        assert!(chunk_size != 0);
+        let rem = self.len() % chunk_size;
+        let len = self.len() - rem;
+        let (fst, snd) = self.split_at_mut(len);
+        ChunksExactMut { v: fst, rem: snd, chunk_size: chunk_size }
+    }
+
+    #[inline]
+    fn rchunks_exact_mut(&mut self, chunk_size: usize) -> RChunksExactMut<T> {
+        assert!(chunk_size != 0);
+        let rem = self.len() % chunk_size;
+        let (fst, snd) = self.split_at_mut(rem);
+        RChunksExactMut { v: snd, rem: fst, chunk_size: chunk_size }
+    }
+
+    #[inline]
+    fn as_chunks_mut<A>(&mut self) -> (&mut [A], &mut [T])
+        where A: FixedSizeArray<T>
+    {
+        let n = array_len::<T, A>();
+        assert!(n != 0);
+        let len = self.len() / n;
+        let (multiple_of_n, remainder) = self.split_at_mut(len * n);
+        let array_slice = unsafe {
+            from_raw_parts_mut(multiple_of_n.as_mut_ptr() as *mut A, len)
+        };
+        (array_slice, remainder)
+    }
+
+    #[inline]
+    fn as_rchunks_mut<A>(&mut self) -> (&mut [T], &mut [A])
+        where A: FixedSizeArray<T>
+    {
+        let n = array_len::<T, A>();
+        assert!(n != 0);
+        let len = self.len() / n;
+        let rem_len = self.len() - len * n;
+        let (remainder, multiple_of_n) = self.split_at_mut(rem_len);
+        let array_slice = unsafe {
+            from_raw_parts_mut(multiple_of_n.as_mut_ptr() as *mut A, len)
+        };
+        (remainder, array_slice)
+    }
+
+    #[inline]
+    fn array_chunks_mut<A>(&mut self) -> ArrayChunksMut<T, A>
+        where A: FixedSizeArray<T>
+    {
+        let (array_slice, rem) = self.as_chunks_mut();
+        ArrayChunksMut { iter: array_slice.iter_mut(), rem: rem }
+    }
+
+    #[inline]
+    fn chunk_by_mut<F>(&mut self, pred: F) -> ChunkByMut<T, F>
+        where F: FnMut(&T, &T) -> bool
+    {
+        ChunkByMut { v: self, pred: pred }
+    }
+
     #[inline]
     fn swap(&mut self, a: usize, b: usize) {
         unsafe {
@@ -540,6 +920,14 @@ impl<T> SliceExt for [T] {
         }
     }
 
+    #[inline]
+    unsafe fn swap_unchecked(&mut self, a: usize, b: usize) {
+        debug_assert!(a < self.len() && b < self.len(),
+                      "swap_unchecked requires that the indices are within the slice");
+        let ptr = self.as_mut_ptr();
+        ptr::swap(ptr.offset(a as isize), ptr.offset(b as isize));
+    }
+
     fn reverse(&mut self) {
         let mut i: usize = 0;
         let ln = self.len();
@@ -615,9 +1003,16 @@ impl<T> SliceExt for [T] {
         self as *mut [T] as *mut T
     }
 
+    #[inline]
+    fn as_mut_ptr_range(&mut self) -> ops::Range<*mut T> {
+        let start = self.as_mut_ptr();
+        let end = unsafe { start.offset(self.len() as isize) };
+        start..end
+    }
+
     #[inline]
     fn contains(&self, x: &T) -> bool where T: PartialEq {
-        self.iter().any(|elt| *x == *elt)
+        specialize::SliceContains::slice_contains(x, self)
     }
 
     #[inline]
@@ -632,6 +1027,26 @@ impl<T> SliceExt for [T] {
         m >= n && needle == &self[m-n..]
     }
 
+    #[inline]
+    fn strip_prefix(&self, prefix: &[T]) -> Option<&[T]> where T: PartialEq {
+        let n = prefix.len();
+        if self.len() >= n && prefix == &self[..n] {
+            Some(&self[n..])
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn strip_suffix(&self, suffix: &[T]) -> Option<&[T]> where T: PartialEq {
+        let (m, n) = (self.len(), suffix.len());
+        if m >= n && suffix == &self[m-n..] {
+            Some(&self[..m-n])
+        } else {
+            None
+        }
+    }
+
     fn binary_search<Q: ?Sized>(&self, x: &Q) -> Result<usize, usize>
         where T: Borrow<Q>,
               Q: Ord
@@ -673,6 +1088,75 @@ impl<T> SliceExt for [T] {
         }
     }
 
+    #[inline]
+    fn fill(&mut self, value: T) where T: Clone {
+        specialize::SpecFill::spec_fill(self, value);
+    }
+
+    #[inline]
+    fn fill_with<F>(&mut self, mut f: F) where F: FnMut() -> T {
+        for el in self {
+            *el = f();
+        }
+    }
+
+    fn copy_within(&mut self, src_start: usize, src_end: usize, dest: usize)
+        where T: Copy
+    {
+        assert!(src_start <= src_end, "src end is before src start");
+        assert!(src_end <= self.len(), "src is out of bounds");
+        let count = src_end - src_start;
+        assert!(dest <= self.len() - count, "dest is out of bounds");
+        // Either index may be `self.len()` when `count` is zero, so the
+        // pointers are offset directly rather than through `get_unchecked`.
+        unsafe {
+            let ptr = self.as_mut_ptr();
+            ptr::copy(ptr.offset(src_start as isize), ptr.offset(dest as isize), count);
+        }
+    }
+
+    fn split_off_first<'a>(slice: &mut &'a [T]) -> Option<&'a T> {
+        let whole: &'a [T] = *slice;
+        match whole.split_first() {
+            Some((first, rem)) => {
+                *slice = rem;
+                Some(first)
+            }
+            None => None,
+        }
+    }
+
+    fn split_off_first_mut<'a>(slice: &mut &'a mut [T]) -> Option<&'a mut T> {
+        match mem::replace(slice, &mut []).split_first_mut() {
+            Some((first, rem)) => {
+                *slice = rem;
+                Some(first)
+            }
+            None => None,
+        }
+    }
+
+    fn split_off_last<'a>(slice: &mut &'a [T]) -> Option<&'a T> {
+        let whole: &'a [T] = *slice;
+        match whole.split_last() {
+            Some((last, rem)) => {
+                *slice = rem;
+                Some(last)
+            }
+            None => None,
+        }
+    }
+
+    fn split_off_last_mut<'a>(slice: &mut &'a mut [T]) -> Option<&'a mut T> {
+        match mem::replace(slice, &mut []).split_last_mut() {
+            Some((last, rem)) => {
+                *slice = rem;
+                Some(last)
+            }
+            None => None,
+        }
+    }
+
     #[inline]
     fn binary_search_by_key<'a, B, F, Q: ?Sized>(&'a self, b: &Q, mut f: F) -> Result<usize, usize>
         where F: FnMut(&'a Self::Item) -> B,
@@ -682,6 +1166,36 @@ impl<T> SliceExt for [T] {
         self.binary_search_by(|k| f(k).borrow().cmp(b))
     }
 
+    #[inline]
+    fn partition_point<P>(&self, mut pred: P) -> usize
+        where P: FnMut(&T) -> bool
+    {
+        self.binary_search_by(|x| if pred(x) { Less } else { Greater })
+            .unwrap_or_else(|i| i)
+    }
+
+    #[inline]
+    fn is_sorted(&self) -> bool
+        where T: PartialOrd
+    {
+        self.is_sorted_by(|a, b| a <= b)
+    }
+
+    #[inline]
+    fn is_sorted_by<F>(&self, mut compare: F) -> bool
+        where F: FnMut(&T, &T) -> bool
+    {
+        self.iter().is_sorted_by(|a, b| compare(*a, *b))
+    }
+
+    #[inline]
+    fn is_sorted_by_key<F, K>(&self, f: F) -> bool
+        where F: FnMut(&T) -> K,
+              K: PartialOrd
+    {
+        self.iter().is_sorted_by_key(f)
+    }
+
     #[inline]
     fn sort_unstable(&mut self)
         where Self::Item: Ord
@@ -703,52 +1217,207 @@ impl<T> SliceExt for [T] {
     {
         sort::quicksort(self, |a, b| f(a).lt(&f(b)));
     }
-}
 
-#[stable(feature = "rust1", since = "1.0.0")]
-#[rustc_on_unimplemented = "slice indices are of type `usize` or ranges of `usize`"]
-impl<T, I> ops::Index<I> for [T]
-    where I: SliceIndex<[T]>
-{
-    type Output = I::Output;
+    #[inline]
+    fn select_nth_unstable(&mut self, index: usize) -> (&mut [T], &mut T, &mut [T])
+        where T: Ord
+    {
+        sort::partition_at_index(self, index, |a: &T, b: &T| a.lt(b))
+    }
 
     #[inline]
-    fn index(&self, index: I) -> &I::Output {
-        index.index(self)
+    fn select_nth_unstable_by<F>(&mut self, index: usize, mut compare: F)
+        -> (&mut [T], &mut T, &mut [T])
+        where F: FnMut(&T, &T) -> Ordering
+    {
+        sort::partition_at_index(self, index, |a: &T, b: &T| compare(a, b) == Less)
     }
-}
 
-#[stable(feature = "rust1", since = "1.0.0")]
-#[rustc_on_unimplemented = "slice indices are of type `usize` or ranges of `usize`"]
-impl<T, I> ops::IndexMut<I> for [T]
-    where I: SliceIndex<[T]>
-{
     #[inline]
-    fn index_mut(&mut self, index: I) -> &mut I::Output {
-        index.index_mut(self)
+    fn select_nth_unstable_by_key<B, F>(&mut self, index: usize, mut f: F)
+        -> (&mut [T], &mut T, &mut [T])
+        where F: FnMut(&T) -> B,
+              B: Ord
+    {
+        sort::partition_at_index(self, index, |a: &T, b: &T| f(a).lt(&f(b)))
     }
-}
 
-#[inline(never)]
-#[cold]
-fn slice_index_len_fail(index: usize, len: usize) -> ! {
-    panic!("index {} out of range for slice of length {}", index, len);
-}
+    unsafe fn align_to<U>(&self) -> (&[T], &[U], &[T]) {
+        // Zero-sized types have no meaningful layout to reinterpret, so
+        // everything stays in the prefix.
+        if mem::size_of::<U>() == 0 || mem::size_of::<T>() == 0 {
+            return (self, &[], &[]);
+        }
 
-#[inline(never)]
-#[cold]
-fn slice_index_order_fail(index: usize, end: usize) -> ! {
-    panic!("slice index starts at {} but ends at {}", index, end);
-}
+        // First, find at what point we split between the prefix and the
+        // aligned middle.
+        let ptr = self.as_ptr();
+        let offset = align_offset::<T, U>(ptr);
+        if offset > self.len() {
+            (self, &[], &[])
+        } else {
+            let (left, rest) = self.split_at(offset);
+            let rest_len = rest.len();
+            let (us_len, ts_len) = align_to_offsets::<T, U>(rest_len);
+            (left,
+             from_raw_parts(rest.as_ptr() as *const U, us_len),
+             from_raw_parts(rest.as_ptr().offset((rest_len - ts_len) as isize), ts_len))
+        }
+    }
 
-/// A helper trait used for indexing operations.
-#[unstable(feature = "slice_get_slice", issue = "35729")]
-#[rustc_on_unimplemented = "slice indices are of type `usize` or ranges of `usize`"]
-pub trait SliceIndex<T: ?Sized> {
-    /// The output type returned by methods.
-    type Output: ?Sized;
+    unsafe fn align_to_mut<U>(&mut self) -> (&mut [T], &mut [U], &mut [T]) {
+        if mem::size_of::<U>() == 0 || mem::size_of::<T>() == 0 {
+            return (self, &mut [], &mut []);
+        }
 
-    /// Returns a shared reference to the output at this location, if in
+        let ptr = self.as_ptr();
+        let offset = align_offset::<T, U>(ptr);
+        if offset > self.len() {
+            (self, &mut [], &mut [])
+        } else {
+            let (left, rest) = self.split_at_mut(offset);
+            let rest_len = rest.len();
+            let (us_len, ts_len) = align_to_offsets::<T, U>(rest_len);
+            let mp = rest.as_mut_ptr();
+            (left,
+             from_raw_parts_mut(mp as *mut U, us_len),
+             from_raw_parts_mut(mp.offset((rest_len - ts_len) as isize), ts_len))
+        }
+    }
+
+    #[inline]
+    fn as_flattened<U>(&self) -> &[U]
+        where T: FixedSizeArray<U>
+    {
+        let len = if mem::size_of::<U>() == 0 {
+            self.len().checked_mul(array_len::<U, T>()).expect("slice len overflow")
+        } else {
+            // A slice of sized elements can never hold more than `isize::MAX`
+            // bytes, so the multiplication cannot overflow.
+            self.len() * array_len::<U, T>()
+        };
+        // `T` is laid out as `[U; N]`, so `self` is `len` contiguous `U`s.
+        unsafe { from_raw_parts(self.as_ptr() as *const U, len) }
+    }
+
+    #[inline]
+    fn as_flattened_mut<U>(&mut self) -> &mut [U]
+        where T: FixedSizeArray<U>
+    {
+        let len = if mem::size_of::<U>() == 0 {
+            self.len().checked_mul(array_len::<U, T>()).expect("slice len overflow")
+        } else {
+            self.len() * array_len::<U, T>()
+        };
+        unsafe { from_raw_parts_mut(self.as_mut_ptr() as *mut U, len) }
+    }
+
+    #[inline]
+    fn split_first_chunk<A>(&self) -> Option<(&A, &[T])>
+        where A: FixedSizeArray<T>
+    {
+        let n = array_len::<T, A>();
+        if self.len() < n {
+            None
+        } else {
+            let (first, tail) = self.split_at(n);
+            // `first` holds exactly `n` elements, which is the layout of `A`.
+            Some((unsafe { &*(first.as_ptr() as *const A) }, tail))
+        }
+    }
+
+    #[inline]
+    fn split_first_chunk_mut<A>(&mut self) -> Option<(&mut A, &mut [T])>
+        where A: FixedSizeArray<T>
+    {
+        let n = array_len::<T, A>();
+        if self.len() < n {
+            None
+        } else {
+            let (first, tail) = self.split_at_mut(n);
+            Some((unsafe { &mut *(first.as_mut_ptr() as *mut A) }, tail))
+        }
+    }
+
+    #[inline]
+    fn split_last_chunk<A>(&self) -> Option<(&[T], &A)>
+        where A: FixedSizeArray<T>
+    {
+        let n = array_len::<T, A>();
+        if self.len() < n {
+            None
+        } else {
+            let (init, last) = self.split_at(self.len() - n);
+            Some((init, unsafe { &*(last.as_ptr() as *const A) }))
+        }
+    }
+
+    #[inline]
+    fn split_last_chunk_mut<A>(&mut self) -> Option<(&mut [T], &mut A)>
+        where A: FixedSizeArray<T>
+    {
+        let n = array_len::<T, A>();
+        if self.len() < n {
+            None
+        } else {
+            let mid = self.len() - n;
+            let (init, last) = self.split_at_mut(mid);
+            Some((init, unsafe { &mut *(last.as_mut_ptr() as *mut A) }))
+        }
+    }
+
+    #[inline]
+    fn get_many_mut<'a, I>(&'a mut self, indices: I) -> Option<I::Output>
+        where I: GetManyMutIndices<'a, T>
+    {
+        indices.get_many_mut(self)
+    }
+}
+
+#[stable(feature = "rust1", since = "1.0.0")]
+#[rustc_on_unimplemented = "slice indices are of type `usize` or ranges of `usize`"]
+impl<T, I> ops::Index<I> for [T]
+    where I: SliceIndex<[T]>
+{
+    type Output = I::Output;
+
+    #[inline]
+    fn index(&self, index: I) -> &I::Output {
+        index.index(self)
+    }
+}
+
+#[stable(feature = "rust1", since = "1.0.0")]
+#[rustc_on_unimplemented = "slice indices are of type `usize` or ranges of `usize`"]
+impl<T, I> ops::IndexMut<I> for [T]
+    where I: SliceIndex<[T]>
+{
+    #[inline]
+    fn index_mut(&mut self, index: I) -> &mut I::Output {
+        index.index_mut(self)
+    }
+}
+
+#[inline(never)]
+#[cold]
+fn slice_index_len_fail(index: usize, len: usize) -> ! {
+    panic!("index {} out of range for slice of length {}", index, len);
+}
+
+#[inline(never)]
+#[cold]
+fn slice_index_order_fail(index: usize, end: usize) -> ! {
+    panic!("slice index starts at {} but ends at {}", index, end);
+}
+
+/// A helper trait used for indexing operations.
+#[unstable(feature = "slice_get_slice", issue = "35729")]
+#[rustc_on_unimplemented = "slice indices are of type `usize` or ranges of `usize`"]
+pub trait SliceIndex<T: ?Sized> {
+    /// The output type returned by methods.
+    type Output: ?Sized;
+
+    /// Returns a shared reference to the output at this location, if in
     /// bounds.
     fn get(self, slice: &T) -> Option<&Self::Output>;
 
@@ -1232,6 +1901,20 @@ macro_rules! iterator {
                 }
             }
 
+            #[inline]
+            fn nth_back(&mut self, n: usize) -> Option<$elem> {
+                // could be implemented with slices, but this avoids bounds checks
+                if n < ptrdistance(self.ptr, self.end) {
+                    unsafe {
+                        self.end = slice_offset!(self.end, -(n as isize) - 1);
+                        Some($mkref!(self.end))
+                    }
+                } else {
+                    self.end = self.ptr;
+                    None
+                }
+            }
+
             fn rfind<F>(&mut self, mut predicate: F) -> Option<Self::Item>
                 where F: FnMut(&Self::Item) -> bool,
             {
@@ -1808,6 +2491,195 @@ impl<'a, T, P> DoubleEndedIterator for SplitMut<'a, T, P> where
 #[unstable(feature = "fused", issue = "35602")]
 impl<'a, T, P> FusedIterator for SplitMut<'a, T, P> where P: FnMut(&T) -> bool {}
 
+/// An iterator over subslices separated by elements that match a predicate
+/// function. Unlike `Split`, it contains the matched part as a terminator
+/// of the subslice.
+///
+/// This struct is created by the [`split_inclusive`] method on [slices].
+///
+/// [`split_inclusive`]: ../../std/primitive.slice.html#method.split_inclusive
+/// [slices]: ../../std/primitive.slice.html
+#[unstable(feature = "split_inclusive", issue = "0")]
+pub struct SplitInclusive<'a, T:'a, P> where P: FnMut(&T) -> bool {
+    v: &'a [T],
+    pred: P,
+    finished: bool
+}
+
+#[unstable(feature = "split_inclusive", issue = "0")]
+impl<'a, T: 'a + fmt::Debug, P> fmt::Debug for SplitInclusive<'a, T, P>
+    where P: FnMut(&T) -> bool
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SplitInclusive")
+            .field("v", &self.v)
+            .field("finished", &self.finished)
+            .finish()
+    }
+}
+
+// FIXME(#19839) Remove in favor of `#[derive(Clone)]`
+#[unstable(feature = "split_inclusive", issue = "0")]
+impl<'a, T, P> Clone for SplitInclusive<'a, T, P> where P: Clone + FnMut(&T) -> bool {
+    fn clone(&self) -> SplitInclusive<'a, T, P> {
+        SplitInclusive {
+            v: self.v,
+            pred: self.pred.clone(),
+            finished: self.finished,
+        }
+    }
+}
+
+#[unstable(feature = "split_inclusive", issue = "0")]
+impl<'a, T, P> Iterator for SplitInclusive<'a, T, P> where P: FnMut(&T) -> bool {
+    type Item = &'a [T];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a [T]> {
+        if self.finished { return None; }
+
+        let idx = self.v.iter().position(|x| (self.pred)(x))
+                                .map(|idx| idx + 1).unwrap_or(self.v.len());
+        if idx == self.v.len() {
+            self.finished = true;
+        }
+        let ret = Some(&self.v[..idx]);
+        self.v = &self.v[idx..];
+        ret
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            (0, Some(0))
+        } else {
+            // if the predicate doesn't match anything, we yield one slice
+            // if it matches every element, we yield `len()` one-element slices,
+            // or a single empty slice.
+            (1, Some(cmp::max(1, self.v.len())))
+        }
+    }
+}
+
+#[unstable(feature = "split_inclusive", issue = "0")]
+impl<'a, T, P> DoubleEndedIterator for SplitInclusive<'a, T, P> where P: FnMut(&T) -> bool {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a [T]> {
+        if self.finished { return None; }
+
+        // The last index of self.v is already checked and found to match
+        // by the last iteration, so we start searching a new match
+        // one index to the left.
+        let remainder: &[T] = if self.v.is_empty() { &[] } else { &self.v[..(self.v.len() - 1)] };
+        let idx = remainder.iter().rposition(|x| (self.pred)(x)).map(|idx| idx + 1).unwrap_or(0);
+        if idx == 0 {
+            self.finished = true;
+        }
+        let ret = Some(&self.v[idx..]);
+        self.v = &self.v[..idx];
+        ret
+    }
+}
+
+#[unstable(feature = "fused", issue = "35602")]
+impl<'a, T, P> FusedIterator for SplitInclusive<'a, T, P> where P: FnMut(&T) -> bool {}
+
+/// An iterator over the mutable subslices of the vector which are separated
+/// by elements that match `pred`. Unlike `SplitMut`, it contains the matched
+/// parts in the ends of the subslices.
+///
+/// This struct is created by the [`split_inclusive_mut`] method on [slices].
+///
+/// [`split_inclusive_mut`]: ../../std/primitive.slice.html#method.split_inclusive_mut
+/// [slices]: ../../std/primitive.slice.html
+#[unstable(feature = "split_inclusive", issue = "0")]
+pub struct SplitInclusiveMut<'a, T:'a, P> where P: FnMut(&T) -> bool {
+    v: &'a mut [T],
+    pred: P,
+    finished: bool
+}
+
+#[unstable(feature = "split_inclusive", issue = "0")]
+impl<'a, T: 'a + fmt::Debug, P> fmt::Debug for SplitInclusiveMut<'a, T, P>
+    where P: FnMut(&T) -> bool
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SplitInclusiveMut")
+            .field("v", &self.v)
+            .field("finished", &self.finished)
+            .finish()
+    }
+}
+
+#[unstable(feature = "split_inclusive", issue = "0")]
+impl<'a, T, P> Iterator for SplitInclusiveMut<'a, T, P> where P: FnMut(&T) -> bool {
+    type Item = &'a mut [T];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a mut [T]> {
+        if self.finished { return None; }
+
+        let idx_opt = { // work around borrowck limitations
+            let pred = &mut self.pred;
+            self.v.iter().position(|x| (*pred)(x))
+        };
+        let idx = idx_opt.map(|idx| idx + 1).unwrap_or(self.v.len());
+        if idx == self.v.len() {
+            self.finished = true;
+        }
+        let tmp = mem::replace(&mut self.v, &mut []);
+        let (head, tail) = tmp.split_at_mut(idx);
+        self.v = tail;
+        Some(head)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            (0, Some(0))
+        } else {
+            // if the predicate doesn't match anything, we yield one slice
+            // if it matches every element, we yield `len()` one-element slices,
+            // or a single empty slice.
+            (1, Some(cmp::max(1, self.v.len())))
+        }
+    }
+}
+
+#[unstable(feature = "split_inclusive", issue = "0")]
+impl<'a, T, P> DoubleEndedIterator for SplitInclusiveMut<'a, T, P> where
+    P: FnMut(&T) -> bool,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a mut [T]> {
+        if self.finished { return None; }
+
+        let idx_opt = if self.v.is_empty() {
+            None
+        } else {
+            // work around borrowck limitations
+            let pred = &mut self.pred;
+
+            // The last index of self.v is already checked and found to match
+            // by the last iteration, so we start searching a new match
+            // one index to the left.
+            let remainder = &self.v[..(self.v.len() - 1)];
+            remainder.iter().rposition(|x| (*pred)(x))
+        };
+        let idx = idx_opt.map(|idx| idx + 1).unwrap_or(0);
+        if idx == 0 {
+            self.finished = true;
+        }
+        let tmp = mem::replace(&mut self.v, &mut []);
+        let (head, tail) = tmp.split_at_mut(idx);
+        self.v = head;
+        Some(tail)
+    }
+}
+
+#[unstable(feature = "fused", issue = "35602")]
+impl<'a, T, P> FusedIterator for SplitInclusiveMut<'a, T, P> where P: FnMut(&T) -> bool {}
+
 /// An iterator over subslices separated by elements that match a predicate
 /// function, starting from the end of the slice.
 ///
@@ -2381,6 +3253,1005 @@ impl<'a, T> ExactSizeIterator for ChunksMut<'a, T> {}
 #[unstable(feature = "fused", issue = "35602")]
 impl<'a, T> FusedIterator for ChunksMut<'a, T> {}
 
+/// An iterator over a slice in (non-overlapping) chunks (`chunk_size` elements at a
+/// time).
+///
+/// When the slice len is not evenly divided by the chunk size, the last
+/// up to `chunk_size-1` elements will be omitted but can be retrieved from
+/// the [`remainder`] function from the iterator.
+///
+/// This struct is created by the [`chunks_exact`] method on [slices].
+///
+/// [`chunks_exact`]: ../../std/primitive.slice.html#method.chunks_exact
+/// [`remainder`]: ../../std/slice/struct.ChunksExact.html#method.remainder
+/// [slices]: ../../std/primitive.slice.html
+#[derive(Debug)]
+#[unstable(feature = "chunks_exact", issue = "0")]
+pub struct ChunksExact<'a, T:'a> {
+    v: &'a [T],
+    rem: &'a [T],
+    chunk_size: usize
+}
+
+#[unstable(feature = "chunks_exact", issue = "0")]
+impl<'a, T> ChunksExact<'a, T> {
+    /// Returns the remainder of the original slice that is not going to be
+    /// returned by the iterator. The returned slice has at most `chunk_size-1`
+    /// elements.
+    pub fn remainder(&self) -> &'a [T] {
+        self.rem
+    }
+}
+
+// FIXME(#19839) Remove in favor of `#[derive(Clone)]`
+#[unstable(feature = "chunks_exact", issue = "0")]
+impl<'a, T> Clone for ChunksExact<'a, T> {
+    fn clone(&self) -> ChunksExact<'a, T> {
+        ChunksExact {
+            v: self.v,
+            rem: self.rem,
+            chunk_size: self.chunk_size,
+        }
+    }
+}
+
+#[unstable(feature = "chunks_exact", issue = "0")]
+impl<'a, T> Iterator for ChunksExact<'a, T> {
+    type Item = &'a [T];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a [T]> {
+        if self.v.len() < self.chunk_size {
+            None
+        } else {
+            let (fst, snd) = self.v.split_at(self.chunk_size);
+            self.v = snd;
+            Some(fst)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.v.len() / self.chunk_size;
+        (n, Some(n))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let (start, overflow) = n.overflowing_mul(self.chunk_size);
+        if start >= self.v.len() || overflow {
+            self.v = &[];
+            None
+        } else {
+            let (_, snd) = self.v.split_at(start);
+            self.v = snd;
+            self.next()
+        }
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+#[unstable(feature = "chunks_exact", issue = "0")]
+impl<'a, T> DoubleEndedIterator for ChunksExact<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a [T]> {
+        if self.v.len() < self.chunk_size {
+            None
+        } else {
+            let (fst, snd) = self.v.split_at(self.v.len() - self.chunk_size);
+            self.v = fst;
+            Some(snd)
+        }
+    }
+}
+
+#[unstable(feature = "chunks_exact", issue = "0")]
+impl<'a, T> ExactSizeIterator for ChunksExact<'a, T> {
+    fn is_empty(&self) -> bool {
+        self.v.is_empty()
+    }
+}
+
+#[unstable(feature = "trusted_len", issue = "37572")]
+unsafe impl<'a, T> TrustedLen for ChunksExact<'a, T> {}
+
+#[unstable(feature = "fused", issue = "35602")]
+impl<'a, T> FusedIterator for ChunksExact<'a, T> {}
+
+/// An iterator over a slice in (non-overlapping) mutable chunks (`chunk_size`
+/// elements at a time).
+///
+/// When the slice len is not evenly divided by the chunk size, the last up to
+/// `chunk_size-1` elements will be omitted but can be retrieved from the
+/// [`into_remainder`] function from the iterator.
+///
+/// This struct is created by the [`chunks_exact_mut`] method on [slices].
+///
+/// [`chunks_exact_mut`]: ../../std/primitive.slice.html#method.chunks_exact_mut
+/// [`into_remainder`]: ../../std/slice/struct.ChunksExactMut.html#method.into_remainder
+/// [slices]: ../../std/primitive.slice.html
+#[derive(Debug)]
+#[unstable(feature = "chunks_exact", issue = "0")]
+pub struct ChunksExactMut<'a, T:'a> {
+    v: &'a mut [T],
+    rem: &'a mut [T],
+    chunk_size: usize
+}
+
+#[unstable(feature = "chunks_exact", issue = "0")]
+impl<'a, T> ChunksExactMut<'a, T> {
+    /// Returns the remainder of the original slice that is not going to be
+    /// returned by the iterator. The returned slice has at most `chunk_size-1`
+    /// elements.
+    pub fn into_remainder(self) -> &'a mut [T] {
+        self.rem
+    }
+}
+
+#[unstable(feature = "chunks_exact", issue = "0")]
+impl<'a, T> Iterator for ChunksExactMut<'a, T> {
+    type Item = &'a mut [T];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a mut [T]> {
+        if self.v.len() < self.chunk_size {
+            None
+        } else {
+            let tmp = mem::replace(&mut self.v, &mut []);
+            let (head, tail) = tmp.split_at_mut(self.chunk_size);
+            self.v = tail;
+            Some(head)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.v.len() / self.chunk_size;
+        (n, Some(n))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<&'a mut [T]> {
+        let (start, overflow) = n.overflowing_mul(self.chunk_size);
+        if start >= self.v.len() || overflow {
+            self.v = &mut [];
+            None
+        } else {
+            let tmp = mem::replace(&mut self.v, &mut []);
+            let (_, snd) = tmp.split_at_mut(start);
+            self.v = snd;
+            self.next()
+        }
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+#[unstable(feature = "chunks_exact", issue = "0")]
+impl<'a, T> DoubleEndedIterator for ChunksExactMut<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a mut [T]> {
+        if self.v.len() < self.chunk_size {
+            None
+        } else {
+            let tmp = mem::replace(&mut self.v, &mut []);
+            let tmp_len = tmp.len();
+            let (head, tail) = tmp.split_at_mut(tmp_len - self.chunk_size);
+            self.v = head;
+            Some(tail)
+        }
+    }
+}
+
+#[unstable(feature = "chunks_exact", issue = "0")]
+impl<'a, T> ExactSizeIterator for ChunksExactMut<'a, T> {
+    fn is_empty(&self) -> bool {
+        self.v.is_empty()
+    }
+}
+
+#[unstable(feature = "trusted_len", issue = "37572")]
+unsafe impl<'a, T> TrustedLen for ChunksExactMut<'a, T> {}
+
+#[unstable(feature = "fused", issue = "35602")]
+impl<'a, T> FusedIterator for ChunksExactMut<'a, T> {}
+
+/// An iterator over a slice in (non-overlapping) chunks (`chunk_size` elements at a
+/// time), starting at the end of the slice.
+///
+/// When the slice len is not evenly divided by the chunk size, the first
+/// up to `chunk_size-1` elements will be omitted but can be retrieved from
+/// the [`remainder`] function from the iterator.
+///
+/// This struct is created by the [`rchunks_exact`] method on [slices].
+///
+/// [`rchunks_exact`]: ../../std/primitive.slice.html#method.rchunks_exact
+/// [`remainder`]: ../../std/slice/struct.RChunksExact.html#method.remainder
+/// [slices]: ../../std/primitive.slice.html
+#[derive(Debug)]
+#[unstable(feature = "chunks_exact", issue = "0")]
+pub struct RChunksExact<'a, T:'a> {
+    v: &'a [T],
+    rem: &'a [T],
+    chunk_size: usize
+}
+
+#[unstable(feature = "chunks_exact", issue = "0")]
+impl<'a, T> RChunksExact<'a, T> {
+    /// Returns the remainder of the original slice that is not going to be
+    /// returned by the iterator. The returned slice has at most `chunk_size-1`
+    /// elements.
+    pub fn remainder(&self) -> &'a [T] {
+        self.rem
+    }
+}
+
+// FIXME(#19839) Remove in favor of `#[derive(Clone)]`
+#[unstable(feature = "chunks_exact", issue = "0")]
+impl<'a, T> Clone for RChunksExact<'a, T> {
+    fn clone(&self) -> RChunksExact<'a, T> {
+        RChunksExact {
+            v: self.v,
+            rem: self.rem,
+            chunk_size: self.chunk_size,
+        }
+    }
+}
+
+#[unstable(feature = "chunks_exact", issue = "0")]
+impl<'a, T> Iterator for RChunksExact<'a, T> {
+    type Item = &'a [T];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a [T]> {
+        if self.v.len() < self.chunk_size {
+            None
+        } else {
+            let (fst, snd) = self.v.split_at(self.v.len() - self.chunk_size);
+            self.v = fst;
+            Some(snd)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.v.len() / self.chunk_size;
+        (n, Some(n))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let (end, overflow) = n.overflowing_mul(self.chunk_size);
+        if end >= self.v.len() || overflow {
+            self.v = &[];
+            None
+        } else {
+            let (fst, _) = self.v.split_at(self.v.len() - end);
+            self.v = fst;
+            self.next()
+        }
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+#[unstable(feature = "chunks_exact", issue = "0")]
+impl<'a, T> DoubleEndedIterator for RChunksExact<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a [T]> {
+        if self.v.len() < self.chunk_size {
+            None
+        } else {
+            let (fst, snd) = self.v.split_at(self.chunk_size);
+            self.v = snd;
+            Some(fst)
+        }
+    }
+}
+
+#[unstable(feature = "chunks_exact", issue = "0")]
+impl<'a, T> ExactSizeIterator for RChunksExact<'a, T> {
+    fn is_empty(&self) -> bool {
+        self.v.is_empty()
+    }
+}
+
+#[unstable(feature = "trusted_len", issue = "37572")]
+unsafe impl<'a, T> TrustedLen for RChunksExact<'a, T> {}
+
+#[unstable(feature = "fused", issue = "35602")]
+impl<'a, T> FusedIterator for RChunksExact<'a, T> {}
+
+/// An iterator over a slice in (non-overlapping) mutable chunks (`chunk_size`
+/// elements at a time), starting at the end of the slice.
+///
+/// When the slice len is not evenly divided by the chunk size, the first up to
+/// `chunk_size-1` elements will be omitted but can be retrieved from the
+/// [`into_remainder`] function from the iterator.
+///
+/// This struct is created by the [`rchunks_exact_mut`] method on [slices].
+///
+/// [`rchunks_exact_mut`]: ../../std/primitive.slice.html#method.rchunks_exact_mut
+/// [`into_remainder`]: ../../std/slice/struct.RChunksExactMut.html#method.into_remainder
+/// [slices]: ../../std/primitive.slice.html
+#[derive(Debug)]
+#[unstable(feature = "chunks_exact", issue = "0")]
+pub struct RChunksExactMut<'a, T:'a> {
+    v: &'a mut [T],
+    rem: &'a mut [T],
+    chunk_size: usize
+}
+
+#[unstable(feature = "chunks_exact", issue = "0")]
+impl<'a, T> RChunksExactMut<'a, T> {
+    /// Returns the remainder of the original slice that is not going to be
+    /// returned by the iterator. The returned slice has at most `chunk_size-1`
+    /// elements.
+    pub fn into_remainder(self) -> &'a mut [T] {
+        self.rem
+    }
+}
+
+#[unstable(feature = "chunks_exact", issue = "0")]
+impl<'a, T> Iterator for RChunksExactMut<'a, T> {
+    type Item = &'a mut [T];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a mut [T]> {
+        if self.v.len() < self.chunk_size {
+            None
+        } else {
+            let tmp = mem::replace(&mut self.v, &mut []);
+            let tmp_len = tmp.len();
+            let (head, tail) = tmp.split_at_mut(tmp_len - self.chunk_size);
+            self.v = head;
+            Some(tail)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.v.len() / self.chunk_size;
+        (n, Some(n))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<&'a mut [T]> {
+        let (end, overflow) = n.overflowing_mul(self.chunk_size);
+        if end >= self.v.len() || overflow {
+            self.v = &mut [];
+            None
+        } else {
+            let tmp = mem::replace(&mut self.v, &mut []);
+            let tmp_len = tmp.len();
+            let (fst, _) = tmp.split_at_mut(tmp_len - end);
+            self.v = fst;
+            self.next()
+        }
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+#[unstable(feature = "chunks_exact", issue = "0")]
+impl<'a, T> DoubleEndedIterator for RChunksExactMut<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a mut [T]> {
+        if self.v.len() < self.chunk_size {
+            None
+        } else {
+            let tmp = mem::replace(&mut self.v, &mut []);
+            let (head, tail) = tmp.split_at_mut(self.chunk_size);
+            self.v = tail;
+            Some(head)
+        }
+    }
+}
+
+#[unstable(feature = "chunks_exact", issue = "0")]
+impl<'a, T> ExactSizeIterator for RChunksExactMut<'a, T> {
+    fn is_empty(&self) -> bool {
+        self.v.is_empty()
+    }
+}
+
+#[unstable(feature = "trusted_len", issue = "37572")]
+unsafe impl<'a, T> TrustedLen for RChunksExactMut<'a, T> {}
+
+#[unstable(feature = "fused", issue = "35602")]
+impl<'a, T> FusedIterator for RChunksExactMut<'a, T> {}
+
+/// An iterator over a slice in (non-overlapping) chunks (`N` elements at a
+/// time), yielded as references to `N`-element arrays, starting at the
+/// beginning of the slice.
+///
+/// When the slice len is not evenly divided by the chunk size, the last
+/// up to `N-1` elements will be omitted but can be retrieved from
+/// the [`remainder`] function from the iterator.
+///
+/// This struct is created by the [`array_chunks`] method on [slices].
+///
+/// [`array_chunks`]: ../../std/primitive.slice.html#method.array_chunks
+/// [`remainder`]: ../../std/slice/struct.ArrayChunks.html#method.remainder
+/// [slices]: ../../std/primitive.slice.html
+#[derive(Debug)]
+#[unstable(feature = "array_chunks", issue = "0")]
+pub struct ArrayChunks<'a, T:'a, A:'a> {
+    iter: Iter<'a, A>,
+    rem: &'a [T],
+}
+
+#[unstable(feature = "array_chunks", issue = "0")]
+impl<'a, T, A> ArrayChunks<'a, T, A> {
+    /// Returns the remainder of the original slice that is not going to be
+    /// returned by the iterator. The returned slice has at most `N-1`
+    /// elements.
+    pub fn remainder(&self) -> &'a [T] {
+        self.rem
+    }
+}
+
+// FIXME(#19839) Remove in favor of `#[derive(Clone)]`
+#[unstable(feature = "array_chunks", issue = "0")]
+impl<'a, T, A> Clone for ArrayChunks<'a, T, A> {
+    fn clone(&self) -> ArrayChunks<'a, T, A> {
+        ArrayChunks {
+            iter: self.iter.clone(),
+            rem: self.rem,
+        }
+    }
+}
+
+#[unstable(feature = "array_chunks", issue = "0")]
+impl<'a, T, A> Iterator for ArrayChunks<'a, T, A> {
+    type Item = &'a A;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a A> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n)
+    }
+
+    #[inline]
+    fn last(self) -> Option<Self::Item> {
+        self.iter.last()
+    }
+}
+
+#[unstable(feature = "array_chunks", issue = "0")]
+impl<'a, T, A> DoubleEndedIterator for ArrayChunks<'a, T, A> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a A> {
+        self.iter.next_back()
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth_back(n)
+    }
+}
+
+#[unstable(feature = "array_chunks", issue = "0")]
+impl<'a, T, A> ExactSizeIterator for ArrayChunks<'a, T, A> {
+    fn is_empty(&self) -> bool {
+        self.iter.is_empty()
+    }
+}
+
+#[unstable(feature = "trusted_len", issue = "37572")]
+unsafe impl<'a, T, A> TrustedLen for ArrayChunks<'a, T, A> {}
+
+#[unstable(feature = "fused", issue = "35602")]
+impl<'a, T, A> FusedIterator for ArrayChunks<'a, T, A> {}
+
+/// An iterator over a slice in (non-overlapping) mutable chunks (`N` elements
+/// at a time), yielded as mutable references to `N`-element arrays, starting
+/// at the beginning of the slice.
+///
+/// When the slice len is not evenly divided by the chunk size, the last
+/// up to `N-1` elements will be omitted but can be retrieved from
+/// the [`into_remainder`] function from the iterator.
+///
+/// This struct is created by the [`array_chunks_mut`] method on [slices].
+///
+/// [`array_chunks_mut`]: ../../std/primitive.slice.html#method.array_chunks_mut
+/// [`into_remainder`]: ../../std/slice/struct.ArrayChunksMut.html#method.into_remainder
+/// [slices]: ../../std/primitive.slice.html
+#[derive(Debug)]
+#[unstable(feature = "array_chunks", issue = "0")]
+pub struct ArrayChunksMut<'a, T:'a, A:'a> {
+    iter: IterMut<'a, A>,
+    rem: &'a mut [T],
+}
+
+#[unstable(feature = "array_chunks", issue = "0")]
+impl<'a, T, A> ArrayChunksMut<'a, T, A> {
+    /// Returns the remainder of the original slice that is not going to be
+    /// returned by the iterator. The returned slice has at most `N-1`
+    /// elements.
+    pub fn into_remainder(self) -> &'a mut [T] {
+        self.rem
+    }
+}
+
+#[unstable(feature = "array_chunks", issue = "0")]
+impl<'a, T, A> Iterator for ArrayChunksMut<'a, T, A> {
+    type Item = &'a mut A;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a mut A> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n)
+    }
+
+    #[inline]
+    fn last(self) -> Option<Self::Item> {
+        self.iter.last()
+    }
+}
+
+#[unstable(feature = "array_chunks", issue = "0")]
+impl<'a, T, A> DoubleEndedIterator for ArrayChunksMut<'a, T, A> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a mut A> {
+        self.iter.next_back()
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth_back(n)
+    }
+}
+
+#[unstable(feature = "array_chunks", issue = "0")]
+impl<'a, T, A> ExactSizeIterator for ArrayChunksMut<'a, T, A> {
+    fn is_empty(&self) -> bool {
+        self.iter.is_empty()
+    }
+}
+
+#[unstable(feature = "trusted_len", issue = "37572")]
+unsafe impl<'a, T, A> TrustedLen for ArrayChunksMut<'a, T, A> {}
+
+#[unstable(feature = "fused", issue = "35602")]
+impl<'a, T, A> FusedIterator for ArrayChunksMut<'a, T, A> {}
+
+/// An iterator over overlapping windows of `N` elements of a slice, yielded
+/// as references to `N`-element arrays.
+///
+/// This struct is created by the [`array_windows`] method on [slices].
+///
+/// [`array_windows`]: ../../std/primitive.slice.html#method.array_windows
+/// [slices]: ../../std/primitive.slice.html
+#[derive(Debug)]
+#[unstable(feature = "array_windows", issue = "0")]
+pub struct ArrayWindows<'a, T:'a, A:'a> {
+    v: &'a [T],
+    size: usize,
+    _marker: marker::PhantomData<&'a A>,
+}
+
+impl<'a, T, A> ArrayWindows<'a, T, A> {
+    // The caller must ensure that `start + self.size <= self.v.len()`.
+    #[inline]
+    unsafe fn window_at(&self, start: usize) -> &'a A {
+        &*(self.v.as_ptr().offset(start as isize) as *const A)
+    }
+}
+
+// FIXME(#19839) Remove in favor of `#[derive(Clone)]`
+#[unstable(feature = "array_windows", issue = "0")]
+impl<'a, T, A> Clone for ArrayWindows<'a, T, A> {
+    fn clone(&self) -> ArrayWindows<'a, T, A> {
+        ArrayWindows {
+            v: self.v,
+            size: self.size,
+            _marker: marker::PhantomData,
+        }
+    }
+}
+
+#[unstable(feature = "array_windows", issue = "0")]
+impl<'a, T, A> Iterator for ArrayWindows<'a, T, A> {
+    type Item = &'a A;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a A> {
+        if self.size > self.v.len() {
+            None
+        } else {
+            let ret = unsafe { self.window_at(0) };
+            self.v = &self.v[1..];
+            Some(ret)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.size > self.v.len() {
+            (0, Some(0))
+        } else {
+            let size = self.v.len() - self.size + 1;
+            (size, Some(size))
+        }
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let (end, overflow) = self.size.overflowing_add(n);
+        if end > self.v.len() || overflow {
+            self.v = &[];
+            None
+        } else {
+            let nth = unsafe { self.window_at(n) };
+            self.v = &self.v[n+1..];
+            Some(nth)
+        }
+    }
+
+    #[inline]
+    fn last(self) -> Option<Self::Item> {
+        if self.size > self.v.len() {
+            None
+        } else {
+            let start = self.v.len() - self.size;
+            Some(unsafe { self.window_at(start) })
+        }
+    }
+}
+
+#[unstable(feature = "array_windows", issue = "0")]
+impl<'a, T, A> DoubleEndedIterator for ArrayWindows<'a, T, A> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a A> {
+        if self.size > self.v.len() {
+            None
+        } else {
+            let ret = unsafe { self.window_at(self.v.len() - self.size) };
+            self.v = &self.v[..self.v.len()-1];
+            Some(ret)
+        }
+    }
+}
+
+#[unstable(feature = "array_windows", issue = "0")]
+impl<'a, T, A> ExactSizeIterator for ArrayWindows<'a, T, A> {}
+
+#[unstable(feature = "trusted_len", issue = "37572")]
+unsafe impl<'a, T, A> TrustedLen for ArrayWindows<'a, T, A> {}
+
+#[unstable(feature = "fused", issue = "35602")]
+impl<'a, T, A> FusedIterator for ArrayWindows<'a, T, A> {}
+
+/// An iterator over a slice in (non-overlapping) chunks separated by a
+/// predicate.
+///
+/// This struct is created by the [`chunk_by`] method on [slices].
+///
+/// [`chunk_by`]: ../../std/primitive.slice.html#method.chunk_by
+/// [slices]: ../../std/primitive.slice.html
+#[unstable(feature = "slice_group_by", issue = "0")]
+pub struct ChunkBy<'a, T:'a, P> {
+    v: &'a [T],
+    pred: P,
+}
+
+#[unstable(feature = "slice_group_by", issue = "0")]
+impl<'a, T: 'a, P> Iterator for ChunkBy<'a, T, P> where P: FnMut(&T, &T) -> bool {
+    type Item = &'a [T];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a [T]> {
+        if self.v.is_empty() {
+            None
+        } else {
+            let mut len = 1;
+            while len < self.v.len() && (self.pred)(&self.v[len - 1], &self.v[len]) {
+                len += 1;
+            }
+            let (head, tail) = self.v.split_at(len);
+            self.v = tail;
+            Some(head)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.v.is_empty() { (0, Some(0)) } else { (1, Some(self.v.len())) }
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+#[unstable(feature = "slice_group_by", issue = "0")]
+impl<'a, T: 'a, P> DoubleEndedIterator for ChunkBy<'a, T, P> where P: FnMut(&T, &T) -> bool {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a [T]> {
+        if self.v.is_empty() {
+            None
+        } else {
+            let mut len = 1;
+            while len < self.v.len() {
+                let end = self.v.len() - len;
+                if !(self.pred)(&self.v[end - 1], &self.v[end]) {
+                    break;
+                }
+                len += 1;
+            }
+            let (head, tail) = self.v.split_at(self.v.len() - len);
+            self.v = head;
+            Some(tail)
+        }
+    }
+}
+
+#[unstable(feature = "fused", issue = "35602")]
+impl<'a, T: 'a, P> FusedIterator for ChunkBy<'a, T, P> where P: FnMut(&T, &T) -> bool {}
+
+#[unstable(feature = "slice_group_by", issue = "0")]
+impl<'a, T: 'a + fmt::Debug, P> fmt::Debug for ChunkBy<'a, T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ChunkBy")
+            .field("v", &self.v)
+            .finish()
+    }
+}
+
+/// An iterator over a slice in (non-overlapping) mutable chunks separated
+/// by a predicate.
+///
+/// This struct is created by the [`chunk_by_mut`] method on [slices].
+///
+/// [`chunk_by_mut`]: ../../std/primitive.slice.html#method.chunk_by_mut
+/// [slices]: ../../std/primitive.slice.html
+#[unstable(feature = "slice_group_by", issue = "0")]
+pub struct ChunkByMut<'a, T:'a, P> {
+    v: &'a mut [T],
+    pred: P,
+}
+
+#[unstable(feature = "slice_group_by", issue = "0")]
+impl<'a, T: 'a, P> Iterator for ChunkByMut<'a, T, P> where P: FnMut(&T, &T) -> bool {
+    type Item = &'a mut [T];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a mut [T]> {
+        if self.v.is_empty() {
+            None
+        } else {
+            let mut len = 1;
+            while len < self.v.len() && (self.pred)(&self.v[len - 1], &self.v[len]) {
+                len += 1;
+            }
+            let tmp = mem::replace(&mut self.v, &mut []);
+            let (head, tail) = tmp.split_at_mut(len);
+            self.v = tail;
+            Some(head)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.v.is_empty() { (0, Some(0)) } else { (1, Some(self.v.len())) }
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+#[unstable(feature = "slice_group_by", issue = "0")]
+impl<'a, T: 'a, P> DoubleEndedIterator for ChunkByMut<'a, T, P> where P: FnMut(&T, &T) -> bool {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a mut [T]> {
+        if self.v.is_empty() {
+            None
+        } else {
+            let mut len = 1;
+            while len < self.v.len() {
+                let end = self.v.len() - len;
+                if !(self.pred)(&self.v[end - 1], &self.v[end]) {
+                    break;
+                }
+                len += 1;
+            }
+            let tmp = mem::replace(&mut self.v, &mut []);
+            let tmp_len = tmp.len();
+            let (head, tail) = tmp.split_at_mut(tmp_len - len);
+            self.v = head;
+            Some(tail)
+        }
+    }
+}
+
+#[unstable(feature = "fused", issue = "35602")]
+impl<'a, T: 'a, P> FusedIterator for ChunkByMut<'a, T, P> where P: FnMut(&T, &T) -> bool {}
+
+#[unstable(feature = "slice_group_by", issue = "0")]
+impl<'a, T: 'a + fmt::Debug, P> fmt::Debug for ChunkByMut<'a, T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ChunkByMut")
+            .field("v", &self.v)
+            .finish()
+    }
+}
+
+/// A set of indices that can be used to borrow several distinct elements of a
+/// slice mutably at once.
+///
+/// This is implemented for arrays of `usize` of up to 32 elements, and is
+/// used by the [`get_many_mut`] method on [slices].
+///
+/// [`get_many_mut`]: ../../std/primitive.slice.html#method.get_many_mut
+/// [slices]: ../../std/primitive.slice.html
+#[unstable(feature = "get_many_mut", issue = "0")]
+pub trait GetManyMutIndices<'a, T: 'a> {
+    /// The array of mutable references produced for these indices.
+    type Output;
+
+    /// Returns mutable references to the elements of `slice` at these
+    /// indices, or `None` if any index is out of bounds or if an index
+    /// appears more than once.
+    fn get_many_mut(self, slice: &'a mut [T]) -> Option<Self::Output>;
+}
+
+macro_rules! get_many_mut_impls {
+    ($($N:expr)+) => {
+        $(
+            #[unstable(feature = "get_many_mut", issue = "0")]
+            impl<'a, T: 'a> GetManyMutIndices<'a, T> for [usize; $N] {
+                type Output = [&'a mut T; $N];
+
+                #[inline]
+                fn get_many_mut(self, slice: &'a mut [T]) -> Option<[&'a mut T; $N]> {
+                    let len = slice.len();
+                    for (i, &idx) in self.iter().enumerate() {
+                        if idx >= len || self[..i].contains(&idx) {
+                            return None;
+                        }
+                    }
+
+                    // Every index is in bounds and distinct from all the
+                    // others, so the references below never alias. The
+                    // pointers are gathered first so that no reference ever
+                    // exists before it points at an element.
+                    let ptr = slice.as_mut_ptr();
+                    let mut ptrs: [*mut T; $N] = [ptr::null_mut(); $N];
+                    for (slot, &idx) in ptrs.iter_mut().zip(self.iter()) {
+                        *slot = unsafe { ptr.offset(idx as isize) };
+                    }
+                    // `*mut T` and `&mut T` have the same layout, so the
+                    // arrays of them do too.
+                    let refs = &ptrs as *const [*mut T; $N] as *const [&'a mut T; $N];
+                    unsafe { Some(ptr::read(refs)) }
+                }
+            }
+        )+
+    }
+}
+
+get_many_mut_impls! {
+     0  1  2  3  4  5  6  7  8  9
+    10 11 12 13 14 15 16 17 18 19
+    20 21 22 23 24 25 26 27 28 29
+    30 31 32
+}
+
+/// Returns the number of `T` elements that need to be skipped from `p` before
+/// the pointer is aligned for a `U`, or `usize::MAX` if stepping by whole `T`s
+/// can never reach such an address.
+///
+/// Both `T` and `U` must be non-zero-sized.
+#[inline]
+fn align_offset<T, U>(p: *const T) -> usize {
+    p.align_offset(mem::align_of::<U>())
+}
+
+/// Given a run of `len` `T`s starting at an address aligned for `U`, returns
+/// how many `U`s fit into it and how many trailing `T`s are left over.
+///
+/// Both `T` and `U` must be non-zero-sized.
+fn align_to_offsets<T, U>(len: usize) -> (usize, usize) {
+    // The run is cut into groups of `ts` `T`s holding exactly `us` `U`s, the
+    // smallest such group being `lcm(size_of::<T>(), size_of::<U>())` bytes.
+    fn gcd(mut a: usize, mut b: usize) -> usize {
+        while b != 0 {
+            let t = a % b;
+            a = b;
+            b = t;
+        }
+        a
+    }
+    let gcd = gcd(mem::size_of::<T>(), mem::size_of::<U>());
+    let ts = mem::size_of::<U>() / gcd;
+    let us = mem::size_of::<T>() / gcd;
+
+    // Each group of `ts` `T`s becomes `us` `U`s; whatever is left over stays a `T`.
+    (len / ts * us, len % ts)
+}
+
+/// Returns the number of `T` elements in the array type `A`.
+#[inline]
+fn array_len<T, A: FixedSizeArray<T>>() -> usize {
+    if mem::size_of::<T>() != 0 {
+        mem::size_of::<A>() / mem::size_of::<T>()
+    } else {
+        // `A` is zero-sized as well, so any well-aligned, non-null address
+        // is a valid place to look at it.
+        unsafe { (&*(mem::align_of::<A>() as *const A)).as_slice().len() }
+    }
+}
+
 //
 // Free functions
 //
@@ -0,0 +1,173 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use fmt;
+use iter::FusedIterator;
+use super::{from_utf8_unchecked, run_utf8_validation};
+
+/// Creates an iterator over the valid UTF-8 runs and the invalid bytes of
+/// `v`.
+///
+/// Each item is a [`Utf8Chunk`] holding the longest run of valid UTF-8 that
+/// could be found before the next invalid sequence, followed by that invalid
+/// sequence. This is the same split that [`String::from_utf8_lossy`] uses to
+/// decide where U+FFFD REPLACEMENT CHARACTERs go, but it doesn't allocate.
+///
+/// [`Utf8Chunk`]: struct.Utf8Chunk.html
+/// [`String::from_utf8_lossy`]: ../../std/string/struct.String.html#method.from_utf8_lossy
+///
+/// # Examples
+///
+/// ```
+/// #![feature(utf8_chunks)]
+/// use std::str;
+///
+/// let bytes = b"Hello\xC2 There\xFF Goodbye";
+/// let mut chunks = str::utf8_chunks(bytes);
+///
+/// let chunk = chunks.next().unwrap();
+/// assert_eq!(chunk.valid(), "Hello");
+/// assert_eq!(chunk.invalid(), b"\xC2");
+///
+/// let chunk = chunks.next().unwrap();
+/// assert_eq!(chunk.valid(), " There");
+/// assert_eq!(chunk.invalid(), b"\xFF");
+///
+/// let chunk = chunks.next().unwrap();
+/// assert_eq!(chunk.valid(), " Goodbye");
+/// assert_eq!(chunk.invalid(), b"");
+///
+/// assert!(chunks.next().is_none());
+/// ```
+///
+/// Writing out a byte slice lossily without allocating:
+///
+/// ```
+/// #![feature(utf8_chunks)]
+/// use std::fmt::{self, Write};
+/// use std::str;
+///
+/// fn write_lossy<W: Write>(w: &mut W, bytes: &[u8]) -> fmt::Result {
+///     for chunk in str::utf8_chunks(bytes) {
+///         w.write_str(chunk.valid())?;
+///         if !chunk.invalid().is_empty() {
+///             w.write_char('\u{FFFD}')?;
+///         }
+///     }
+///     Ok(())
+/// }
+///
+/// let mut s = String::new();
+/// write_lossy(&mut s, b"caf\xC3\xA9 \xE6\x83 ok").unwrap();
+/// assert_eq!(s, "café \u{FFFD} ok");
+/// ```
+#[unstable(feature = "utf8_chunks", issue = "0")]
+#[inline]
+pub fn utf8_chunks(v: &[u8]) -> Utf8Chunks {
+    Utf8Chunks { source: v }
+}
+
+/// A piece of a byte slice split by [`utf8_chunks`]: a run of valid UTF-8,
+/// followed by the invalid sequence that ended it.
+///
+/// [`utf8_chunks`]: fn.utf8_chunks.html
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[unstable(feature = "utf8_chunks", issue = "0")]
+pub struct Utf8Chunk<'a> {
+    valid: &'a str,
+    invalid: &'a [u8],
+}
+
+impl<'a> Utf8Chunk<'a> {
+    /// Returns the valid UTF-8 at the start of this chunk.
+    ///
+    /// This may be empty, for instance between two invalid sequences.
+    #[unstable(feature = "utf8_chunks", issue = "0")]
+    #[inline]
+    pub fn valid(&self) -> &'a str {
+        self.valid
+    }
+
+    /// Returns the invalid sequence that follows the valid part.
+    ///
+    /// This is at most 3 bytes long, and is only empty for the last chunk of
+    /// the input. A lossy decoder replaces each non-empty invalid sequence
+    /// with exactly one U+FFFD REPLACEMENT CHARACTER.
+    #[unstable(feature = "utf8_chunks", issue = "0")]
+    #[inline]
+    pub fn invalid(&self) -> &'a [u8] {
+        self.invalid
+    }
+}
+
+/// An iterator over the [`Utf8Chunk`]s of a byte slice.
+///
+/// This struct is created by the [`utf8_chunks`] function.
+///
+/// [`Utf8Chunk`]: struct.Utf8Chunk.html
+/// [`utf8_chunks`]: fn.utf8_chunks.html
+#[derive(Clone)]
+#[unstable(feature = "utf8_chunks", issue = "0")]
+pub struct Utf8Chunks<'a> {
+    source: &'a [u8],
+}
+
+#[unstable(feature = "utf8_chunks", issue = "0")]
+impl<'a> fmt::Debug for Utf8Chunks<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Utf8Chunks")
+            .field("source", &self.source)
+            .finish()
+    }
+}
+
+#[unstable(feature = "utf8_chunks", issue = "0")]
+impl<'a> Iterator for Utf8Chunks<'a> {
+    type Item = Utf8Chunk<'a>;
+
+    fn next(&mut self) -> Option<Utf8Chunk<'a>> {
+        if self.source.is_empty() {
+            return None;
+        }
+
+        let (valid_up_to, invalid_end) = match run_utf8_validation(self.source) {
+            Ok(()) => (self.source.len(), self.source.len()),
+            Err(e) => {
+                let valid_up_to = e.valid_up_to();
+                // A sequence cut short by the end of the input is invalid
+                // as a whole.
+                let len = e.error_len().unwrap_or(self.source.len() - valid_up_to);
+                (valid_up_to, valid_up_to + len)
+            }
+        };
+
+        let chunk = Utf8Chunk {
+            // `run_utf8_validation` checked everything before
+            // `valid_up_to`.
+            valid: unsafe { from_utf8_unchecked(&self.source[..valid_up_to]) },
+            invalid: &self.source[valid_up_to..invalid_end],
+        };
+        self.source = &self.source[invalid_end..];
+        Some(chunk)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.source.is_empty() {
+            (0, Some(0))
+        } else {
+            // Every byte could be its own invalid sequence.
+            (1, Some(self.source.len()))
+        }
+    }
+}
+
+#[unstable(feature = "fused", issue = "35602")]
+impl<'a> FusedIterator for Utf8Chunks<'a> {}
@@ -17,14 +17,19 @@
 use self::pattern::Pattern;
 use self::pattern::{Searcher, ReverseSearcher, DoubleEndedSearcher};
 
+use ascii;
 use char;
 use convert::TryFrom;
 use fmt;
-use iter::{Map, Cloned, FusedIterator};
+use iter::{Map, Cloned, Filter, FusedIterator};
 use slice::{self, SliceIndex};
 use mem;
 
 pub mod pattern;
+mod lossy;
+
+#[unstable(feature = "utf8_chunks", issue = "0")]
+pub use self::lossy::{utf8_chunks, Utf8Chunk, Utf8Chunks};
 
 /// A trait to abstract the idea of creating a new instance of a type from a
 /// string.
@@ -1044,6 +1049,54 @@ impl<'a, P: Pattern<'a>> SplitInternal<'a, P> {
             },
         }
     }
+
+    #[inline]
+    fn next_inclusive(&mut self) -> Option<&'a str> {
+        if self.finished { return None }
+
+        let haystack = self.matcher.haystack();
+        match self.matcher.next_match() {
+            // Searcher is known to return valid indices, and `self.start`
+            // only ever holds the end of a previous match
+            Some((_, b)) => unsafe {
+                let elt = haystack.slice_unchecked(self.start, b);
+                self.start = b;
+                Some(elt)
+            },
+            None => self.get_end(),
+        }
+    }
+
+    #[inline]
+    fn next_back_inclusive(&mut self) -> Option<&'a str>
+        where P::Searcher: ReverseSearcher<'a>
+    {
+        if self.finished { return None }
+
+        // A match at the very end of the haystack terminates the last piece
+        // rather than starting an empty one, so skip the empty piece it
+        // would otherwise produce.
+        if !self.allow_trailing_empty {
+            self.allow_trailing_empty = true;
+            match self.next_back_inclusive() {
+                Some(elt) if !elt.is_empty() => return Some(elt),
+                _ => if self.finished { return None }
+            }
+        }
+
+        let haystack = self.matcher.haystack();
+        match self.matcher.next_match_back() {
+            Some((_, b)) => unsafe {
+                let elt = haystack.slice_unchecked(b, self.end);
+                self.end = b;
+                Some(elt)
+            },
+            None => unsafe {
+                self.finished = true;
+                Some(haystack.slice_unchecked(self.start, self.end))
+            },
+        }
+    }
 }
 
 generate_pattern_iterators! {
@@ -1082,6 +1135,59 @@ generate_pattern_iterators! {
     delegate double ended;
 }
 
+/// An iterator over the substrings of a string, terminated by a
+/// substring matching to a predicate function.
+/// Unlike `Split`, it contains the matched part as a terminator
+/// of the subslice.
+///
+/// This struct is created by the [`split_inclusive`] method on [`str`].
+/// See its documentation for more.
+///
+/// [`split_inclusive`]: ../../std/primitive.str.html#method.split_inclusive
+/// [`str`]: ../../std/primitive.str.html
+#[unstable(feature = "split_inclusive", issue = "0")]
+pub struct SplitInclusive<'a, P: Pattern<'a>>(SplitInternal<'a, P>);
+
+#[unstable(feature = "split_inclusive", issue = "0")]
+impl<'a, P: Pattern<'a>> Iterator for SplitInclusive<'a, P> {
+    type Item = &'a str;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a str> {
+        self.0.next_inclusive()
+    }
+}
+
+#[unstable(feature = "split_inclusive", issue = "0")]
+impl<'a, P: Pattern<'a>> fmt::Debug for SplitInclusive<'a, P> where P::Searcher: fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SplitInclusive")
+            .field("0", &self.0)
+            .finish()
+    }
+}
+
+// FIXME(#19839) Remove in favor of `#[derive(Clone)]`
+#[unstable(feature = "split_inclusive", issue = "0")]
+impl<'a, P: Pattern<'a>> Clone for SplitInclusive<'a, P> where P::Searcher: Clone {
+    fn clone(&self) -> Self {
+        SplitInclusive(self.0.clone())
+    }
+}
+
+#[unstable(feature = "split_inclusive", issue = "0")]
+impl<'a, P: Pattern<'a>> DoubleEndedIterator for SplitInclusive<'a, P>
+    where P::Searcher: DoubleEndedSearcher<'a>
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a str> {
+        self.0.next_back_inclusive()
+    }
+}
+
+#[unstable(feature = "fused", issue = "35602")]
+impl<'a, P: Pattern<'a>> FusedIterator for SplitInclusive<'a, P> {}
+
 derive_pattern_clone!{
     clone SplitNInternal
     with |s| SplitNInternal { iter: s.iter.clone(), ..*s }
@@ -1350,6 +1456,102 @@ impl<'a> DoubleEndedIterator for LinesAny<'a> {
 #[allow(deprecated)]
 impl<'a> FusedIterator for LinesAny<'a> {}
 
+/// An iterator over the non-ASCII-whitespace substrings of a string,
+/// separated by any amount of ASCII whitespace.
+///
+/// This struct is created by the [`split_ascii_whitespace`] method on [`str`].
+/// See its documentation for more.
+///
+/// [`split_ascii_whitespace`]: ../../std/primitive.str.html#method.split_ascii_whitespace
+/// [`str`]: ../../std/primitive.str.html
+#[unstable(feature = "split_ascii_whitespace", issue = "0")]
+#[derive(Clone, Debug)]
+pub struct SplitAsciiWhitespace<'a> {
+    inner: Map<Filter<slice::Split<'a, u8, IsAsciiWhitespace>, BytesIsNotEmpty>, UnsafeBytesToStr>,
+}
+
+#[unstable(feature = "split_ascii_whitespace", issue = "0")]
+impl<'a> Iterator for SplitAsciiWhitespace<'a> {
+    type Item = &'a str;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a str> {
+        self.inner.next()
+    }
+}
+
+#[unstable(feature = "split_ascii_whitespace", issue = "0")]
+impl<'a> DoubleEndedIterator for SplitAsciiWhitespace<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a str> {
+        self.inner.next_back()
+    }
+}
+
+#[unstable(feature = "fused", issue = "35602")]
+impl<'a> FusedIterator for SplitAsciiWhitespace<'a> {}
+
+/// A nameable, cloneable fn type
+#[derive(Clone)]
+struct IsAsciiWhitespace;
+
+impl<'a> FnMut<(&'a u8,)> for IsAsciiWhitespace {
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, (byte,): (&'a u8,)) -> bool {
+        byte.is_ascii_whitespace()
+    }
+}
+
+impl<'a> FnOnce<(&'a u8,)> for IsAsciiWhitespace {
+    type Output = bool;
+
+    #[inline]
+    extern "rust-call" fn call_once(mut self, arg: (&'a u8,)) -> bool {
+        self.call_mut(arg)
+    }
+}
+
+/// A nameable, cloneable fn type
+#[derive(Clone)]
+struct BytesIsNotEmpty;
+
+impl<'a, 'b> FnMut<(&'a &'b [u8],)> for BytesIsNotEmpty {
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, (bytes,): (&'a &'b [u8],)) -> bool {
+        !bytes.is_empty()
+    }
+}
+
+impl<'a, 'b> FnOnce<(&'a &'b [u8],)> for BytesIsNotEmpty {
+    type Output = bool;
+
+    #[inline]
+    extern "rust-call" fn call_once(mut self, arg: (&'a &'b [u8],)) -> bool {
+        self.call_mut(arg)
+    }
+}
+
+/// A nameable, cloneable fn type
+#[derive(Clone)]
+struct UnsafeBytesToStr;
+
+impl<'a> FnMut<(&'a [u8],)> for UnsafeBytesToStr {
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, (bytes,): (&'a [u8],)) -> &'a str {
+        // Splitting a `str` on ASCII bytes only ever cuts at char boundaries.
+        unsafe { from_utf8_unchecked(bytes) }
+    }
+}
+
+impl<'a> FnOnce<(&'a [u8],)> for UnsafeBytesToStr {
+    type Output = &'a str;
+
+    #[inline]
+    extern "rust-call" fn call_once(mut self, arg: (&'a [u8],)) -> &'a str {
+        self.call_mut(arg)
+    }
+}
+
 /*
 Section: Comparing strings
 */
@@ -1376,14 +1578,137 @@ fn contains_nonascii(x: usize) -> bool {
     (x & NONASCII_MASK) != 0
 }
 
-/// Walks through `iter` checking that it's a valid UTF-8 sequence,
-/// returning `true` in that case, or, if it is invalid, `false` with
-/// `iter` reset such that it is pointing at the first byte in the
-/// invalid sequence.
+/// A shift-based DFA recognising UTF-8, as described in RFC 3629.
+///
+/// Each state is a bit offset into a `u64`, and the transition row for a byte
+/// packs the next state for every current state, so advancing the automaton
+/// is a single table lookup and shift with no data-dependent branches.
+mod utf8_dfa {
+    pub const REJECT: u32 = 0;
+    pub const ACCEPT: u32 = 6;
+    /// Expecting one, two or three more continuation bytes.
+    const CONT1: u32 = 12;
+    const CONT2: u32 = 18;
+    const CONT3: u32 = 24;
+    /// After a lead byte whose first continuation byte is restricted, to
+    /// reject overlong encodings, surrogates, and values above U+10FFFF.
+    const AFTER_E0: u32 = 30;
+    const AFTER_ED: u32 = 36;
+    const AFTER_F0: u32 = 42;
+    const AFTER_F4: u32 = 48;
+
+    macro_rules! row {
+        ($($from:ident => $to:ident),*) => { 0 $(| ($to as u64) << $from)* }
+    }
+
+    // Transitions not listed lead to `REJECT`, which is also a sink.
+    const AS: u64 = row!(ACCEPT => ACCEPT);
+    const C8: u64 = row!(CONT1 => ACCEPT, CONT2 => CONT1, CONT3 => CONT2,
+                         AFTER_ED => CONT1, AFTER_F4 => CONT2);
+    const C9: u64 = row!(CONT1 => ACCEPT, CONT2 => CONT1, CONT3 => CONT2,
+                         AFTER_ED => CONT1, AFTER_F0 => CONT2);
+    const CA: u64 = row!(CONT1 => ACCEPT, CONT2 => CONT1, CONT3 => CONT2,
+                         AFTER_E0 => CONT1, AFTER_F0 => CONT2);
+    const XX: u64 = row!();
+    const L2: u64 = row!(ACCEPT => CONT1);
+    const E0: u64 = row!(ACCEPT => AFTER_E0);
+    const E1: u64 = row!(ACCEPT => CONT2);
+    const ED: u64 = row!(ACCEPT => AFTER_ED);
+    const F0: u64 = row!(ACCEPT => AFTER_F0);
+    const F1: u64 = row!(ACCEPT => CONT3);
+    const F4: u64 = row!(ACCEPT => AFTER_F4);
+
+    static TRANSITIONS: [u64; 256] = [
+        AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS, // 0x0F
+        AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS, // 0x1F
+        AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS, // 0x2F
+        AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS, // 0x3F
+        AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS, // 0x4F
+        AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS, // 0x5F
+        AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS, // 0x6F
+        AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS,AS, // 0x7F
+        C8,C8,C8,C8,C8,C8,C8,C8,C8,C8,C8,C8,C8,C8,C8,C8, // 0x8F
+        C9,C9,C9,C9,C9,C9,C9,C9,C9,C9,C9,C9,C9,C9,C9,C9, // 0x9F
+        CA,CA,CA,CA,CA,CA,CA,CA,CA,CA,CA,CA,CA,CA,CA,CA, // 0xAF
+        CA,CA,CA,CA,CA,CA,CA,CA,CA,CA,CA,CA,CA,CA,CA,CA, // 0xBF
+        XX,XX,L2,L2,L2,L2,L2,L2,L2,L2,L2,L2,L2,L2,L2,L2, // 0xCF
+        L2,L2,L2,L2,L2,L2,L2,L2,L2,L2,L2,L2,L2,L2,L2,L2, // 0xDF
+        E0,E1,E1,E1,E1,E1,E1,E1,E1,E1,E1,E1,E1,ED,E1,E1, // 0xEF
+        F0,F1,F1,F1,F4,XX,XX,XX,XX,XX,XX,XX,XX,XX,XX,XX, // 0xFF
+    ];
+
+    #[inline]
+    pub fn next(state: u32, byte: u8) -> u32 {
+        (TRANSITIONS[byte as usize] >> state) as u32 & 63
+    }
+}
+
+/// Checks that `v` is valid UTF-8.
+///
+/// Multibyte sequences are run through `utf8_dfa`, and runs of ASCII are
+/// skipped two words at a time whenever the automaton is between characters
+/// and the data is aligned. Only once the automaton rejects the input is
+/// the slower decoder run, from the start of the offending sequence, to
+/// work out the exact `Utf8Error`.
 #[inline]
 fn run_utf8_validation(v: &[u8]) -> Result<(), Utf8Error> {
     let mut index = 0;
     let len = v.len();
+    let mut state = utf8_dfa::ACCEPT;
+    // Start of the sequence currently being decoded.
+    let mut char_start = 0;
+
+    let usize_bytes = mem::size_of::<usize>();
+    let ascii_block_size = 2 * usize_bytes;
+    let blocks_end = if len >= ascii_block_size { len - ascii_block_size + 1 } else { 0 };
+    let ptr = v.as_ptr();
+
+    while index < len {
+        if state == utf8_dfa::ACCEPT {
+            let align = (ptr as usize + index) & (usize_bytes - 1);
+            if align == 0 {
+                while index < blocks_end {
+                    unsafe {
+                        let block = ptr.offset(index as isize) as *const usize;
+                        // break if there is a nonascii byte
+                        let zu = contains_nonascii(*block);
+                        let zv = contains_nonascii(*block.offset(1));
+                        if zu | zv {
+                            break;
+                        }
+                    }
+                    index += ascii_block_size;
+                }
+                if index == len {
+                    break;
+                }
+            }
+            char_start = index;
+        }
+        state = utf8_dfa::next(state, v[index]);
+        if state == utf8_dfa::REJECT {
+            break;
+        }
+        index += 1;
+    }
+
+    if state == utf8_dfa::ACCEPT {
+        Ok(())
+    } else {
+        run_utf8_validation_from(v, char_start)
+    }
+}
+
+/// Walks through `v` from `start` checking that it's a valid UTF-8 sequence,
+/// returning the position and length of the first invalid sequence if it
+/// is not.
+///
+/// `start` must be a character boundary.
+#[cold]
+#[inline(never)]
+fn run_utf8_validation_from(v: &[u8], start: usize) -> Result<(), Utf8Error> {
+    let mut index = start;
+    let len = v.len();
 
     let usize_bytes = mem::size_of::<usize>();
     let ascii_block_size = 2 * usize_bytes;
@@ -2065,6 +2390,8 @@ pub trait StrExt {
     fn char_indices(&self) -> CharIndices;
     #[stable(feature = "core", since = "1.6.0")]
     fn split<'a, P: Pattern<'a>>(&'a self, pat: P) -> Split<'a, P>;
+    #[unstable(feature = "split_inclusive", issue = "0")]
+    fn split_inclusive<'a, P: Pattern<'a>>(&'a self, pat: P) -> SplitInclusive<'a, P>;
     #[stable(feature = "core", since = "1.6.0")]
     fn rsplit<'a, P: Pattern<'a>>(&'a self, pat: P) -> RSplit<'a, P>
         where P::Searcher: ReverseSearcher<'a>;
@@ -2090,6 +2417,8 @@ pub trait StrExt {
         where P::Searcher: ReverseSearcher<'a>;
     #[stable(feature = "core", since = "1.6.0")]
     fn lines(&self) -> Lines;
+    #[unstable(feature = "split_ascii_whitespace", issue = "0")]
+    fn split_ascii_whitespace(&self) -> SplitAsciiWhitespace;
     #[stable(feature = "core", since = "1.6.0")]
     #[rustc_deprecated(since = "1.6.0", reason = "use lines() instead now")]
     #[allow(deprecated)]
@@ -2119,10 +2448,27 @@ pub trait StrExt {
     #[stable(feature = "core", since = "1.6.0")]
     fn trim_right_matches<'a, P: Pattern<'a>>(&'a self, pat: P) -> &'a str
         where P::Searcher: ReverseSearcher<'a>;
+    #[unstable(feature = "str_strip", issue = "0")]
+    fn strip_prefix<'a, P: Pattern<'a>>(&'a self, prefix: P) -> Option<&'a str>;
+    #[unstable(feature = "str_strip", issue = "0")]
+    fn strip_suffix<'a, P: Pattern<'a>>(&'a self, suffix: P) -> Option<&'a str>
+        where P::Searcher: ReverseSearcher<'a>;
+    #[unstable(feature = "byte_slice_trim_ascii", issue = "0")]
+    fn trim_ascii_start(&self) -> &str;
+    #[unstable(feature = "byte_slice_trim_ascii", issue = "0")]
+    fn trim_ascii_end(&self) -> &str;
+    #[unstable(feature = "byte_slice_trim_ascii", issue = "0")]
+    fn trim_ascii(&self) -> &str;
     #[stable(feature = "is_char_boundary", since = "1.9.0")]
     fn is_char_boundary(&self, index: usize) -> bool;
+    #[unstable(feature = "round_char_boundary", issue = "0")]
+    fn floor_char_boundary(&self, index: usize) -> usize;
+    #[unstable(feature = "round_char_boundary", issue = "0")]
+    fn ceil_char_boundary(&self, index: usize) -> usize;
     #[stable(feature = "core", since = "1.6.0")]
     fn as_bytes(&self) -> &[u8];
+    #[unstable(feature = "ascii_char", issue = "0")]
+    fn as_ascii(&self) -> Option<&[ascii::Char]>;
     #[stable(feature = "str_mut_extras", since = "1.20.0")]
     unsafe fn as_bytes_mut(&mut self) -> &mut [u8];
     #[stable(feature = "core", since = "1.6.0")]
@@ -2130,6 +2476,11 @@ pub trait StrExt {
     #[stable(feature = "core", since = "1.6.0")]
     fn rfind<'a, P: Pattern<'a>>(&'a self, pat: P) -> Option<usize>
         where P::Searcher: ReverseSearcher<'a>;
+    #[unstable(feature = "str_split_once", issue = "0")]
+    fn split_once<'a, P: Pattern<'a>>(&'a self, delimiter: P) -> Option<(&'a str, &'a str)>;
+    #[unstable(feature = "str_split_once", issue = "0")]
+    fn rsplit_once<'a, P: Pattern<'a>>(&'a self, delimiter: P) -> Option<(&'a str, &'a str)>
+        where P::Searcher: ReverseSearcher<'a>;
     fn find_str<'a, P: Pattern<'a>>(&'a self, pat: P) -> Option<usize>;
     #[stable(feature = "core", since = "1.6.0")]
     fn split_at(&self, mid: usize) -> (&str, &str);
@@ -2222,6 +2573,17 @@ impl StrExt for str {
         })
     }
 
+    #[inline]
+    fn split_inclusive<'a, P: Pattern<'a>>(&'a self, pat: P) -> SplitInclusive<'a, P> {
+        SplitInclusive(SplitInternal {
+            start: 0,
+            end: self.len(),
+            matcher: pat.into_searcher(self),
+            allow_trailing_empty: false,
+            finished: false,
+        })
+    }
+
     #[inline]
     fn rsplit<'a, P: Pattern<'a>>(&'a self, pat: P) -> RSplit<'a, P>
         where P::Searcher: ReverseSearcher<'a>
@@ -2287,6 +2649,15 @@ impl StrExt for str {
         Lines(self.split_terminator('\n').map(LinesAnyMap))
     }
 
+    #[inline]
+    fn split_ascii_whitespace(&self) -> SplitAsciiWhitespace {
+        let inner = self.as_bytes()
+            .split(IsAsciiWhitespace)
+            .filter(BytesIsNotEmpty)
+            .map(UnsafeBytesToStr);
+        SplitAsciiWhitespace { inner: inner }
+    }
+
     #[inline]
     #[allow(deprecated)]
     fn lines_any(&self) -> LinesAny {
@@ -2384,6 +2755,38 @@ impl StrExt for str {
         }
     }
 
+    #[inline]
+    fn strip_prefix<'a, P: Pattern<'a>>(&'a self, prefix: P) -> Option<&'a str> {
+        prefix.strip_prefix_of(self)
+    }
+
+    #[inline]
+    fn strip_suffix<'a, P: Pattern<'a>>(&'a self, suffix: P) -> Option<&'a str>
+        where P::Searcher: ReverseSearcher<'a>
+    {
+        suffix.strip_suffix_of(self)
+    }
+
+    #[inline]
+    fn trim_ascii_start(&self) -> &str {
+        let bytes = self.as_bytes();
+        let start = bytes.iter().position(|b| !b.is_ascii_whitespace()).unwrap_or(bytes.len());
+        // Removing ASCII bytes from either end leaves valid UTF-8 behind.
+        unsafe { self.slice_unchecked(start, bytes.len()) }
+    }
+
+    #[inline]
+    fn trim_ascii_end(&self) -> &str {
+        let bytes = self.as_bytes();
+        let end = bytes.iter().rposition(|b| !b.is_ascii_whitespace()).map_or(0, |i| i + 1);
+        unsafe { self.slice_unchecked(0, end) }
+    }
+
+    #[inline]
+    fn trim_ascii(&self) -> &str {
+        self.trim_ascii_start().trim_ascii_end()
+    }
+
     #[inline]
     fn is_char_boundary(&self, index: usize) -> bool {
         // 0 and len are always ok.
@@ -2397,11 +2800,49 @@ impl StrExt for str {
         }
     }
 
+    #[inline]
+    fn floor_char_boundary(&self, index: usize) -> usize {
+        if index >= self.len() {
+            self.len()
+        } else {
+            // A character is at most four bytes long, so this loops at
+            // most three times.
+            let mut index = index;
+            while !self.is_char_boundary(index) {
+                index -= 1;
+            }
+            index
+        }
+    }
+
+    #[inline]
+    fn ceil_char_boundary(&self, index: usize) -> usize {
+        if index > self.len() {
+            self.len()
+        } else {
+            let mut index = index;
+            while !self.is_char_boundary(index) {
+                index += 1;
+            }
+            index
+        }
+    }
+
     #[inline]
     fn as_bytes(&self) -> &[u8] {
         unsafe { mem::transmute(self) }
     }
 
+    #[inline]
+    fn as_ascii(&self) -> Option<&[ascii::Char]> {
+        if self.bytes().all(|b| b < 128) {
+            // `ascii::Char` is a `repr(C)` wrapper around a `u8`.
+            Some(unsafe { mem::transmute(self.as_bytes()) })
+        } else {
+            None
+        }
+    }
+
     #[inline]
     unsafe fn as_bytes_mut(&mut self) -> &mut [u8] {
         mem::transmute(self)
@@ -2417,6 +2858,30 @@ impl StrExt for str {
         pat.into_searcher(self).next_match_back().map(|(i, _)| i)
     }
 
+    #[inline]
+    fn split_once<'a, P: Pattern<'a>>(&'a self, delimiter: P) -> Option<(&'a str, &'a str)> {
+        match delimiter.into_searcher(self).next_match() {
+            // Searcher is known to return valid indices
+            Some((start, end)) => unsafe {
+                Some((self.slice_unchecked(0, start), self.slice_unchecked(end, self.len())))
+            },
+            None => None,
+        }
+    }
+
+    #[inline]
+    fn rsplit_once<'a, P: Pattern<'a>>(&'a self, delimiter: P) -> Option<(&'a str, &'a str)>
+        where P::Searcher: ReverseSearcher<'a>
+    {
+        match delimiter.into_searcher(self).next_match_back() {
+            // Searcher is known to return valid indices
+            Some((start, end)) => unsafe {
+                Some((self.slice_unchecked(0, start), self.slice_unchecked(end, self.len())))
+            },
+            None => None,
+        }
+    }
+
     fn find_str<'a, P: Pattern<'a>>(&'a self, pat: P) -> Option<usize> {
         self.find(pat)
     }
@@ -10,8 +10,89 @@
 
 //! The string Pattern API.
 //!
+//! The Pattern API provides a generic mechanism for using different pattern
+//! types when searching through a string. It is what lets methods such as
+//! `str::find`, `str::split` and `str::trim_matches` accept a `char`, a
+//! `&str`, a `&[char]` or a `FnMut(char) -> bool` closure alike.
+//!
 //! For more details, see the traits `Pattern`, `Searcher`,
 //! `ReverseSearcher` and `DoubleEndedSearcher`.
+//!
+//! # Implementing a pattern
+//!
+//! Types outside the standard library can be used as patterns as well, by
+//! implementing `Pattern` and a matching `Searcher`. The searcher is given
+//! the haystack and yields a stream of `SearchStep`s that covers it from
+//! front to back; every index it reports must lie on a UTF-8 character
+//! boundary, which is why `Searcher` is an `unsafe` trait. Patterns that can
+//! also search from the back implement `ReverseSearcher`, which enables
+//! methods such as `rfind`, `rsplit` and `trim_right_matches`.
+//!
+//! ```
+//! #![feature(pattern)]
+//! use std::str::pattern::{Pattern, Searcher, SearchStep};
+//!
+//! /// Matches any single byte from a set of ASCII bytes.
+//! struct AsciiSet<'b>(&'b [u8]);
+//!
+//! impl<'b> AsciiSet<'b> {
+//!     fn new(set: &'b [u8]) -> AsciiSet<'b> {
+//!         assert!(set.iter().all(|&b| b < 128));
+//!         AsciiSet(set)
+//!     }
+//! }
+//!
+//! struct AsciiSetSearcher<'a, 'b> {
+//!     haystack: &'a str,
+//!     set: &'b [u8],
+//!     position: usize,
+//! }
+//!
+//! impl<'a, 'b> Pattern<'a> for AsciiSet<'b> {
+//!     type Searcher = AsciiSetSearcher<'a, 'b>;
+//!
+//!     fn into_searcher(self, haystack: &'a str) -> AsciiSetSearcher<'a, 'b> {
+//!         AsciiSetSearcher { haystack: haystack, set: self.0, position: 0 }
+//!     }
+//! }
+//!
+//! // Every byte in the set is ASCII, so the positions just before and just
+//! // after a match are always character boundaries.
+//! unsafe impl<'a, 'b> Searcher<'a> for AsciiSetSearcher<'a, 'b> {
+//!     fn haystack(&self) -> &'a str {
+//!         self.haystack
+//!     }
+//!
+//!     fn next(&mut self) -> SearchStep {
+//!         let start = self.position;
+//!         let rest = &self.haystack.as_bytes()[start..];
+//!         if rest.is_empty() {
+//!             return SearchStep::Done;
+//!         }
+//!         match rest.iter().position(|b| self.set.contains(b)) {
+//!             Some(0) => {
+//!                 self.position += 1;
+//!                 SearchStep::Match(start, start + 1)
+//!             }
+//!             Some(i) => {
+//!                 self.position += i;
+//!                 SearchStep::Reject(start, start + i)
+//!             }
+//!             None => {
+//!                 self.position = self.haystack.len();
+//!                 SearchStep::Reject(start, self.position)
+//!             }
+//!         }
+//!     }
+//! }
+//!
+//! let separators = AsciiSet::new(b",;");
+//! let fields: Vec<&str> = "größe,farbe;preis".split(separators).collect();
+//! assert_eq!(fields, ["größe", "farbe", "preis"]);
+//!
+//! assert_eq!("über;alles".find(AsciiSet::new(b";")), Some(5));
+//! assert_eq!(";;,x".trim_left_matches(AsciiSet::new(b",;")), "x");
+//! ```
 
 #![unstable(feature = "pattern",
             reason = "API not fully fleshed out and ready to be stabilized",
@@ -67,6 +148,38 @@ pub trait Pattern<'a>: Sized {
             _ => false,
         }
     }
+
+    /// Removes the pattern from the front of haystack, if it matches.
+    #[inline]
+    fn strip_prefix_of(self, haystack: &'a str) -> Option<&'a str> {
+        match self.into_searcher(haystack).next() {
+            SearchStep::Match(0, len) => {
+                debug_assert!(haystack.is_char_boundary(len),
+                              "The first search step from Searcher \
+                               must include the first character");
+                // Searcher is known to return valid indices.
+                unsafe { Some(haystack.slice_unchecked(len, haystack.len())) }
+            }
+            _ => None,
+        }
+    }
+
+    /// Removes the pattern from the back of haystack, if it matches.
+    #[inline]
+    fn strip_suffix_of(self, haystack: &'a str) -> Option<&'a str>
+        where Self::Searcher: ReverseSearcher<'a>
+    {
+        match self.into_searcher(haystack).next_back() {
+            SearchStep::Match(start, end) if end == haystack.len() => {
+                debug_assert!(haystack.is_char_boundary(start),
+                              "The first search step from ReverseSearcher \
+                               must include the last character");
+                // Searcher is known to return valid indices.
+                unsafe { Some(haystack.slice_unchecked(0, start)) }
+            }
+            _ => None,
+        }
+    }
 }
 
 // Searcher
@@ -371,6 +484,18 @@ macro_rules! pattern_methods {
         {
             ($pmap)(self).is_suffix_of(haystack)
         }
+
+        #[inline]
+        fn strip_prefix_of(self, haystack: &'a str) -> Option<&'a str> {
+            ($pmap)(self).strip_prefix_of(haystack)
+        }
+
+        #[inline]
+        fn strip_suffix_of(self, haystack: &'a str) -> Option<&'a str>
+            where $t: ReverseSearcher<'a>
+        {
+            ($pmap)(self).strip_suffix_of(haystack)
+        }
     }
 }
 
@@ -456,6 +581,18 @@ impl<'a> Pattern<'a> for char {
     {
         CharEqPattern(self).is_suffix_of(haystack)
     }
+
+    #[inline]
+    fn strip_prefix_of(self, haystack: &'a str) -> Option<&'a str> {
+        CharEqPattern(self).strip_prefix_of(haystack)
+    }
+
+    #[inline]
+    fn strip_suffix_of(self, haystack: &'a str) -> Option<&'a str>
+        where Self::Searcher: ReverseSearcher<'a>
+    {
+        CharEqPattern(self).strip_suffix_of(haystack)
+    }
 }
 
 /////////////////////////////////////////////////////////////////////////////
@@ -562,6 +699,28 @@ impl<'a, 'b> Pattern<'a> for &'b str {
             haystack.is_char_boundary(haystack.len() - self.len()) &&
             self == &haystack[haystack.len() - self.len()..]
     }
+
+    /// Removes the pattern from the front of haystack, if it matches.
+    #[inline]
+    fn strip_prefix_of(self, haystack: &'a str) -> Option<&'a str> {
+        if self.is_prefix_of(haystack) {
+            // `self` is a prefix, so `self.len()` is a char boundary.
+            unsafe { Some(haystack.slice_unchecked(self.len(), haystack.len())) }
+        } else {
+            None
+        }
+    }
+
+    /// Removes the pattern from the back of haystack, if it matches.
+    #[inline]
+    fn strip_suffix_of(self, haystack: &'a str) -> Option<&'a str> {
+        if self.is_suffix_of(haystack) {
+            // `self` is a suffix, so its start is a char boundary.
+            unsafe { Some(haystack.slice_unchecked(0, haystack.len() - self.len())) }
+        } else {
+            None
+        }
+    }
 }
 
 
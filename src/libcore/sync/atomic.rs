@@ -90,6 +90,7 @@
 
 use self::Ordering::*;
 
+use hint;
 use intrinsics;
 use cell::UnsafeCell;
 use fmt;
@@ -102,19 +103,15 @@ use fmt;
 /// combination of both functions.  Careful benchmarking is advised.
 ///
 /// On some platforms this function may not do anything at all.
+///
+/// This is the same as [`hint::spin_loop`].
+///
+/// [`hint::spin_loop`]: ../../hint/fn.spin_loop.html
 #[inline]
 #[unstable(feature = "hint_core_should_pause", issue = "41196")]
 pub fn hint_core_should_pause()
 {
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    unsafe {
-        asm!("pause" ::: "memory" : "volatile");
-    }
-
-    #[cfg(target_arch = "aarch64")]
-    unsafe {
-        asm!("yield" ::: "memory" : "volatile");
-    }
+    hint::spin_loop()
 }
 
 /// A boolean type which can be safely shared between threads.
@@ -920,15 +917,17 @@ impl<T> AtomicPtr<T> {
 
 #[cfg(target_has_atomic = "ptr")]
 macro_rules! atomic_int {
-    ($stable:meta,
+    ($(#[$attr:meta])*
+     $stable:meta,
      $stable_cxchg:meta,
      $stable_debug:meta,
      $stable_access:meta,
-     $int_type:ident $atomic_type:ident $atomic_init:ident) => {
+     $int_type:ident $atomic_type:ident $atomic_init:ident
+     $max_fn:ident $min_fn:ident) => {
         /// An integer type which can be safely shared between threads.
         ///
-        /// This type has the same in-memory representation as the underlying integer type.
         #[$stable]
+        $(#[$attr])*
         pub struct $atomic_type {
             v: UnsafeCell<$int_type>,
         }
@@ -1325,89 +1324,245 @@ macro_rules! atomic_int {
             pub fn fetch_xor(&self, val: $int_type, order: Ordering) -> $int_type {
                 unsafe { atomic_xor(self.v.get(), val, order) }
             }
+
+            /// Fetches the value, and applies a function to it that returns an optional
+            /// new value. Returns a `Result` of `Ok(previous_value)` if the function
+            /// returned `Some(_)`, else `Err(previous_value)`.
+            ///
+            /// Note: This may call the function multiple times if the value has been
+            /// changed from other threads in the meantime, as long as the function
+            /// returns `Some(_)`, but the function will have been applied only once to
+            /// the stored value.
+            ///
+            /// `fetch_update` takes two [`Ordering`] arguments to describe the memory
+            /// ordering of this operation. The first describes the required ordering for
+            /// when the operation finally succeeds while the second describes the
+            /// required ordering for loads. These correspond to the success and failure
+            /// orderings of [`compare_exchange`] respectively, and the same restrictions
+            /// apply to them.
+            ///
+            /// [`Ordering`]: enum.Ordering.html
+            /// [`compare_exchange`]: #method.compare_exchange
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(atomic_fetch_update)]
+            /// use std::sync::atomic::AtomicIsize;
+            /// use std::sync::atomic::Ordering::SeqCst;
+            ///
+            /// let x = AtomicIsize::new(7);
+            /// assert_eq!(x.fetch_update(SeqCst, SeqCst, |_| None), Err(7));
+            /// assert_eq!(x.fetch_update(SeqCst, SeqCst, |x| Some(x + 1)), Ok(7));
+            /// assert_eq!(x.fetch_update(SeqCst, SeqCst, |x| Some(x + 1)), Ok(8));
+            /// assert_eq!(x.load(SeqCst), 9);
+            /// ```
+            #[inline]
+            #[unstable(feature = "atomic_fetch_update", issue = "0")]
+            pub fn fetch_update<F>(&self,
+                                   set_order: Ordering,
+                                   fetch_order: Ordering,
+                                   mut f: F) -> Result<$int_type, $int_type>
+                where F: FnMut($int_type) -> Option<$int_type>
+            {
+                let mut prev = self.load(fetch_order);
+                while let Some(next) = f(prev) {
+                    match self.compare_exchange_weak(prev, next, set_order, fetch_order) {
+                        x @ Ok(_) => return x,
+                        Err(next_prev) => prev = next_prev,
+                    }
+                }
+                Err(prev)
+            }
+
+            /// Maximum with the current value.
+            ///
+            /// Finds the maximum of the current value and the argument `val`, and
+            /// sets the new value to the result.
+            ///
+            /// Returns the previous value.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(atomic_min_max)]
+            /// use std::sync::atomic::{AtomicIsize, Ordering};
+            ///
+            /// let foo = AtomicIsize::new(23);
+            /// assert_eq!(foo.fetch_max(42, Ordering::SeqCst), 23);
+            /// assert_eq!(foo.load(Ordering::SeqCst), 42);
+            /// ```
+            ///
+            /// If you want to obtain the maximum value in one step, you can use the following:
+            ///
+            /// ```
+            /// #![feature(atomic_min_max)]
+            /// use std::sync::atomic::{AtomicIsize, Ordering};
+            ///
+            /// let foo = AtomicIsize::new(23);
+            /// let bar = 42;
+            /// let max_foo = foo.fetch_max(bar, Ordering::SeqCst).max(bar);
+            /// assert!(max_foo == 42);
+            /// ```
+            #[inline]
+            #[unstable(feature = "atomic_min_max", issue = "0")]
+            pub fn fetch_max(&self, val: $int_type, order: Ordering) -> $int_type {
+                unsafe { $max_fn(self.v.get(), val, order) }
+            }
+
+            /// Minimum with the current value.
+            ///
+            /// Finds the minimum of the current value and the argument `val`, and
+            /// sets the new value to the result.
+            ///
+            /// Returns the previous value.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(atomic_min_max)]
+            /// use std::sync::atomic::{AtomicIsize, Ordering};
+            ///
+            /// let foo = AtomicIsize::new(23);
+            /// assert_eq!(foo.fetch_min(42, Ordering::Relaxed), 23);
+            /// assert_eq!(foo.load(Ordering::Relaxed), 23);
+            /// assert_eq!(foo.fetch_min(22, Ordering::Relaxed), 23);
+            /// assert_eq!(foo.load(Ordering::Relaxed), 22);
+            /// ```
+            #[inline]
+            #[unstable(feature = "atomic_min_max", issue = "0")]
+            pub fn fetch_min(&self, val: $int_type, order: Ordering) -> $int_type {
+                unsafe { $min_fn(self.v.get(), val, order) }
+            }
         }
     }
 }
 
 #[cfg(target_has_atomic = "8")]
 atomic_int! {
+    /// This type has the same in-memory representation as `i8`.
     unstable(feature = "integer_atomics", issue = "32976"),
     unstable(feature = "integer_atomics", issue = "32976"),
     unstable(feature = "integer_atomics", issue = "32976"),
     unstable(feature = "integer_atomics", issue = "32976"),
     i8 AtomicI8 ATOMIC_I8_INIT
+    atomic_max atomic_min
 }
 #[cfg(target_has_atomic = "8")]
 atomic_int! {
+    /// This type has the same in-memory representation as `u8`.
     unstable(feature = "integer_atomics", issue = "32976"),
     unstable(feature = "integer_atomics", issue = "32976"),
     unstable(feature = "integer_atomics", issue = "32976"),
     unstable(feature = "integer_atomics", issue = "32976"),
     u8 AtomicU8 ATOMIC_U8_INIT
+    atomic_umax atomic_umin
 }
 #[cfg(target_has_atomic = "16")]
 atomic_int! {
+    /// This type has the same in-memory representation as `i16`.
     unstable(feature = "integer_atomics", issue = "32976"),
     unstable(feature = "integer_atomics", issue = "32976"),
     unstable(feature = "integer_atomics", issue = "32976"),
     unstable(feature = "integer_atomics", issue = "32976"),
     i16 AtomicI16 ATOMIC_I16_INIT
+    atomic_max atomic_min
 }
 #[cfg(target_has_atomic = "16")]
 atomic_int! {
+    /// This type has the same in-memory representation as `u16`.
     unstable(feature = "integer_atomics", issue = "32976"),
     unstable(feature = "integer_atomics", issue = "32976"),
     unstable(feature = "integer_atomics", issue = "32976"),
     unstable(feature = "integer_atomics", issue = "32976"),
     u16 AtomicU16 ATOMIC_U16_INIT
+    atomic_umax atomic_umin
 }
 #[cfg(target_has_atomic = "32")]
 atomic_int! {
+    /// This type has the same in-memory representation as `i32`.
     unstable(feature = "integer_atomics", issue = "32976"),
     unstable(feature = "integer_atomics", issue = "32976"),
     unstable(feature = "integer_atomics", issue = "32976"),
     unstable(feature = "integer_atomics", issue = "32976"),
     i32 AtomicI32 ATOMIC_I32_INIT
+    atomic_max atomic_min
 }
 #[cfg(target_has_atomic = "32")]
 atomic_int! {
+    /// This type has the same in-memory representation as `u32`.
     unstable(feature = "integer_atomics", issue = "32976"),
     unstable(feature = "integer_atomics", issue = "32976"),
     unstable(feature = "integer_atomics", issue = "32976"),
     unstable(feature = "integer_atomics", issue = "32976"),
     u32 AtomicU32 ATOMIC_U32_INIT
+    atomic_umax atomic_umin
 }
 #[cfg(target_has_atomic = "64")]
 atomic_int! {
+    /// This type has the same in-memory representation as `i64`.
     unstable(feature = "integer_atomics", issue = "32976"),
     unstable(feature = "integer_atomics", issue = "32976"),
     unstable(feature = "integer_atomics", issue = "32976"),
     unstable(feature = "integer_atomics", issue = "32976"),
     i64 AtomicI64 ATOMIC_I64_INIT
+    atomic_max atomic_min
 }
 #[cfg(target_has_atomic = "64")]
 atomic_int! {
+    /// This type has the same in-memory representation as `u64`.
     unstable(feature = "integer_atomics", issue = "32976"),
     unstable(feature = "integer_atomics", issue = "32976"),
     unstable(feature = "integer_atomics", issue = "32976"),
     unstable(feature = "integer_atomics", issue = "32976"),
     u64 AtomicU64 ATOMIC_U64_INIT
+    atomic_umax atomic_umin
+}
+#[cfg(target_has_atomic = "128")]
+atomic_int! {
+    // Double-word compare-and-swap instructions need the operand to be
+    // aligned to its size, which `i128` alone isn't on every target.
+    /// This type has the same size as `i128`, but unlike `i128` it is
+    /// always aligned to 16 bytes.
+    #[repr(C, align(16))]
+    unstable(feature = "integer_atomics", issue = "32976"),
+    unstable(feature = "integer_atomics", issue = "32976"),
+    unstable(feature = "integer_atomics", issue = "32976"),
+    unstable(feature = "integer_atomics", issue = "32976"),
+    i128 AtomicI128 ATOMIC_I128_INIT
+    atomic_max atomic_min
+}
+#[cfg(target_has_atomic = "128")]
+atomic_int! {
+    /// This type has the same size as `u128`, but unlike `u128` it is
+    /// always aligned to 16 bytes.
+    #[repr(C, align(16))]
+    unstable(feature = "integer_atomics", issue = "32976"),
+    unstable(feature = "integer_atomics", issue = "32976"),
+    unstable(feature = "integer_atomics", issue = "32976"),
+    unstable(feature = "integer_atomics", issue = "32976"),
+    u128 AtomicU128 ATOMIC_U128_INIT
+    atomic_umax atomic_umin
 }
 #[cfg(target_has_atomic = "ptr")]
 atomic_int!{
+    /// This type has the same in-memory representation as `isize`.
     stable(feature = "rust1", since = "1.0.0"),
     stable(feature = "extended_compare_and_swap", since = "1.10.0"),
     stable(feature = "atomic_debug", since = "1.3.0"),
     stable(feature = "atomic_access", since = "1.15.0"),
     isize AtomicIsize ATOMIC_ISIZE_INIT
+    atomic_max atomic_min
 }
 #[cfg(target_has_atomic = "ptr")]
 atomic_int!{
+    /// This type has the same in-memory representation as `usize`.
     stable(feature = "rust1", since = "1.0.0"),
     stable(feature = "extended_compare_and_swap", since = "1.10.0"),
     stable(feature = "atomic_debug", since = "1.3.0"),
     stable(feature = "atomic_access", since = "1.15.0"),
     usize AtomicUsize ATOMIC_USIZE_INIT
+    atomic_umax atomic_umin
 }
 
 #[inline]
@@ -1572,6 +1727,54 @@ unsafe fn atomic_xor<T>(dst: *mut T, val: T, order: Ordering) -> T {
     }
 }
 
+#[inline]
+unsafe fn atomic_max<T>(dst: *mut T, val: T, order: Ordering) -> T {
+    match order {
+        Acquire => intrinsics::atomic_max_acq(dst, val),
+        Release => intrinsics::atomic_max_rel(dst, val),
+        AcqRel => intrinsics::atomic_max_acqrel(dst, val),
+        Relaxed => intrinsics::atomic_max_relaxed(dst, val),
+        SeqCst => intrinsics::atomic_max(dst, val),
+        __Nonexhaustive => panic!("invalid memory ordering"),
+    }
+}
+
+#[inline]
+unsafe fn atomic_min<T>(dst: *mut T, val: T, order: Ordering) -> T {
+    match order {
+        Acquire => intrinsics::atomic_min_acq(dst, val),
+        Release => intrinsics::atomic_min_rel(dst, val),
+        AcqRel => intrinsics::atomic_min_acqrel(dst, val),
+        Relaxed => intrinsics::atomic_min_relaxed(dst, val),
+        SeqCst => intrinsics::atomic_min(dst, val),
+        __Nonexhaustive => panic!("invalid memory ordering"),
+    }
+}
+
+#[inline]
+unsafe fn atomic_umax<T>(dst: *mut T, val: T, order: Ordering) -> T {
+    match order {
+        Acquire => intrinsics::atomic_umax_acq(dst, val),
+        Release => intrinsics::atomic_umax_rel(dst, val),
+        AcqRel => intrinsics::atomic_umax_acqrel(dst, val),
+        Relaxed => intrinsics::atomic_umax_relaxed(dst, val),
+        SeqCst => intrinsics::atomic_umax(dst, val),
+        __Nonexhaustive => panic!("invalid memory ordering"),
+    }
+}
+
+#[inline]
+unsafe fn atomic_umin<T>(dst: *mut T, val: T, order: Ordering) -> T {
+    match order {
+        Acquire => intrinsics::atomic_umin_acq(dst, val),
+        Release => intrinsics::atomic_umin_rel(dst, val),
+        AcqRel => intrinsics::atomic_umin_acqrel(dst, val),
+        Relaxed => intrinsics::atomic_umin_relaxed(dst, val),
+        SeqCst => intrinsics::atomic_umin(dst, val),
+        __Nonexhaustive => panic!("invalid memory ordering"),
+    }
+}
+
 /// An atomic fence.
 ///
 /// Depending on the specified order, a fence prevents the compiler and CPU from
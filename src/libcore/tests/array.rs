@@ -7,7 +7,8 @@
 // <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
-use core::array::FixedSizeArray;
+use core::array::{self, ArrayEachRef, ArrayMap, FixedSizeArray, IntoIter};
+use core::cell::Cell;
 
 #[test]
 fn fixed_size_array() {
@@ -26,3 +27,201 @@ fn fixed_size_array() {
     assert_eq!(FixedSizeArray::as_mut_slice(&mut empty_array).len(), 0);
     assert_eq!(FixedSizeArray::as_mut_slice(&mut empty_zero_sized).len(), 0);
 }
+
+#[test]
+fn array_map() {
+    let a = [1, 2, 3];
+    assert_eq!(a.map(|x| x * 2), [2, 4, 6]);
+
+    let empty: [String; 0] = [];
+    let mapped: [usize; 0] = empty.map(|s| s.len());
+    assert_eq!(mapped, []);
+
+    let strings = [String::from("a"), String::from("bc")];
+    assert_eq!(strings.map(|s| s + "!"), [String::from("a!"), String::from("bc!")]);
+}
+
+#[test]
+fn array_from_fn() {
+    let a: [usize; 4] = array::from_fn(|i| i * 10);
+    assert_eq!(a, [0, 10, 20, 30]);
+
+    let empty: [String; 0] = array::from_fn(|_| unreachable!());
+    assert_eq!(empty.len(), 0);
+
+    let mut calls = Vec::new();
+    let strings: [String; 3] = array::from_fn(|i| {
+        calls.push(i);
+        i.to_string()
+    });
+    assert_eq!(strings, [String::from("0"), String::from("1"), String::from("2")]);
+    assert_eq!(calls, [0, 1, 2]);
+}
+
+#[test]
+fn array_map_panic_drops() {
+    use std::panic;
+
+    struct DropCounter<'a>(&'a Cell<usize>);
+    impl<'a> Drop for DropCounter<'a> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    let drops = Cell::new(0);
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        let a = [DropCounter(&drops), DropCounter(&drops), DropCounter(&drops)];
+        let mut calls = 0;
+        a.map(|d| {
+            calls += 1;
+            if calls == 2 {
+                panic!("oops");
+            }
+            d
+        });
+    }));
+    assert!(result.is_err());
+    assert_eq!(drops.get(), 3);
+}
+
+#[test]
+fn array_try_map() {
+    let a = ["1", "2", "3"];
+    assert_eq!(a.try_map(|s| s.parse::<i32>()), Ok([1, 2, 3]));
+
+    let mut calls = 0;
+    let b = ["1", "x", "3"];
+    assert!(b.try_map(|s| { calls += 1; s.parse::<i32>() }).is_err());
+    assert_eq!(calls, 2);
+}
+
+#[test]
+fn array_try_map_error_drops() {
+    struct DropCounter<'a>(&'a Cell<usize>);
+    impl<'a> Drop for DropCounter<'a> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    let drops = Cell::new(0);
+    {
+        let a = [DropCounter(&drops), DropCounter(&drops), DropCounter(&drops)];
+        let mut calls = 0;
+        let result = a.try_map(|d| {
+            calls += 1;
+            if calls == 2 { Err(()) } else { Ok(d) }
+        });
+        assert!(result.is_err());
+    }
+    assert_eq!(drops.get(), 3);
+}
+
+#[test]
+fn array_try_from_fn() {
+    let a: Result<[usize; 3], ()> = array::try_from_fn(|i| Ok(i + 1));
+    assert_eq!(a, Ok([1, 2, 3]));
+
+    let b: Result<[usize; 3], usize> = array::try_from_fn(|i| if i == 1 { Err(i) } else { Ok(i) });
+    assert_eq!(b, Err(1));
+
+    let c: Result<[String; 0], ()> = array::try_from_fn(|_| Err(()));
+    assert_eq!(c, Ok([]));
+}
+
+#[test]
+fn array_each_ref() {
+    let a = [String::from("a"), String::from("b")];
+    let refs = a.each_ref();
+    assert!(refs[0] as *const String == &a[0] as *const String);
+    assert!(refs[1] as *const String == &a[1] as *const String);
+
+    let mut b = [1, 2, 3];
+    {
+        let muts = b.each_mut();
+        for r in muts.iter() {
+            assert!(**r > 0);
+        }
+        let [x, _, z] = muts;
+        ::std::mem::swap(x, z);
+    }
+    assert_eq!(b, [3, 2, 1]);
+
+    let mut empty: [String; 0] = [];
+    assert_eq!(empty.each_ref().len(), 0);
+    assert_eq!(empty.each_mut().len(), 0);
+}
+
+#[test]
+fn array_into_iter() {
+    let mut it = IntoIter::new([String::from("a"), String::from("b"), String::from("c")]);
+    assert_eq!(it.len(), 3);
+    assert_eq!(it.next(), Some(String::from("a")));
+    assert_eq!(it.next_back(), Some(String::from("c")));
+    assert_eq!(it.as_slice(), [String::from("b")]);
+
+    let clone = it.clone();
+    assert_eq!(it.next(), Some(String::from("b")));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next_back(), None);
+    assert_eq!(clone.collect::<Vec<_>>(), [String::from("b")]);
+
+    let empty: [String; 0] = [];
+    assert_eq!(IntoIter::new(empty).next(), None);
+
+    assert_eq!(IntoIter::new([1, 2, 3]).rev().collect::<Vec<_>>(), [3, 2, 1]);
+    assert_eq!(format!("{:?}", IntoIter::new([1, 2])), "IntoIter([1, 2])");
+}
+
+#[test]
+fn array_into_iter_drops() {
+    struct DropCounter<'a>(&'a Cell<usize>);
+    impl<'a> Drop for DropCounter<'a> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    let drops = Cell::new(0);
+    {
+        let mut it = IntoIter::new([DropCounter(&drops), DropCounter(&drops),
+                                    DropCounter(&drops), DropCounter(&drops)]);
+        drop(it.next());
+        assert_eq!(drops.get(), 1);
+        let last = it.next_back();
+        assert_eq!(drops.get(), 1);
+        drop(last);
+    }
+    assert_eq!(drops.get(), 4);
+}
+
+#[test]
+fn array_split_concat() {
+    let a = [String::from("a"), String::from("b"), String::from("c")];
+    let (left, right): ([String; 1], [String; 2]) = array::split_array(a);
+    assert_eq!(left, [String::from("a")]);
+    assert_eq!(right, [String::from("b"), String::from("c")]);
+
+    let joined: [String; 3] = array::concat_arrays(right, left);
+    assert_eq!(joined, [String::from("b"), String::from("c"), String::from("a")]);
+
+    let (empty, all): ([u8; 0], [u8; 2]) = array::split_array([1, 2]);
+    assert_eq!(empty, []);
+    assert_eq!(all, [1, 2]);
+
+    let units: [(); 5] = array::concat_arrays([(); 2], [(); 3]);
+    assert_eq!(units.len(), 5);
+}
+
+#[test]
+#[should_panic]
+fn array_split_bad_lengths() {
+    let _: ([u8; 2], [u8; 2]) = array::split_array([1, 2, 3]);
+}
+
+#[test]
+#[should_panic]
+fn array_concat_bad_lengths() {
+    let _: [u8; 4] = array::concat_arrays([1, 2], [3]);
+}
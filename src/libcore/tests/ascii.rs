@@ -0,0 +1,54 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::ascii::Char;
+
+#[test]
+fn test_char_from_u8() {
+    for b in 0..128u8 {
+        let c = Char::from_u8(b).unwrap();
+        assert_eq!(c.to_u8(), b);
+        assert_eq!(c.to_char(), b as char);
+        assert_eq!(u8::from(c), b);
+        assert_eq!(char::from(c), b as char);
+        assert_eq!(b.as_ascii(), Some(c));
+        assert_eq!((b as char).as_ascii(), Some(c));
+        assert_eq!(c.as_str().as_bytes(), &[b]);
+    }
+    for b in 128..256u32 {
+        assert_eq!(Char::from_u8(b as u8), None);
+        assert_eq!((b as u8).as_ascii(), None);
+        assert_eq!(::std::char::from_u32(b).unwrap().as_ascii(), None);
+    }
+    assert_eq!('\u{10FFFF}'.as_ascii(), None);
+}
+
+#[test]
+fn test_str_as_ascii() {
+    let s = "Host: example.com\r\n";
+    let ascii = s.as_ascii().unwrap();
+    assert_eq!(ascii.len(), s.len());
+    assert_eq!(ascii[0].to_char(), 'H');
+    assert_eq!(Char::slice_as_str(ascii), s);
+    assert_eq!(Char::slice_as_bytes(ascii), s.as_bytes());
+
+    assert_eq!("".as_ascii().map(|s| s.len()), Some(0));
+    assert_eq!("caf\u{e9}".as_ascii(), None);
+    assert_eq!("\u{7f}".as_ascii().map(Char::slice_as_str), Some("\u{7f}"));
+}
+
+#[test]
+fn test_char_fmt() {
+    let a = Char::from_u8(b'a').unwrap();
+    let nl = Char::from_u8(b'\n').unwrap();
+    assert_eq!(format!("{}", a), "a");
+    assert_eq!(format!("{:?}", a), "'a'");
+    assert_eq!(format!("{:?}", nl), "'\\n'");
+}
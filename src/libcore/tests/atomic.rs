@@ -83,6 +83,57 @@ fn int_xor() {
     assert_eq!(x.load(SeqCst), 0xf731 ^ 0x137f);
 }
 
+#[test]
+fn int_min_max() {
+    let x = AtomicIsize::new(-3);
+    assert_eq!(x.fetch_max(-5, SeqCst), -3);
+    assert_eq!(x.fetch_max(7, SeqCst), -3);
+    assert_eq!(x.fetch_min(-2, SeqCst), 7);
+    assert_eq!(x.load(SeqCst), -2);
+}
+
+#[test]
+fn uint_min_max() {
+    // The unsigned variants must not compare as signed.
+    let x = AtomicUsize::new(1);
+    assert_eq!(x.fetch_max(usize::max_value(), SeqCst), 1);
+    assert_eq!(x.fetch_min(2, SeqCst), usize::max_value());
+    assert_eq!(x.load(SeqCst), 2);
+}
+
+#[test]
+fn int_fetch_update() {
+    let x = AtomicUsize::new(7);
+    assert_eq!(x.fetch_update(SeqCst, SeqCst, |_| None), Err(7));
+    assert_eq!(x.fetch_update(SeqCst, SeqCst, |x| x.checked_sub(7)), Ok(7));
+    assert_eq!(x.fetch_update(SeqCst, SeqCst, |x| x.checked_sub(1)), Err(0));
+    assert_eq!(x.load(SeqCst), 0);
+}
+
+#[test]
+#[cfg(target_has_atomic = "128")]
+fn u128_ops() {
+    use core::mem;
+
+    assert_eq!(mem::align_of::<AtomicU128>(), 16);
+
+    let seq = 1u128 << 64;
+    let x = AtomicU128::new(seq | 0xf731);
+    assert_eq!(x.fetch_add(seq, SeqCst), seq | 0xf731);
+    assert_eq!(x.load(SeqCst), (2 << 64) | 0xf731);
+    assert_eq!(x.compare_exchange(seq, 0, SeqCst, SeqCst), Err((2 << 64) | 0xf731));
+    assert_eq!(x.compare_exchange((2 << 64) | 0xf731, u128::max_value(), SeqCst, SeqCst),
+               Ok((2 << 64) | 0xf731));
+    x.store(3, SeqCst);
+    assert_eq!(x.fetch_sub(4, SeqCst), 3);
+    assert_eq!(x.load(SeqCst), u128::max_value());
+
+    let y = AtomicI128::new(-1);
+    assert_eq!(y.fetch_and(0x137f, SeqCst), -1);
+    assert_eq!(y.swap(i128::min_value(), SeqCst), 0x137f);
+    assert_eq!(y.into_inner(), i128::min_value());
+}
+
 static S_FALSE: AtomicBool = AtomicBool::new(false);
 static S_TRUE: AtomicBool = AtomicBool::new(true);
 static S_INT: AtomicIsize  = AtomicIsize::new(0);
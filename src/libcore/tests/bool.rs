@@ -0,0 +1,31 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[test]
+fn test_bool_to_option() {
+    assert_eq!(false.then_some(0), None);
+    assert_eq!(true.then_some(0), Some(0));
+    assert_eq!(false.then(|| 0), None);
+    assert_eq!(true.then(|| 0), Some(0));
+
+    let mut called = false;
+    assert_eq!(false.then(|| called = true), None);
+    assert!(!called);
+}
+
+#[test]
+fn test_bool_to_option_chaining() {
+    fn first_even(v: &[i32]) -> Option<i32> {
+        v.first().and_then(|&x| (x % 2 == 0).then_some(x))
+    }
+    assert_eq!(first_even(&[2, 3]), Some(2));
+    assert_eq!(first_even(&[3, 2]), None);
+    assert_eq!(first_even(&[]), None);
+}
@@ -287,3 +287,138 @@ fn refcell_ref_coercion() {
         assert_eq!(&*coerced, comp);
     }
 }
+
+#[test]
+fn cell_update() {
+    let x = Cell::new(10);
+
+    assert_eq!(x.update(|x| x + 5), 15);
+    assert_eq!(x.get(), 15);
+
+    assert_eq!(x.update(|x| x / 3), 5);
+    assert_eq!(x.get(), 5);
+}
+
+#[test]
+fn as_slice_of_cells() {
+    let c = Cell::new([1, 2, 3]);
+    {
+        let slice: &Cell<[i32]> = &c;
+        let cells = slice.as_slice_of_cells();
+        assert_eq!(cells.len(), 3);
+        cells[0].set(cells[2].get());
+        cells[2].update(|x| x * 10);
+    }
+    assert_eq!(c.get(), [3, 2, 30]);
+}
+
+#[test]
+fn refcell_replace_with() {
+    let x = RefCell::new(vec![1, 2]);
+    assert_eq!(x.replace_with(|v| { v.push(3); vec![4] }), [1, 2, 3]);
+    assert_eq!(*x.borrow(), [4]);
+}
+
+#[test]
+#[should_panic]
+fn refcell_replace_with_borrowed() {
+    let x = RefCell::new(0);
+    let _b = x.borrow();
+    x.replace_with(|&mut v| v + 1);
+}
+
+#[test]
+fn refcell_take() {
+    let x = RefCell::new(String::from("hello"));
+    assert_eq!(x.take(), "hello");
+    assert_eq!(*x.borrow(), "");
+}
+
+#[test]
+#[should_panic]
+fn refcell_take_borrowed() {
+    let x = RefCell::new(5);
+    let _b = x.borrow_mut();
+    x.take();
+}
+
+#[test]
+fn once_cell() {
+    let c = OnceCell::new();
+    assert!(c.get().is_none());
+    assert_eq!(c.get_or_init(|| 92), &92);
+    assert_eq!(c.get_or_init(|| panic!("called twice")), &92);
+    assert_eq!(c.set(62), Err(62));
+    assert_eq!(c.get(), Some(&92));
+}
+
+#[test]
+fn once_cell_get_or_try_init() {
+    let c: OnceCell<i32> = OnceCell::new();
+    assert_eq!(c.get_or_try_init(|| Err("nope")), Err("nope"));
+    assert!(c.get().is_none());
+    assert_eq!(c.get_or_try_init(|| Ok::<_, ()>(1)), Ok(&1));
+    assert_eq!(c.get_or_try_init(|| Err(())), Ok(&1));
+}
+
+#[test]
+fn once_cell_take_and_get_mut() {
+    let mut c = OnceCell::from(String::from("hello"));
+    c.get_mut().unwrap().push_str(", world");
+    assert_eq!(c.take(), Some(String::from("hello, world")));
+    assert_eq!(c.take(), None);
+    assert!(c.set(String::from("again")).is_ok());
+    assert_eq!(c.into_inner(), Some(String::from("again")));
+}
+
+#[test]
+fn once_cell_drop() {
+    struct Dropper<'a>(&'a Cell<usize>);
+    impl<'a> Drop for Dropper<'a> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    let drops = Cell::new(0);
+    {
+        let c = OnceCell::new();
+        c.get_or_init(|| Dropper(&drops));
+        assert_eq!(drops.get(), 0);
+    }
+    assert_eq!(drops.get(), 1);
+}
+
+#[test]
+#[should_panic(expected = "reentrant init")]
+fn once_cell_reentrant_init() {
+    let c = OnceCell::new();
+    c.get_or_init(|| {
+        c.set(1).unwrap();
+        2
+    });
+}
+
+#[test]
+fn once_cell_debug() {
+    let c = OnceCell::new();
+    assert_eq!(format!("{:?}", c), "OnceCell(<uninit>)");
+    c.set(1).unwrap();
+    assert_eq!(format!("{:?}", c), "OnceCell(1)");
+}
+
+#[test]
+fn lazy_cell() {
+    let calls = Cell::new(0);
+    let lazy = LazyCell::new(|| {
+        calls.set(calls.get() + 1);
+        92
+    });
+    assert_eq!(calls.get(), 0);
+    assert_eq!(*lazy, 92);
+    assert_eq!(*LazyCell::force(&lazy), 92);
+    assert_eq!(calls.get(), 1);
+
+    let default: LazyCell<Vec<i32>> = LazyCell::default();
+    assert!(default.is_empty());
+}
@@ -82,6 +82,65 @@ fn test_to_digit() {
     assert_eq!('Z'.to_digit(36), Some(35));
     assert_eq!(' '.to_digit(10), None);
     assert_eq!('$'.to_digit(36), None);
+
+    assert_eq!('Z'.to_digit(37), Some(35));
+    assert_eq!('a'.to_digit(37), Some(36));
+    assert_eq!('b'.to_digit(37), None);
+    assert_eq!('A'.to_digit(62), Some(10));
+    assert_eq!('z'.to_digit(62), Some(61));
+    assert_eq!('z'.to_digit(61), None);
+}
+
+#[test]
+fn test_to_digit_all_radices() {
+    fn naive(c: char, radix: u32) -> Option<u32> {
+        let val = match c {
+            '0' ... '9' => c as u32 - '0' as u32,
+            'a' ... 'z' => c as u32 - 'a' as u32 + 10,
+            'A' ... 'Z' => c as u32 - 'A' as u32 + 10,
+            _ => return None,
+        };
+        if val < radix { Some(val) } else { None }
+    }
+
+    let chars = (0..0x250).chain(0x1_0000..0x1_0080).filter_map(char::from_u32);
+    for c in chars {
+        for radix in 2..37 {
+            assert_eq!(c.to_digit(radix), naive(c, radix), "{:?} in radix {}", c, radix);
+        }
+    }
+}
+
+#[test]
+fn test_from_digit() {
+    assert_eq!(char::from_digit(0, 10), Some('0'));
+    assert_eq!(char::from_digit(9, 10), Some('9'));
+    assert_eq!(char::from_digit(10, 10), None);
+    assert_eq!(char::from_digit(10, 16), Some('a'));
+    assert_eq!(char::from_digit(35, 36), Some('z'));
+    assert_eq!(char::from_digit(35, 37), Some('Z'));
+    assert_eq!(char::from_digit(36, 37), Some('a'));
+    assert_eq!(char::from_digit(61, 62), Some('z'));
+    assert_eq!(char::from_digit(62, 62), None);
+
+    for radix in 2..63 {
+        for num in 0..radix {
+            let c = char::from_digit(num, radix).unwrap();
+            assert_eq!(c.to_digit(radix), Some(num));
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_from_digit_radix_too_high() {
+    char::from_digit(0, 63);
+}
+
+#[test]
+#[should_panic]
+fn test_to_digit_radix_too_high() {
+    '0'.to_digit(63);
 }
 
 #[test]
@@ -265,6 +324,48 @@ fn test_encode_utf16() {
     check('\u{1f4a9}', &[0xd83d, 0xdca9]);
 }
 
+#[test]
+fn test_try_encode_utf8() {
+    fn check(input: char, expect: &[u8]) {
+        for len in 0..5 {
+            let mut buf = [0; 4];
+            let result = input.try_encode_utf8(&mut buf[..len]).map(|s| s.as_bytes().to_vec());
+            if len < expect.len() {
+                assert_eq!(result, None);
+                assert_eq!(buf, [0; 4]);
+            } else {
+                assert_eq!(result, Some(expect.to_vec()));
+            }
+        }
+    }
+
+    check('x', &[0x78]);
+    check('\u{e9}', &[0xc3, 0xa9]);
+    check('\u{a66e}', &[0xea, 0x99, 0xae]);
+    check('\u{1f4a9}', &[0xf0, 0x9f, 0x92, 0xa9]);
+}
+
+#[test]
+fn test_try_encode_utf16() {
+    fn check(input: char, expect: &[u16]) {
+        for len in 0..3 {
+            let mut buf = [0; 2];
+            let result = input.try_encode_utf16(&mut buf[..len]).map(|s| s.to_vec());
+            if len < expect.len() {
+                assert_eq!(result, None);
+                assert_eq!(buf, [0; 2]);
+            } else {
+                assert_eq!(result, Some(expect.to_vec()));
+            }
+        }
+    }
+
+    check('x', &[0x0078]);
+    check('\u{e9}', &[0x00e9]);
+    check('\u{a66e}', &[0xa66e]);
+    check('\u{1f4a9}', &[0xd83d, 0xdca9]);
+}
+
 #[test]
 fn test_len_utf16() {
     assert!('x'.len_utf16() == 1);
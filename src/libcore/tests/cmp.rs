@@ -8,6 +8,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use core::cmp;
 use core::cmp::Ordering::{Less, Greater, Equal};
 
 #[test]
@@ -38,6 +39,58 @@ fn test_ord_max_min() {
     assert_eq!(1.min(1), 1);
 }
 
+#[test]
+fn test_min_max_by() {
+    let f = |x: &i32, y: &i32| x.abs().cmp(&y.abs());
+    assert_eq!(cmp::min_by(1, -1, &f), 1);
+    assert_eq!(cmp::min_by(1, -2, &f), 1);
+    assert_eq!(cmp::min_by(2, -1, &f), -1);
+    assert_eq!(cmp::max_by(1, -1, &f), -1);
+    assert_eq!(cmp::max_by(1, -2, &f), -2);
+    assert_eq!(cmp::max_by(2, -1, &f), 2);
+}
+
+#[test]
+fn test_min_max_by_key() {
+    let f = |x: &i32| x.abs();
+    assert_eq!(cmp::min_by_key(1, -1, &f), 1);
+    assert_eq!(cmp::min_by_key(1, -2, &f), 1);
+    assert_eq!(cmp::min_by_key(2, -1, &f), -1);
+    assert_eq!(cmp::max_by_key(1, -1, &f), -1);
+    assert_eq!(cmp::max_by_key(1, -2, &f), -2);
+    assert_eq!(cmp::max_by_key(2, -1, &f), 2);
+}
+
+#[test]
+fn test_minmax() {
+    assert_eq!(cmp::minmax(1, 2), [1, 2]);
+    assert_eq!(cmp::minmax(2, 1), [1, 2]);
+
+    // Equal elements keep their order, agreeing with `min` and `max`.
+    let f = |x: &i32| x.abs();
+    assert_eq!(cmp::minmax_by_key(1, -1, &f), [1, -1]);
+    assert_eq!(cmp::minmax_by_key(-2, 1, &f), [1, -2]);
+    assert_eq!(cmp::minmax_by(-1, 1, |x: &i32, y: &i32| x.abs().cmp(&y.abs())), [-1, 1]);
+    assert_eq!(cmp::minmax_by(3, -2, |x: &i32, y: &i32| x.abs().cmp(&y.abs())), [-2, 3]);
+}
+
+#[test]
+fn test_clamp() {
+    assert_eq!(cmp::clamp(1, 2, 4), 2);
+    assert_eq!(cmp::clamp(3, 2, 4), 3);
+    assert_eq!(cmp::clamp(5, 2, 4), 4);
+    assert_eq!(cmp::clamp(2, 2, 2), 2);
+    assert_eq!(cmp::clamp("b", "a", "c"), "b");
+    assert_eq!(cmp::clamp(Some(9), None, Some(3)), Some(3));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn test_clamp_inverted_bounds() {
+    cmp::clamp(1, 4, 2);
+}
+
 #[test]
 fn test_ordering_reverse() {
     assert_eq!(Less.reverse(), Greater);
@@ -45,6 +98,17 @@ fn test_ordering_reverse() {
     assert_eq!(Greater.reverse(), Less);
 }
 
+#[test]
+fn test_ordering_predicates() {
+    assert!(Less.is_lt() && Less.is_le() && Less.is_ne());
+    assert!(!Less.is_eq() && !Less.is_gt() && !Less.is_ge());
+    assert!(Equal.is_eq() && Equal.is_le() && Equal.is_ge());
+    assert!(!Equal.is_ne() && !Equal.is_lt() && !Equal.is_gt());
+    assert!(Greater.is_gt() && Greater.is_ge() && Greater.is_ne());
+    assert!(!Greater.is_eq() && !Greater.is_lt() && !Greater.is_le());
+    assert!(1.cmp(&2).is_le());
+}
+
 #[test]
 fn test_ordering_order() {
     assert!(Less < Equal);
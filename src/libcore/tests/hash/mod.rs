@@ -109,3 +109,32 @@ fn test_custom_state() {
 
     assert_eq!(hash(&Custom { hash: 5 }), 5);
 }
+
+// Records which methods were called, to check that `str` and slices go
+// through the dedicated prefix-free methods.
+#[derive(Default)]
+struct PrefixHasher { calls: Vec<String> }
+
+impl Hasher for PrefixHasher {
+    fn finish(&self) -> u64 { 0 }
+    fn write(&mut self, bytes: &[u8]) { self.calls.push(format!("write({:?})", bytes)); }
+    fn write_length_prefix(&mut self, len: usize) {
+        self.calls.push(format!("length({})", len));
+    }
+    fn write_str(&mut self, s: &str) { self.calls.push(format!("str({})", s)); }
+}
+
+#[test]
+fn test_prefix_free_extras() {
+    let mut h = PrefixHasher::default();
+    "ab".hash(&mut h);
+    [1u8, 2].hash(&mut h);
+    (&[] as &[u8]).hash(&mut h);
+    assert_eq!(h.calls, ["str(ab)", "length(2)", "write([1, 2])", "length(0)", "write([])"]);
+
+    // The defaults are what `str` and slices used to write.
+    let mut a = MyHasher { hash: 0 };
+    a.write_str("a");
+    a.write_length_prefix(3);
+    assert_eq!(a.finish(), 97 + 0xFF + 3);
+}
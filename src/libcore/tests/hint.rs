@@ -0,0 +1,27 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::hint::{black_box, spin_loop};
+
+#[test]
+fn test_black_box() {
+    assert_eq!(black_box(5), 5);
+    assert_eq!(black_box("five"), "five");
+
+    let v = vec![1, 2, 3];
+    assert_eq!(black_box(v), [1, 2, 3]);
+}
+
+#[test]
+fn test_spin_loop() {
+    for _ in 0..10 {
+        spin_loop();
+    }
+}
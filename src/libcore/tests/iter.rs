@@ -8,6 +8,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use core::cell::Cell;
 use core::iter::*;
 use core::{i8, i16, isize};
 use core::usize;
@@ -691,6 +692,77 @@ fn test_iterator_nth() {
     assert_eq!(v.iter().nth(v.len()), None);
 }
 
+#[test]
+fn test_iterator_nth_back() {
+    let v: &[_] = &[0, 1, 2, 3, 4];
+    for i in 0..v.len() {
+        assert_eq!(v.iter().rev().nth(i).unwrap(), &v[v.len() - 1 - i]);
+        assert_eq!(v.iter().rev().nth_back(i).unwrap(), &v[i]);
+    }
+    assert_eq!(v.iter().rev().nth(v.len()), None);
+
+    let mut it = v.iter().map(|&x| x).filter(|&x| x % 2 == 0);
+    assert_eq!(it.nth_back(1), Some(2));
+    assert_eq!(it.nth_back(1), None);
+}
+
+#[test]
+fn test_try_reduce() {
+    let v: Vec<u8> = vec![10, 20, 5, 23, 0];
+    assert_eq!(v.iter().cloned().try_reduce(|x, y| x.checked_add(y).ok_or(())), Ok(Some(58)));
+
+    let v: Vec<u8> = vec![100, 200, 5, 23, 0];
+    let mut it = v.iter().cloned();
+    assert_eq!(it.try_reduce(|x, y| x.checked_add(y).ok_or(y)), Err(200));
+    // the iterator stops right after the failing element
+    assert_eq!(it.next(), Some(5));
+
+    let v: Vec<u8> = Vec::new();
+    assert_eq!(v.iter().cloned().try_reduce(|x, y| x.checked_add(y).ok_or(())), Ok(None));
+}
+
+#[test]
+fn test_try_find() {
+    let xs: &[isize] = &[];
+    assert_eq!(xs.iter().try_find(testfn), Ok(None));
+    let xs: &[isize] = &[1, 2, 3, 4];
+    assert_eq!(xs.iter().try_find(testfn), Ok(Some(&2)));
+    let xs: &[isize] = &[1, 3, 4];
+    assert_eq!(xs.iter().try_find(testfn), Err(()));
+
+    let xs: &[isize] = &[1, 2, 3, 4, 5, 6, 7];
+    let mut iter = xs.iter();
+    assert_eq!(iter.try_find(testfn), Ok(Some(&2)));
+    assert_eq!(iter.try_find(testfn), Err(()));
+    assert_eq!(iter.next(), Some(&5));
+
+    fn testfn(x: &&isize) -> Result<bool, ()> {
+        if **x == 2 {
+            return Ok(true);
+        }
+        if **x == 4 {
+            return Err(());
+        }
+        Ok(false)
+    }
+}
+
+#[test]
+fn test_zip_function() {
+    let xs = [0, 1, 2, 4, 5];
+    let ys = [10, 11, 12];
+
+    let it = zip(&xs, &ys);
+    assert_eq!(it.size_hint(), (3, Some(3)));
+    assert_eq!(it.map(|(&a, &b)| a + b).collect::<Vec<_>>(), [10, 12, 14]);
+
+    let mut it = zip(0..3, ys.iter().rev());
+    assert_eq!(it.next(), Some((0, &12)));
+    assert_eq!(it.next_back(), Some((2, &10)));
+    assert_eq!(it.next(), Some((1, &11)));
+    assert_eq!(it.next(), None);
+}
+
 #[test]
 fn test_iterator_last() {
     let v: &[_] = &[0, 1, 2, 3, 4];
@@ -847,6 +919,21 @@ fn test_max_by_key() {
     assert_eq!(*xs.iter().max_by_key(|x| x.abs()).unwrap(), -10);
 }
 
+#[test]
+fn test_is_sorted() {
+    assert!([1, 2, 2, 9].iter().is_sorted());
+    assert!(![1, 3, 2].iter().is_sorted());
+    assert!([0].iter().is_sorted());
+    assert!(empty::<i32>().is_sorted());
+    assert!(![0.0, 1.0, ::std::f32::NAN].iter().is_sorted());
+    assert!([-2, -1, 0, 3].iter().is_sorted());
+    assert!(![-2i32, -1, 0, 3].iter().is_sorted_by_key(|n| n.abs()));
+    assert!(!["c", "bb", "aaa"].iter().is_sorted());
+    assert!(["c", "bb", "aaa"].iter().is_sorted_by_key(|s| s.len()));
+    assert!((0..10).is_sorted_by(|a, b| a < b));
+    assert!(!(0..10).rev().is_sorted());
+}
+
 #[test]
 fn test_max_by() {
     let xs: &[isize] = &[-3, 0, 1, 5, -10];
@@ -901,6 +988,28 @@ fn test_cloned() {
     assert_eq!(it.next_back(), None);
 }
 
+#[test]
+fn test_copied() {
+    let xs = [2, 4, 6, 8];
+
+    let mut it = xs.iter().copied();
+    assert_eq!(it.len(), 4);
+    assert_eq!(it.next(), Some(2));
+    assert_eq!(it.len(), 3);
+    assert_eq!(it.next(), Some(4));
+    assert_eq!(it.len(), 2);
+    assert_eq!(it.next_back(), Some(8));
+    assert_eq!(it.len(), 1);
+    assert_eq!(it.next_back(), Some(6));
+    assert_eq!(it.len(), 0);
+    assert_eq!(it.next_back(), None);
+
+    assert_eq!(xs.iter().copied().nth(2), Some(6));
+    assert_eq!(xs.iter().copied().nth_back(2), Some(4));
+    assert_eq!(xs.iter().copied().fold(0, |acc, x| acc + x), 20);
+    assert_eq!(xs.iter().zip(xs.iter().copied()).map(|(&a, b)| a * b).sum::<i32>(), 120);
+}
+
 #[test]
 fn test_double_ended_map() {
     let xs = [1, 2, 3, 4, 5, 6];
@@ -1145,6 +1254,41 @@ fn test_range_inclusive_nth() {
     assert_eq!(r, 1...0);  // We may not want to document/promise this detail
 }
 
+#[test]
+fn test_range_nth_back() {
+    assert_eq!((10..15).nth_back(0), Some(14));
+    assert_eq!((10..15).nth_back(1), Some(13));
+    assert_eq!((10..15).nth_back(4), Some(10));
+    assert_eq!((10..15).nth_back(5), None);
+    assert_eq!((-120..80_i8).nth_back(199), Some(-120));
+
+    let mut r = 10..20;
+    assert_eq!(r.nth_back(2), Some(17));
+    assert_eq!(r, 10..17);
+    assert_eq!(r.nth_back(2), Some(14));
+    assert_eq!(r, 10..14);
+    assert_eq!(r.nth_back(10), None);
+    assert_eq!(r, 10..10);
+}
+
+#[test]
+fn test_range_inclusive_nth_back() {
+    assert_eq!((10...15).nth_back(0), Some(15));
+    assert_eq!((10...15).nth_back(1), Some(14));
+    assert_eq!((10...15).nth_back(5), Some(10));
+    assert_eq!((10...15).nth_back(6), None);
+
+    let mut r = 10_u8...20;
+    assert_eq!(r.nth_back(2), Some(18));
+    assert_eq!(r, 10...17);
+    assert_eq!(r.nth_back(2), Some(15));
+    assert_eq!(r, 10...14);
+    assert_eq!(r.is_empty(), false);
+    assert_eq!(r.nth_back(10), None);
+    assert_eq!(r.is_empty(), true);
+    assert_eq!(r, 1...0);  // We may not want to document/promise this detail
+}
+
 #[test]
 fn test_range_step() {
     #![allow(deprecated)]
@@ -1176,6 +1320,44 @@ fn test_repeat() {
     assert_eq!(it.next(), Some(42));
 }
 
+#[test]
+fn test_repeat_n() {
+    let mut it = repeat_n(42, 3);
+    assert_eq!(it.len(), 3);
+    assert_eq!(it.next(), Some(42));
+    assert_eq!(it.len(), 2);
+    assert_eq!(it.next_back(), Some(42));
+    assert_eq!(it.next(), Some(42));
+    assert_eq!(it.len(), 0);
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next_back(), None);
+
+    let mut it = repeat_n(42, 0);
+    assert_eq!(it.size_hint(), (0, Some(0)));
+    assert_eq!(it.next(), None);
+}
+
+#[test]
+fn test_repeat_n_moves_last_element() {
+    // Counts the clones made from the repeated value.
+    struct Tracked<'a>(&'a Cell<usize>);
+
+    impl<'a> Clone for Tracked<'a> {
+        fn clone(&self) -> Tracked<'a> {
+            self.0.set(self.0.get() + 1);
+            Tracked(self.0)
+        }
+    }
+
+    let clones = Cell::new(0);
+    assert_eq!(repeat_n(Tracked(&clones), 3).count(), 3);
+    assert_eq!(clones.get(), 2);
+
+    let clones = Cell::new(0);
+    assert_eq!(repeat_n(Tracked(&clones), 0).count(), 0);
+    assert_eq!(clones.get(), 0);
+}
+
 #[test]
 fn test_fuse() {
     let mut it = 0..3;
@@ -1238,6 +1420,46 @@ fn test_empty() {
     assert_eq!(it.next(), None);
 }
 
+#[test]
+fn test_once_with() {
+    let count = Cell::new(0);
+    let mut it = once_with(|| {
+        count.set(count.get() + 1);
+        42
+    });
+
+    assert_eq!(count.get(), 0);
+    assert_eq!(it.len(), 1);
+    assert_eq!(it.next(), Some(42));
+    assert_eq!(count.get(), 1);
+    assert_eq!(it.len(), 0);
+    assert_eq!(it.next(), None);
+    assert_eq!(count.get(), 1);
+}
+
+#[test]
+fn test_from_fn() {
+    let mut count = 0;
+    let it = from_fn(|| {
+        count += 1;
+        if count <= 3 { Some(count) } else { None }
+    });
+    assert_eq!(it.collect::<Vec<_>>(), [1, 2, 3]);
+}
+
+#[test]
+fn test_successors() {
+    let mut powers_of_10 = successors(Some(1_u16), |n| n.checked_mul(10));
+    assert_eq!(powers_of_10.size_hint(), (1, None));
+    assert_eq!(powers_of_10.by_ref().collect::<Vec<_>>(), [1, 10, 100, 1_000, 10_000]);
+    assert_eq!(powers_of_10.size_hint(), (0, Some(0)));
+    assert_eq!(powers_of_10.next(), None);
+
+    let mut empty = successors(None::<u32>, |_| unimplemented!());
+    assert_eq!(empty.next(), None);
+    assert_eq!(empty.next(), None);
+}
+
 #[test]
 fn test_chain_fold() {
     let xs = [1, 2, 3];
@@ -10,9 +10,29 @@
 
 #![deny(warnings)]
 
+#![feature(array_each_ref)]
+#![feature(array_from_fn)]
+#![feature(array_split)]
+#![feature(array_map)]
+#![feature(array_try_from_fn)]
+#![feature(array_try_map)]
+#![feature(array_value_iter)]
+#![feature(as_cell)]
+#![feature(ascii_char)]
+#![feature(assert_matches)]
+#![feature(atomic_fetch_update)]
+#![feature(atomic_min_max)]
+#![feature(bool_to_option)]
 #![feature(box_syntax)]
+#![feature(cell_update)]
+#![feature(cfg_target_has_atomic)]
+#![feature(clamp)]
+#![feature(cmp_min_max_by)]
+#![feature(cmp_minmax)]
 #![feature(const_fn)]
+#![feature(control_flow_enum)]
 #![feature(core_float)]
+#![feature(core_hint)]
 #![feature(core_private_bignum)]
 #![feature(core_private_diy_float)]
 #![feature(dec2flt)]
@@ -21,28 +41,76 @@
 #![feature(fixed_size_array)]
 #![feature(flt2dec)]
 #![feature(fmt_internals)]
+#![feature(hasher_prefixfree_extras)]
+#![feature(hint_black_box)]
+#![feature(hint_spin_loop)]
 #![feature(iterator_step_by)]
 #![feature(i128_type)]
 #![feature(inclusive_range)]
 #![feature(inclusive_range_syntax)]
+#![feature(ip_in_core)]
+#![feature(is_some_and)]
+#![feature(is_sorted)]
+#![feature(iter_copied)]
+#![feature(iter_from_fn)]
+#![feature(iter_nth_back)]
+#![feature(iter_once_with)]
+#![feature(iter_repeat_n)]
 #![feature(iter_rfind)]
+#![feature(iter_successors)]
+#![feature(iter_zip)]
+#![feature(iterator_try_reduce)]
 #![feature(libc)]
+#![feature(matches_macro)]
+#![feature(mem_take)]
+#![feature(never_type)]
 #![feature(nonzero)]
+#![feature(once_cell)]
+#![feature(option_deref)]
+#![feature(option_flattening)]
+#![feature(option_insert)]
+#![feature(option_result_unwrap_unchecked)]
+#![feature(option_xor)]
+#![feature(option_zip)]
 #![feature(ord_max_min)]
+#![feature(ordering_helpers)]
+#![feature(partition_point)]
+#![feature(pointer_is_aligned)]
+#![feature(portable_simd)]
 #![feature(rand)]
 #![feature(raw)]
+#![feature(refcell_replace_with)]
+#![feature(refcell_take)]
+#![feature(result_flattening)]
+#![feature(shared)]
 #![feature(sip_hash_13)]
+#![feature(slice_align_to)]
+#![feature(slice_from_raw_parts)]
 #![feature(slice_patterns)]
+#![feature(slice_ptr_range)]
 #![feature(slice_rotate)]
+#![feature(slice_select_nth_unstable)]
+#![feature(slice_split_at_unchecked)]
+#![feature(slice_split_once)]
+#![feature(slice_strip)]
+#![feature(slice_swap_unchecked)]
 #![feature(sort_internals)]
 #![feature(specialization)]
 #![feature(step_trait)]
+#![feature(strict_provenance)]
 #![feature(test)]
+#![feature(todo_macro)]
 #![feature(trusted_len)]
+#![feature(try_encode_char)]
+#![feature(try_find)]
 #![feature(try_from)]
 #![feature(unicode)]
 #![feature(unique)]
+#![feature(unwrap_infallible)]
+#![feature(unzip_option)]
+#![cfg_attr(target_has_atomic = "128", feature(integer_atomics))]
 
+#[macro_use(matches, assert_matches, todo)]
 extern crate core;
 extern crate test;
 extern crate libc;
@@ -51,22 +119,28 @@ extern crate rand;
 
 mod any;
 mod array;
+mod ascii;
 mod atomic;
+mod bool;
 mod cell;
 mod char;
 mod clone;
 mod cmp;
 mod fmt;
 mod hash;
+mod hint;
 mod intrinsics;
+mod macros;
 mod iter;
 mod mem;
+mod net;
 mod nonzero;
 mod num;
 mod ops;
 mod option;
 mod ptr;
 mod result;
+mod simd;
 mod slice;
 mod str;
 mod tuple;
@@ -0,0 +1,37 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[test]
+fn test_matches() {
+    assert!(matches!(Some(3), Some(_)));
+    assert!(!matches!(None::<i32>, Some(_)));
+    assert!(matches!('b', 'a' | 'b' | 'c'));
+    assert!(matches!(Some(3), Some(x) if x > 2));
+    assert!(!matches!(Some(1), Some(x) if x > 2));
+}
+
+#[test]
+fn test_assert_matches() {
+    assert_matches!(Ok::<i32, ()>(5), Ok(_));
+    assert_matches!(7, 1...9 if true);
+    assert_matches!(Some("x"), Some(s) if s.len() == 1, "with a {}", "message");
+}
+
+#[test]
+#[should_panic(expected = "assertion failed: `(left matches right)`")]
+fn test_assert_matches_fail() {
+    assert_matches!(Some(1), None);
+}
+
+#[test]
+#[should_panic(expected = "not yet implemented: later")]
+fn test_todo() {
+    todo!("later");
+}
@@ -99,6 +99,18 @@ fn test_replace() {
     assert!(y.is_some());
 }
 
+#[test]
+fn test_take() {
+    let mut x = Some("test".to_string());
+    let y = take(&mut x);
+    assert!(x.is_none());
+    assert_eq!(y, Some("test".to_string()));
+
+    let mut v = vec![1, 2, 3];
+    assert_eq!(take(&mut v), [1, 2, 3]);
+    assert!(v.is_empty());
+}
+
 #[test]
 fn test_transmute_copy() {
     assert_eq!(1, unsafe { transmute_copy(&1) });
@@ -0,0 +1,53 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+#[test]
+fn test_ipv4_octets() {
+    let ip = Ipv4Addr::new(192, 168, 0, 1);
+    assert_eq!(ip.octets(), [192, 168, 0, 1]);
+    assert_eq!(u32::from(ip), 0xc0a80001);
+    assert_eq!(Ipv4Addr::from(0xc0a80001), ip);
+    assert!(Ipv4Addr::new(10, 0, 0, 1) < Ipv4Addr::new(10, 0, 1, 0));
+    assert!(Ipv4Addr::new(0, 0, 0, 0).is_unspecified());
+}
+
+#[test]
+fn test_ipv6_segments() {
+    let ip = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1);
+    assert_eq!(ip.segments(), [0x2001, 0xdb8, 0, 0, 0, 0, 0, 1]);
+    assert_eq!(ip.octets()[..4], [0x20, 0x01, 0x0d, 0xb8]);
+    assert_eq!(Ipv6Addr::from(ip.octets()), ip);
+}
+
+#[test]
+fn test_parse_and_display() {
+    let ip: IpAddr = "2001:db8::1".parse().unwrap();
+    assert_eq!(ip, IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1)));
+    assert_eq!(format!("{}", ip), "2001:db8::1");
+
+    let sa: SocketAddr = "127.0.0.1:8080".parse().unwrap();
+    assert_eq!(sa, SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::new(127, 0, 0, 1), 8080)));
+    assert_eq!(format!("{}", sa), "127.0.0.1:8080");
+
+    assert!("1.2.3".parse::<Ipv4Addr>().is_err());
+}
+
+#[test]
+fn test_socket_addr_setters() {
+    let mut sa = SocketAddrV6::new(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 1), 80, 1, 2);
+    sa.set_port(443);
+    sa.set_flowinfo(3);
+    sa.set_scope_id(4);
+    assert_eq!(sa.ip(), &Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 1));
+    assert_eq!((sa.port(), sa.flowinfo(), sa.scope_id()), (443, 3, 4));
+    assert_eq!(format!("{}", sa), "[::1]:443");
+}
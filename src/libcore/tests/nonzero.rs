@@ -25,6 +25,15 @@ fn test_size_nonzero_in_option() {
     assert_eq!(size_of::<NonZero<u32>>(), size_of::<Option<NonZero<u32>>>());
 }
 
+#[test]
+fn test_nonzero_sentinel_round_trip() {
+    for &x in &[0usize, 1, 7, usize::max_value()] {
+        assert_eq!(NonZero::new(x).map_or_zero(), x);
+    }
+    assert_eq!(None::<NonZero<u8>>.map_or_zero(), 0);
+    assert_eq!(NonZero::new(-3i64).map_or_zero(), -3);
+}
+
 #[test]
 fn test_match_on_nonzero_option() {
     let a = Some(unsafe {
@@ -9,6 +9,7 @@
 // except according to those terms.
 
 use core::ops::{Range, RangeFull, RangeFrom, RangeTo, RangeInclusive};
+use core::ops::ControlFlow;
 
 // Test the Range structs without the syntactic sugar.
 
@@ -67,4 +68,23 @@ fn test_range_inclusive() {
     r = RangeInclusive { start: 1, end: -1 };
     assert_eq!(r.size_hint(), (0, Some(0)));
     assert_eq!(r.next(), None);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_control_flow() {
+    fn first_even(v: &[i32]) -> ControlFlow<i32> {
+        for &x in v {
+            let step = if x % 2 == 0 { ControlFlow::Break(x) } else { ControlFlow::Continue(()) };
+            step?;
+        }
+        ControlFlow::Continue(())
+    }
+
+    assert_eq!(first_even(&[1, 3, 4, 6]), ControlFlow::Break(4));
+    assert_eq!(first_even(&[1, 3]), ControlFlow::Continue(()));
+    assert!(first_even(&[2]).is_break());
+    assert!(first_even(&[]).is_continue());
+    assert_eq!(first_even(&[5, 8]).break_value(), Some(8));
+    assert_eq!(ControlFlow::<(), _>::Continue(7).continue_value(), Some(7));
+    assert_eq!(ControlFlow::<_, ()>::Break(7).continue_value(), None);
+}
@@ -270,3 +270,76 @@ fn test_cloned() {
     assert_eq!(opt_ref_ref.clone().cloned(), Some(&val));
     assert_eq!(opt_ref_ref.cloned().cloned(), Some(1));
 }
+
+#[test]
+fn test_insert() {
+    let mut opt = None;
+    *opt.insert(1) += 1;
+    assert_eq!(opt, Some(2));
+    *opt.insert(5) += 1;
+    assert_eq!(opt, Some(6));
+}
+
+#[test]
+fn test_xor() {
+    assert_eq!(Some(1).xor(None), Some(1));
+    assert_eq!(None.xor(Some(2)), Some(2));
+    assert_eq!(Some(1).xor(Some(2)), None);
+    assert_eq!(None::<i32>.xor(None), None);
+}
+
+#[test]
+fn test_zip() {
+    assert_eq!(Some(1).zip(Some("a")), Some((1, "a")));
+    assert_eq!(Some(1).zip(None::<&str>), None);
+    assert_eq!(None::<i32>.zip(Some("a")), None);
+}
+
+#[test]
+fn test_unzip() {
+    assert_eq!(Some((1, "a")).unzip(), (Some(1), Some("a")));
+    assert_eq!(None::<(i32, &str)>.unzip(), (None, None));
+}
+
+#[test]
+fn test_is_some_and() {
+    assert!(Some(2).is_some_and(|x| x == 2));
+    assert!(!Some(2).is_some_and(|x| x == 3));
+    assert!(!None::<i32>.is_some_and(|_| true));
+}
+
+#[test]
+fn test_unwrap_unchecked() {
+    assert_eq!(unsafe { Some(1).unwrap_unchecked() }, 1);
+    let s = unsafe { Some("hello".to_string()).unwrap_unchecked() };
+    assert_eq!(s, "hello");
+}
+
+#[test]
+fn test_flatten() {
+    assert_eq!(Some(Some(1)).flatten(), Some(1));
+    assert_eq!(Some(None::<i32>).flatten(), None);
+    assert_eq!(None::<Option<i32>>.flatten(), None);
+}
+
+#[test]
+fn test_as_deref() {
+    let ref_option: Option<&i32> = Some(&42);
+    assert_eq!(ref_option.as_deref(), Some(&42));
+
+    let string_option: Option<String> = Some(String::from("a result"));
+    assert_eq!(string_option.as_deref(), Some("a result"));
+
+    let none: Option<String> = None;
+    assert_eq!(none.as_deref(), None);
+}
+
+#[test]
+fn test_as_deref_mut() {
+    let mut vec_option: Option<Vec<i32>> = Some(vec![1, 2, 3]);
+    vec_option.as_deref_mut().unwrap()[0] = 5;
+    assert_eq!(vec_option, Some(vec![5, 2, 3]));
+
+    let mut none: Option<Vec<i32>> = None;
+    assert_eq!(none.as_deref_mut(), None);
+}
@@ -10,6 +10,7 @@
 
 use core::ptr::*;
 use core::cell::RefCell;
+use core::mem;
 
 #[test]
 fn test() {
@@ -212,3 +213,91 @@ fn write_unaligned_drop() {
     }
     DROPS.with(|d| assert_eq!(*d.borrow(), [0]));
 }
+
+#[test]
+fn test_align_offset() {
+    let data = [0u64; 8];
+    let base = &data[0] as *const u64 as *const u8;
+    for i in 0..16 {
+        let p = base.wrapping_offset(i);
+        for &align in &[1, 2, 4, 8] {
+            let offset = p.align_offset(align);
+            assert!(offset < align);
+            assert!(p.wrapping_offset(offset as isize).is_aligned_to(align));
+        }
+    }
+
+    // Stepping by three bytes reaches every alignment eventually.
+    let p = base.wrapping_offset(1) as *const [u8; 3];
+    let offset = p.align_offset(8);
+    assert_eq!((p as usize + offset * 3) % 8, 0);
+    assert!(offset < 8);
+
+    // Stepping by two bytes from an odd address never reaches an even one.
+    let p = base.wrapping_offset(1) as *const u16;
+    assert_eq!(p.align_offset(2), usize::max_value());
+
+    // Zero-sized types can't move.
+    let p = base.wrapping_offset(1) as *const ();
+    assert_eq!(p.align_offset(2), usize::max_value());
+    assert_eq!((base.wrapping_offset(2) as *const ()).align_offset(2), 0);
+}
+
+#[test]
+fn test_is_aligned() {
+    let data = [0u32; 2];
+    let p = &data[0] as *const u32;
+    assert!(p.is_aligned());
+    assert!((p as *const u8).wrapping_offset(4).is_aligned_to(4));
+    assert!(!(p as *const u8).wrapping_offset(2).is_aligned_to(4));
+    assert!((p as *const u8).wrapping_offset(2).is_aligned_to(2));
+    assert!((p as *mut u8).wrapping_offset(3).is_aligned_to(1));
+}
+
+#[test]
+fn test_slice_from_raw_parts() {
+    let mut x = [1, 2, 3, 4];
+    let p = slice_from_raw_parts(x.as_ptr(), 2);
+    assert_eq!(unsafe { &*p }, &[1, 2]);
+    let p = slice_from_raw_parts_mut(x.as_mut_ptr(), 4);
+    unsafe { (*p)[3] = 5 };
+    assert_eq!(x, [1, 2, 3, 5]);
+}
+
+#[test]
+fn test_shared_slice() {
+    let mut x = [1, 2, 3];
+    let first = Shared::new(x.as_mut_ptr()).unwrap();
+    let slice = Shared::slice_from_raw_parts(first, 3);
+    assert_eq!(slice.len(), 3);
+    assert_eq!(slice.as_non_null_ptr().as_ptr(), x.as_mut_ptr());
+    assert_eq!(unsafe { slice.as_ref() }, &[1, 2, 3]);
+
+    let empty: Shared<[u64]> = Shared::slice_from_raw_parts(Shared::dangling(), 0);
+    assert_eq!(empty.len(), 0);
+    assert_eq!(empty.as_non_null_ptr().as_ptr() as usize, mem::align_of::<u64>());
+}
+
+#[test]
+fn test_strict_provenance() {
+    let mut x = [1u32, 2, 3, 4];
+    let p = &x[0] as *const u32;
+    assert_eq!(p.addr(), p as usize);
+
+    let tagged = p.map_addr(|a| a | 3);
+    assert_eq!(tagged.addr() & 3, 3);
+    let untagged = tagged.with_addr(tagged.addr() & !3);
+    assert_eq!(untagged, p);
+    assert_eq!(unsafe { *untagged }, 1);
+
+    let m = &mut x[0] as *mut u32;
+    let second = m.with_addr(m.addr() + 4);
+    unsafe { *second = 7 };
+    assert_eq!(x, [1, 7, 3, 4]);
+
+    let s: *const [u32] = &x[..];
+    assert_eq!(s.addr(), p.addr());
+
+    assert_eq!(invalid::<u64>(8).addr(), 8);
+    assert!(invalid_mut::<u8>(0).is_null());
+}
@@ -202,3 +202,41 @@ pub fn test_unwrap_or_default() {
     assert_eq!(op1().unwrap_or_default(), 666);
     assert_eq!(op2().unwrap_or_default(), 0);
 }
+
+#[test]
+fn test_is_ok_and_is_err_and() {
+    let ok: Result<i32, i32> = Ok(2);
+    let err: Result<i32, i32> = Err(2);
+    assert!(ok.is_ok_and(|x| x == 2));
+    assert!(!ok.is_ok_and(|x| x == 3));
+    assert!(!ok.is_err_and(|_| true));
+    assert!(err.is_err_and(|x| x == 2));
+    assert!(!err.is_err_and(|x| x == 3));
+    assert!(!err.is_ok_and(|_| true));
+}
+
+#[test]
+fn test_unwrap_unchecked() {
+    let ok: Result<isize, &'static str> = Ok(100);
+    let err: Result<isize, &'static str> = Err("Err");
+    assert_eq!(unsafe { ok.unwrap_unchecked() }, 100);
+    assert_eq!(unsafe { err.unwrap_err_unchecked() }, "Err");
+}
+
+#[test]
+fn test_flatten() {
+    let ok: Result<Result<i32, &str>, &str> = Ok(Ok(1));
+    let inner_err: Result<Result<i32, &str>, &str> = Ok(Err("inner"));
+    let outer_err: Result<Result<i32, &str>, &str> = Err("outer");
+    assert_eq!(ok.flatten(), Ok(1));
+    assert_eq!(inner_err.flatten(), Err("inner"));
+    assert_eq!(outer_err.flatten(), Err("outer"));
+}
+
+#[test]
+fn test_into_ok_into_err() {
+    let ok: Result<isize, !> = Ok(100);
+    assert_eq!(ok.into_ok(), 100);
+    let err: Result<!, &'static str> = Err("Err");
+    assert_eq!(err.into_err(), "Err");
+}
@@ -0,0 +1,131 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::simd::{f32x4, f64x2, i32x4, u8x16, u32x4, m32x4};
+
+#[test]
+fn test_lanes() {
+    let v = i32x4::new(1, 2, 3, 4);
+    assert_eq!(i32x4::LANES, 4);
+    assert_eq!(u8x16::LANES, 16);
+    assert_eq!(v.to_array(), [1, 2, 3, 4]);
+    assert_eq!(i32x4::from_array([1, 2, 3, 4]), v);
+    assert_eq!(i32x4::splat(7).to_array(), [7; 4]);
+    assert_eq!(i32x4::default(), i32x4::splat(0));
+    assert_eq!(v.extract(2), 3);
+    assert_eq!(v.replace(2, 9).to_array(), [1, 2, 9, 4]);
+}
+
+#[test]
+#[should_panic]
+fn test_extract_out_of_bounds() {
+    i32x4::splat(0).extract(4);
+}
+
+#[test]
+fn test_slices() {
+    let a = [1.0, 2.0, 3.0, 4.0, 5.0];
+    assert_eq!(f32x4::from_slice(&a[1..]), f32x4::new(2.0, 3.0, 4.0, 5.0));
+
+    let mut b = [0.0; 5];
+    f64x2::new(1.5, 2.5).write_to_slice(&mut b[3..]);
+    assert_eq!(b, [0.0, 0.0, 0.0, 1.5, 2.5]);
+}
+
+#[test]
+#[should_panic]
+fn test_from_slice_too_short() {
+    u32x4::from_slice(&[1, 2, 3]);
+}
+
+#[test]
+fn test_int_arithmetic() {
+    let a = i32x4::new(1, -2, 3, i32::max_value());
+    let b = i32x4::new(4, 5, -6, 1);
+    assert_eq!((a + b).to_array(), [5, 3, -3, i32::min_value()]);
+    assert_eq!((a - b).to_array(), [-3, -7, 9, i32::max_value() - 1]);
+    assert_eq!((a * b).to_array(), [4, -10, -18, i32::max_value()]);
+    assert_eq!((b / i32x4::splat(2)).to_array(), [2, 2, -3, 0]);
+    assert_eq!((-a).to_array(), [-1, 2, -3, -i32::max_value()]);
+    assert_eq!((a & b).to_array(), [1 & 4, -2 & 5, 3 & -6, i32::max_value() & 1]);
+    assert_eq!((!u32x4::splat(0)).to_array(), [u32::max_value(); 4]);
+
+    let mut c = u8x16::splat(250);
+    c += u8x16::splat(10);
+    assert_eq!(c, u8x16::splat(4));
+    assert_eq!(c.reduce_sum(), 64);
+}
+
+#[test]
+#[should_panic]
+fn test_int_div_by_zero() {
+    let _ = i32x4::splat(1) / i32x4::new(1, 1, 0, 1);
+}
+
+#[test]
+fn test_float_arithmetic() {
+    let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+    let b = f32x4::splat(2.0);
+    assert_eq!(a + b, f32x4::new(3.0, 4.0, 5.0, 6.0));
+    assert_eq!(a - b, f32x4::new(-1.0, 0.0, 1.0, 2.0));
+    assert_eq!(a * b, f32x4::new(2.0, 4.0, 6.0, 8.0));
+    assert_eq!(a / b, f32x4::new(0.5, 1.0, 1.5, 2.0));
+    assert_eq!(-a, f32x4::new(-1.0, -2.0, -3.0, -4.0));
+    assert_eq!(a.reduce_sum(), 10.0);
+}
+
+#[test]
+fn test_swizzle() {
+    let v = i32x4::new(10, 20, 30, 40);
+    assert_eq!(v.swizzle([3, 0, 0, 1]).to_array(), [40, 10, 10, 20]);
+    assert_eq!(v.reverse().to_array(), [40, 30, 20, 10]);
+}
+
+#[test]
+fn test_compare_select() {
+    let a = i32x4::new(1, 5, 3, 7);
+    let b = i32x4::new(4, 5, 2, 8);
+    assert_eq!(a.lanes_eq(b), m32x4::new(false, true, false, false));
+    assert_eq!(a.lanes_ne(b), m32x4::new(true, false, true, true));
+    assert_eq!(a.lanes_lt(b), m32x4::new(true, false, false, true));
+    assert_eq!(a.lanes_le(b), m32x4::new(true, true, false, true));
+    assert_eq!(a.lanes_gt(b), m32x4::new(false, false, true, false));
+    assert_eq!(a.lanes_ge(b), m32x4::new(false, true, true, false));
+
+    let max = i32x4::select(a.lanes_gt(b), a, b);
+    assert_eq!(max.to_array(), [4, 5, 3, 8]);
+
+    let nan = f32x4::new(1.0, ::std::f32::NAN, 3.0, 4.0);
+    assert_eq!(nan.lanes_eq(nan), m32x4::new(true, false, true, true));
+}
+
+#[test]
+fn test_masks() {
+    let m = m32x4::new(true, false, true, false);
+    assert!(m.test(0));
+    assert!(!m.test(1));
+    assert_eq!(m.to_array(), [true, false, true, false]);
+    assert_eq!(m.replace(1, true), m32x4::new(true, true, true, false));
+    assert!(m.any());
+    assert!(!m.all());
+    assert!(m32x4::splat(true).all());
+    assert!(!m32x4::default().any());
+    assert_eq!(!m, m32x4::new(false, true, false, true));
+    assert_eq!(m | !m, m32x4::splat(true));
+    assert_eq!(m & !m, m32x4::splat(false));
+    assert_eq!(m ^ m32x4::splat(true), !m);
+}
+
+#[test]
+fn test_debug() {
+    assert_eq!(format!("{:?}", i32x4::new(1, 2, 3, 4)), "i32x4(1, 2, 3, 4)");
+    assert_eq!(format!("{:?}", m32x4::new(true, false, true, false)),
+               "m32x4(true, false, true, false)");
+}
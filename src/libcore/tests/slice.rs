@@ -33,6 +33,150 @@ fn test_binary_search() {
     assert!(b.binary_search_by(|v| v.cmp(&9)) == Err(6));
 }
 
+#[test]
+fn test_partition_point() {
+    let b: [i32; 0] = [];
+    assert_eq!(b.partition_point(|&x| x < 5), 0);
+
+    let b = [4];
+    assert_eq!(b.partition_point(|&x| x < 3), 0);
+    assert_eq!(b.partition_point(|&x| x < 4), 0);
+    assert_eq!(b.partition_point(|&x| x < 5), 1);
+
+    let b = [1, 2, 4, 6, 8, 9];
+    assert_eq!(b.partition_point(|&x| x < 5), 3);
+    assert_eq!(b.partition_point(|&x| x < 6), 3);
+    assert_eq!(b.partition_point(|&x| x < 7), 4);
+    assert_eq!(b.partition_point(|&x| x < 8), 4);
+
+    let b = [1, 2, 2, 2, 2, 3, 3];
+    assert_eq!(b.partition_point(|&x| x < 2), 1);
+    assert_eq!(b.partition_point(|&x| x <= 2), 5);
+    assert_eq!(b.partition_point(|&x| x < 4), 7);
+}
+
+#[test]
+fn test_split_once() {
+    let v = &[1, 2, 3, 2, 4];
+    assert_eq!(v.split_once(|&x| x == 2), Some((&[1][..], &[3, 2, 4][..])));
+    assert_eq!(v.split_once(|&x| x == 4), Some((&[1, 2, 3, 2][..], &[][..])));
+    assert_eq!(v.split_once(|&x| x == 1), Some((&[][..], &[2, 3, 2, 4][..])));
+    assert_eq!(v.split_once(|&x| x == 0), None);
+}
+
+#[test]
+fn test_rsplit_once() {
+    let v = &[1, 2, 3, 2, 4];
+    assert_eq!(v.rsplit_once(|&x| x == 2), Some((&[1, 2, 3][..], &[4][..])));
+    assert_eq!(v.rsplit_once(|&x| x == 4), Some((&[1, 2, 3, 2][..], &[][..])));
+    assert_eq!(v.rsplit_once(|&x| x == 1), Some((&[][..], &[2, 3, 2, 4][..])));
+    assert_eq!(v.rsplit_once(|&x| x == 0), None);
+}
+
+#[test]
+fn test_strip_prefix() {
+    let v = &[10, 40, 30];
+    assert_eq!(v.strip_prefix(&[]), Some(&[10, 40, 30][..]));
+    assert_eq!(v.strip_prefix(&[10]), Some(&[40, 30][..]));
+    assert_eq!(v.strip_prefix(&[10, 40, 30]), Some(&[][..]));
+    assert_eq!(v.strip_prefix(&[10, 40, 30, 20]), None);
+    assert_eq!(v.strip_prefix(&[40]), None);
+}
+
+#[test]
+fn test_strip_suffix() {
+    let v = &[10, 40, 30];
+    assert_eq!(v.strip_suffix(&[]), Some(&[10, 40, 30][..]));
+    assert_eq!(v.strip_suffix(&[30]), Some(&[10, 40][..]));
+    assert_eq!(v.strip_suffix(&[10, 40, 30]), Some(&[][..]));
+    assert_eq!(v.strip_suffix(&[20, 10, 40, 30]), None);
+    assert_eq!(v.strip_suffix(&[40]), None);
+}
+
+#[test]
+fn test_swap_unchecked() {
+    let mut v = [1, 2, 3, 4];
+    unsafe {
+        v.swap_unchecked(0, 3);
+        v.swap_unchecked(1, 1);
+    }
+    assert_eq!(v, [4, 2, 3, 1]);
+}
+
+#[test]
+fn test_is_sorted() {
+    let empty: [i32; 0] = [];
+
+    assert!([1, 2, 2, 9].is_sorted());
+    assert!(![1, 3, 2].is_sorted());
+    assert!([0].is_sorted());
+    assert!(empty.is_sorted());
+    assert!(![0.0, 1.0, ::std::f32::NAN].is_sorted());
+    assert!([-2, -1, 0, 3].is_sorted());
+    assert!(![-2i32, -1, 0, 3].is_sorted_by_key(|n| n.abs()));
+    assert!(!["c", "bb", "aaa"].is_sorted());
+    assert!(["c", "bb", "aaa"].is_sorted_by_key(|s| s.len()));
+    assert!([3, 2, 2, 1].is_sorted_by(|a, b| a >= b));
+    assert!(![3, 2, 2, 1].is_sorted_by(|a, b| a > b));
+}
+
+#[test]
+fn test_contains_bytes() {
+    let mut data = [1u8; 64];
+    assert!(!data.contains(&2));
+    data[40] = 2;
+    for start in 0..16 {
+        for end in 41..56 {
+            assert!(data[start..end].contains(&2));
+            assert!(!data[start..40].contains(&2));
+        }
+    }
+
+    let signed = [-1i8, 0, 1, -128, 127];
+    assert!(signed.contains(&-128));
+    assert!(signed.contains(&127));
+    assert!(!signed.contains(&2));
+
+    let empty: [u8; 0] = [];
+    assert!(!empty.contains(&0));
+}
+
+#[test]
+fn test_as_ptr_range() {
+    let v = [1, 2, 3];
+    let r = v.as_ptr_range();
+    assert_eq!(r.start, v.as_ptr());
+    assert_eq!(r.end as usize - r.start as usize, 3 * 4);
+    assert_eq!(r.end, unsafe { v.as_ptr().offset(3) });
+
+    let empty: &[u64] = &[];
+    let r = empty.as_ptr_range();
+    assert_eq!(r.start, r.end);
+
+    let mut m = [1u8, 2, 3];
+    let r = m.as_mut_ptr_range();
+    unsafe { *r.end.offset(-1) = 9; }
+    assert_eq!(m, [1, 2, 9]);
+}
+
+#[test]
+fn test_split_at_unchecked() {
+    let v = [1, 2, 3, 4, 5];
+    for mid in 0..v.len() + 1 {
+        assert_eq!(unsafe { v.split_at_unchecked(mid) }, v.split_at(mid));
+    }
+
+    let mut m = [1, 2, 3, 4, 5];
+    {
+        let (left, right) = unsafe { m.split_at_mut_unchecked(3) };
+        assert_eq!(left, &[1, 2, 3]);
+        assert_eq!(right, &[4, 5]);
+        left.swap(0, 2);
+        right[0] = 0;
+    }
+    assert_eq!(m, [3, 2, 1, 0, 5]);
+}
+
 #[test]
 fn test_iterator_nth() {
     let v: &[_] = &[0, 1, 2, 3, 4];
@@ -46,6 +190,27 @@ fn test_iterator_nth() {
     assert_eq!(iter.nth(1).unwrap(), &v[4]);
 }
 
+#[test]
+fn test_iterator_nth_back() {
+    let v: &[_] = &[0, 1, 2, 3, 4];
+    for i in 0..v.len() {
+        assert_eq!(v.iter().nth_back(i).unwrap(), &v[v.len() - 1 - i]);
+    }
+    assert_eq!(v.iter().nth_back(v.len()), None);
+
+    let mut iter = v.iter();
+    assert_eq!(iter.nth_back(2).unwrap(), &v[2]);
+    assert_eq!(iter.nth_back(1).unwrap(), &v[0]);
+    assert_eq!(iter.next(), None);
+
+    let mut zsts = [(), (), ()];
+    let mut iter = zsts.iter_mut();
+    assert_eq!(iter.nth_back(1), Some(&mut ()));
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.nth_back(1), None);
+    assert_eq!(iter.len(), 0);
+}
+
 #[test]
 fn test_iterator_last() {
     let v: &[_] = &[0, 1, 2, 3, 4];
@@ -318,3 +483,180 @@ fn sort_unstable() {
     v.sort_unstable();
     assert!(v == [0xDEADBEEF]);
 }
+
+#[test]
+fn select_nth_unstable() {
+    let mut rng = XorShiftRng::new_unseeded();
+
+    for len in (2..21).chain(500..501) {
+        let mut orig = vec![0; len];
+
+        for &modulus in &[5, 10, 1000] {
+            for _ in 0..10 {
+                for i in 0..len {
+                    orig[i] = rng.gen::<i32>() % modulus;
+                }
+
+                let v_sorted = {
+                    let mut v = orig.clone();
+                    v.sort();
+                    v
+                };
+
+                // Sort in default order.
+                for pivot in 0..len {
+                    let mut v = orig.clone();
+                    v.select_nth_unstable(pivot);
+
+                    assert_eq!(v_sorted[pivot], v[pivot]);
+                    for i in 0..pivot {
+                        for j in pivot..len {
+                            assert!(v[i] <= v[j]);
+                        }
+                    }
+                }
+
+                // Sort in ascending order.
+                for pivot in 0..len {
+                    let mut v = orig.clone();
+                    let (left, pivot_ref, right) = v.select_nth_unstable_by(pivot, |a, b| a.cmp(b));
+
+                    assert_eq!(left.len() + right.len(), len - 1);
+
+                    for l in left.iter() {
+                        assert!(l <= pivot_ref);
+                        for r in right.iter() {
+                            assert!(l <= r);
+                            assert!(pivot_ref <= r);
+                        }
+                    }
+                }
+
+                // Sort in descending order.
+                let sort_descending_comparator = |a: &i32, b: &i32| b.cmp(a);
+                let v_sorted_descending = {
+                    let mut v = orig.clone();
+                    v.sort_by(sort_descending_comparator);
+                    v
+                };
+
+                for pivot in 0..len {
+                    let mut v = orig.clone();
+                    v.select_nth_unstable_by(pivot, sort_descending_comparator);
+
+                    assert_eq!(v_sorted_descending[pivot], v[pivot]);
+                    for i in 0..pivot {
+                        for j in pivot..len {
+                            assert!(v[j] <= v[i]);
+                        }
+                    }
+                }
+
+                // Sort by key.
+                for pivot in 0..len {
+                    let mut v = orig.clone();
+                    v.select_nth_unstable_by_key(pivot, |x| -x);
+
+                    assert_eq!(v_sorted_descending[pivot], v[pivot]);
+                }
+            }
+        }
+    }
+
+    // Sort at index using a completely random comparison function.
+    // This will reorder the elements *somehow*, but won't panic.
+    let mut v = [0; 500];
+    for i in 0..v.len() {
+        v[i] = i as i32;
+    }
+
+    for pivot in 0..v.len() {
+        v.select_nth_unstable_by(pivot, |_, _| *rng.choose(&[Less, Equal, Greater]).unwrap());
+        v.sort();
+        for i in 0..v.len() {
+            assert_eq!(v[i], i as i32);
+        }
+    }
+
+    // Should not panic.
+    [(); 10].select_nth_unstable(0);
+    [(); 10].select_nth_unstable(5);
+    [(); 10].select_nth_unstable(9);
+    [(); 100].select_nth_unstable(0);
+    [(); 100].select_nth_unstable(50);
+    [(); 100].select_nth_unstable(99);
+
+    let mut v = [0xDEADBEEFu64];
+    v.select_nth_unstable(0);
+    assert!(v == [0xDEADBEEF]);
+}
+
+#[test]
+#[should_panic(expected = "index 0 greater than length of slice")]
+fn select_nth_unstable_zero_length() {
+    [0i32; 0].select_nth_unstable(0);
+}
+
+#[test]
+#[should_panic(expected = "index 20 greater than length of slice")]
+fn select_nth_unstable_past_length() {
+    [0i32; 10].select_nth_unstable(20);
+}
+
+#[test]
+fn test_align_to_simple() {
+    let bytes = [1u8, 2, 3, 4, 5, 6, 7];
+    let (prefix, aligned, suffix) = unsafe { bytes.align_to::<u16>() };
+    assert_eq!(aligned.len(), 3);
+    assert!(prefix == [1] || suffix == [7]);
+    let expect1 = [1 << 8 | 2, 3 << 8 | 4, 5 << 8 | 6];
+    let expect2 = [1 | 2 << 8, 3 | 4 << 8, 5 | 6 << 8];
+    let expect3 = [2 << 8 | 3, 4 << 8 | 5, 6 << 8 | 7];
+    let expect4 = [2 | 3 << 8, 4 | 5 << 8, 6 | 7 << 8];
+    assert!(aligned == expect1 || aligned == expect2 || aligned == expect3 || aligned == expect4,
+            "aligned={:?} expected={:?} || {:?} || {:?} || {:?}",
+            aligned, expect1, expect2, expect3, expect4);
+}
+
+#[test]
+fn test_align_to_zst() {
+    let bytes = [1, 2, 3, 4, 5, 6, 7];
+    let (prefix, aligned, suffix) = unsafe { bytes.align_to::<()>() };
+    assert_eq!(aligned.len(), 0);
+    assert!(prefix == [1, 2, 3, 4, 5, 6, 7] || suffix == [1, 2, 3, 4, 5, 6, 7]);
+}
+
+#[test]
+fn test_align_to_non_trivial() {
+    let data = [1u32, 2, 3, 4, 5, 6, 7, 8];
+    let (prefix, aligned, suffix) = unsafe { data.align_to::<[u32; 3]>() };
+    assert!(prefix.is_empty());
+    assert_eq!(aligned, &[[1, 2, 3], [4, 5, 6]]);
+    assert_eq!(suffix, &[7, 8]);
+}
+
+#[test]
+fn test_align_to_empty_mid() {
+    use core::mem;
+
+    // Make sure that we do not create empty unaligned slices for the mid part, even when the
+    // overall slice is too short to contain an aligned address.
+    let bytes = [1, 2, 3, 4, 5, 6, 7];
+    type Chunk = u32;
+    for offset in 0..4 {
+        let (_, mid, _) = unsafe { bytes[offset..offset+1].align_to::<Chunk>() };
+        assert_eq!(mid.as_ptr() as usize % mem::align_of::<Chunk>(), 0);
+    }
+}
+
+#[test]
+fn test_align_to_mut_aliasing() {
+    let mut val = [1u8, 2, 3, 4, 5];
+    // `align_to_mut` used to create `mid` in a way that there was some intermediate
+    // incorrect aliasing, invalidating the resulting `mid` slice.
+    let (begin, mid, end) = unsafe { val.align_to_mut::<[u8; 2]>() };
+    assert!(begin.len() == 0);
+    assert!(end.len() == 1);
+    mid[0] = mid[1];
+    assert_eq!(val, [3, 4, 3, 4, 5])
+}
@@ -2306,23 +2306,23 @@ impl<'a> LoweringContext<'a> {
             ExprKind::Try(ref sub_expr) => {
                 // to:
                 //
-                // match Try::into_result(<expr>) {
-                //     Ok(val) => #[allow(unreachable_code)] val,
-                //     Err(err) => #[allow(unreachable_code)]
-                //                 // If there is an enclosing `catch {...}`
-                //                 break 'catch_target Try::from_error(From::from(err)),
-                //                 // Otherwise
-                //                 return Try::from_error(From::from(err)),
+                // match Try::branch(<expr>) {
+                //     ControlFlow::Continue(val) => #[allow(unreachable_code)] val,
+                //     ControlFlow::Break(residual) => #[allow(unreachable_code)]
+                //         // If there is an enclosing `catch {...}`
+                //         break 'catch_target FromResidual::from_residual(residual),
+                //         // Otherwise
+                //         return FromResidual::from_residual(residual),
                 // }
 
                 let unstable_span = self.allow_internal_unstable("?", e.span);
 
-                // Try::into_result(<expr>)
+                // Try::branch(<expr>)
                 let discr = {
                     // expand <expr>
                     let sub_expr = self.lower_expr(sub_expr);
 
-                    let path = &["ops", "Try", "into_result"];
+                    let path = &["ops", "Try", "branch"];
                     let path = P(self.expr_std_path(unstable_span, path, ThinVec::new()));
                     P(self.expr_call(e.span, path, hir_vec![sub_expr]))
                 };
@@ -2342,36 +2342,31 @@ impl<'a> LoweringContext<'a> {
                 };
                 let attrs = vec![attr];
 
-                // Ok(val) => #[allow(unreachable_code)] val,
-                let ok_arm = {
+                // ControlFlow::Continue(val) => #[allow(unreachable_code)] val,
+                let continue_arm = {
                     let val_ident = self.str_to_ident("val");
                     let val_pat = self.pat_ident(e.span, val_ident);
                     let val_expr = P(self.expr_ident_with_attrs(e.span,
                                                                 val_ident,
                                                                 val_pat.id,
                                                                 ThinVec::from(attrs.clone())));
-                    let ok_pat = self.pat_ok(e.span, val_pat);
+                    let continue_pat = self.pat_cf_continue(unstable_span, val_pat);
 
-                    self.arm(hir_vec![ok_pat], val_expr)
+                    self.arm(hir_vec![continue_pat], val_expr)
                 };
 
-                // Err(err) => #[allow(unreachable_code)]
-                //             return Carrier::from_error(From::from(err)),
-                let err_arm = {
-                    let err_ident = self.str_to_ident("err");
-                    let err_local = self.pat_ident(e.span, err_ident);
-                    let from_expr = {
-                        let path = &["convert", "From", "from"];
-                        let from = P(self.expr_std_path(e.span, path, ThinVec::new()));
-                        let err_expr = self.expr_ident(e.span, err_ident, err_local.id);
-
-                        self.expr_call(e.span, from, hir_vec![err_expr])
-                    };
-                    let from_err_expr = {
-                        let path = &["ops", "Try", "from_error"];
-                        let from_err = P(self.expr_std_path(unstable_span, path,
-                                                            ThinVec::new()));
-                        P(self.expr_call(e.span, from_err, hir_vec![from_expr]))
+                // ControlFlow::Break(residual) => #[allow(unreachable_code)]
+                //     return FromResidual::from_residual(residual),
+                let break_arm = {
+                    let residual_ident = self.str_to_ident("residual");
+                    let residual_local = self.pat_ident(e.span, residual_ident);
+                    let from_residual_expr = {
+                        let path = &["ops", "FromResidual", "from_residual"];
+                        let from_residual = P(self.expr_std_path(unstable_span, path,
+                                                                 ThinVec::new()));
+                        let residual_expr = P(self.expr_ident(e.span, residual_ident,
+                                                              residual_local.id));
+                        P(self.expr_call(e.span, from_residual, hir_vec![residual_expr]))
                     };
 
                     let thin_attrs = ThinVec::from(attrs);
@@ -2384,22 +2379,22 @@ impl<'a> LoweringContext<'a> {
                                     ident: None,
                                     target_id: hir::ScopeTarget::Block(catch_node),
                                 },
-                                Some(from_err_expr)
+                                Some(from_residual_expr)
                             ),
                             thin_attrs))
                     } else {
                         P(self.expr(e.span,
-                                    hir::Expr_::ExprRet(Some(from_err_expr)),
+                                    hir::Expr_::ExprRet(Some(from_residual_expr)),
                                     thin_attrs))
                     };
 
 
-                    let err_pat = self.pat_err(e.span, err_local);
-                    self.arm(hir_vec![err_pat], ret_expr)
+                    let break_pat = self.pat_cf_break(unstable_span, residual_local);
+                    self.arm(hir_vec![break_pat], ret_expr)
                 };
 
                 hir::ExprMatch(discr,
-                               hir_vec![err_arm, ok_arm],
+                               hir_vec![break_arm, continue_arm],
                                hir::MatchSource::TryDesugar)
             }
 
@@ -2675,12 +2670,12 @@ impl<'a> LoweringContext<'a> {
         }
     }
 
-    fn pat_ok(&mut self, span: Span, pat: P<hir::Pat>) -> P<hir::Pat> {
-        self.pat_std_enum(span, &["result", "Result", "Ok"], hir_vec![pat])
+    fn pat_cf_continue(&mut self, span: Span, pat: P<hir::Pat>) -> P<hir::Pat> {
+        self.pat_std_enum(span, &["ops", "ControlFlow", "Continue"], hir_vec![pat])
     }
 
-    fn pat_err(&mut self, span: Span, pat: P<hir::Pat>) -> P<hir::Pat> {
-        self.pat_std_enum(span, &["result", "Result", "Err"], hir_vec![pat])
+    fn pat_cf_break(&mut self, span: Span, pat: P<hir::Pat>) -> P<hir::Pat> {
+        self.pat_std_enum(span, &["ops", "ControlFlow", "Break"], hir_vec![pat])
     }
 
     fn pat_some(&mut self, span: Span, pat: P<hir::Pat>) -> P<hir::Pat> {
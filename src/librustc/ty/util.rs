@@ -826,6 +826,17 @@ impl<'a, 'tcx> ty::TyS<'tcx> {
         layout
     }
 
+    /// Returns the number of variants of an enum type, or 0 for any other
+    /// type. Returns `None` if that isn't known yet, i.e. for type
+    /// parameters, projections that still need normalizing and `impl
+    /// Trait` types.
+    pub fn variant_count(&'tcx self) -> Option<usize> {
+        match self.sty {
+            ty::TyAdt(def, _) if def.is_enum() => Some(def.variants.len()),
+            ty::TyParam(_) | ty::TyProjection(_) | ty::TyAnon(..) => None,
+            _ => Some(0),
+        }
+    }
 
     /// Check whether a type is representable. This means it cannot contain unboxed
     /// structural recursion. This check is needed for structs and enums.
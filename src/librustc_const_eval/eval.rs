@@ -338,6 +338,16 @@ fn eval_const_expr_partial<'a, 'tcx>(cx: &ConstContext<'a, 'tcx>,
                     return Ok(Integral(Usize(ConstUsize::new(align.abi(),
                         tcx.sess.target.uint_type).unwrap())));
                 }
+                "variant_count" => {
+                    let ty = tcx.normalize_associated_type_in_env(&substs.type_at(0),
+                                                                  cx.param_env);
+                    let count = match ty.variant_count() {
+                        Some(count) => count as u64,
+                        None => signal!(e, LayoutError(ty::layout::LayoutError::Unknown(ty))),
+                    };
+                    return Ok(Integral(Usize(ConstUsize::new(count,
+                        tcx.sess.target.uint_type).unwrap())));
+                }
                 _ => signal!(e, TypeckError)
             }
           }
@@ -755,7 +755,9 @@ impl<'a, 'tcx> Visitor<'tcx> for Qualifier<'a, 'tcx, 'tcx> {
                     Abi::PlatformIntrinsic => {
                         assert!(!self.tcx.is_const_fn(def_id));
                         match &self.tcx.item_name(def_id).as_str()[..] {
-                            "size_of" | "min_align_of" => is_const_fn = true,
+                            "size_of" | "min_align_of" | "variant_count" => {
+                                is_const_fn = true;
+                            }
 
                             name if name.starts_with("simd_shuffle") => {
                                 is_shuffle = true;
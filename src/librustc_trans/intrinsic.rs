@@ -162,6 +162,13 @@ pub fn trans_intrinsic_call<'a, 'tcx>(bcx: &Builder<'a, 'tcx>,
                 C_uint(ccx, ccx.align_of(tp_ty))
             }
         }
+        "variant_count" => {
+            let tp_ty = substs.type_at(0);
+            let count = tp_ty.variant_count().unwrap_or_else(|| {
+                span_bug!(span, "variant_count of unresolved `{}`", tp_ty)
+            });
+            C_uint(ccx, count)
+        }
         "pref_align_of" => {
             let tp_ty = substs.type_at(0);
             let lltp_ty = type_of::type_of(ccx, tp_ty);
@@ -373,6 +373,14 @@ impl<'a, 'tcx> MirConstContext<'a, 'tcx> {
                                         self.ccx.align_of(substs.type_at(0)));
                                     Ok(Const::new(llval, tcx.types.usize))
                                 }
+                                "variant_count" => {
+                                    let ty = substs.type_at(0);
+                                    let count = ty.variant_count().unwrap_or_else(|| {
+                                        span_bug!(span, "variant_count of unresolved `{}`", ty)
+                                    });
+                                    let llval = C_uint(self.ccx, count);
+                                    Ok(Const::new(llval, tcx.types.usize))
+                                }
                                 _ => span_bug!(span, "{:?} in constant", terminator.kind)
                             }
                         } else {
@@ -440,6 +440,10 @@ impl<'a, 'gcx, 'tcx> ProbeContext<'a, 'gcx, 'tcx> {
             ty::TyParam(p) => {
                 self.assemble_inherent_candidates_from_param(self_ty, p);
             }
+            ty::TyBool => {
+                let lang_def_id = self.tcx.lang_items.bool_impl();
+                self.assemble_inherent_impl_for_primitive(lang_def_id);
+            }
             ty::TyChar => {
                 let lang_def_id = self.tcx.lang_items.char_impl();
                 self.assemble_inherent_impl_for_primitive(lang_def_id);
@@ -448,7 +452,11 @@ impl<'a, 'gcx, 'tcx> ProbeContext<'a, 'gcx, 'tcx> {
                 let lang_def_id = self.tcx.lang_items.str_impl();
                 self.assemble_inherent_impl_for_primitive(lang_def_id);
             }
-            ty::TySlice(_) => {
+            ty::TySlice(elem_ty) => {
+                if let ty::TyUint(ast::UintTy::U8) = elem_ty.sty {
+                    let lang_def_id = self.tcx.lang_items.slice_u8_impl();
+                    self.assemble_inherent_impl_for_primitive(lang_def_id);
+                }
                 let lang_def_id = self.tcx.lang_items.slice_impl();
                 self.assemble_inherent_impl_for_primitive(lang_def_id);
             }
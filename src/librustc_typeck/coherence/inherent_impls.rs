@@ -119,6 +119,13 @@ impl<'a, 'tcx, 'v> ItemLikeVisitor<'v> for InherentCollect<'a, 'tcx> {
             ty::TyDynamic(ref data, ..) if data.principal().is_some() => {
                 self.check_def_id(item, data.principal().unwrap().def_id());
             }
+            ty::TyBool => {
+                self.check_primitive_impl(def_id,
+                                          self.tcx.lang_items.bool_impl(),
+                                          "bool",
+                                          "bool",
+                                          item.span);
+            }
             ty::TyChar => {
                 self.check_primitive_impl(def_id,
                                           self.tcx.lang_items.char_impl(),
@@ -133,6 +140,13 @@ impl<'a, 'tcx, 'v> ItemLikeVisitor<'v> for InherentCollect<'a, 'tcx> {
                                           "str",
                                           item.span);
             }
+            ty::TySlice(slice_item) if slice_item == self.tcx.types.u8 => {
+                self.check_primitive_impl(def_id,
+                                          self.tcx.lang_items.slice_u8_impl(),
+                                          "slice_u8",
+                                          "[u8]",
+                                          item.span);
+            }
             ty::TySlice(_) => {
                 self.check_primitive_impl(def_id,
                                           self.tcx.lang_items.slice_impl(),
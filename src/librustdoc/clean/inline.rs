@@ -256,9 +256,11 @@ pub fn build_impls(cx: &DocContext, did: DefId) -> Vec<clean::Item> {
         tcx.lang_items.u128_impl(),
         tcx.lang_items.f32_impl(),
         tcx.lang_items.f64_impl(),
+        tcx.lang_items.bool_impl(),
         tcx.lang_items.char_impl(),
         tcx.lang_items.str_impl(),
         tcx.lang_items.slice_impl(),
+        tcx.lang_items.slice_u8_impl(),
         tcx.lang_items.const_ptr_impl(),
         tcx.lang_items.mut_ptr_impl(),
     ];
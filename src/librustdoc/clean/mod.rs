@@ -2558,7 +2558,7 @@ fn build_deref_target_impls(cx: &DocContext,
             F32 => tcx.lang_items.f32_impl(),
             F64 => tcx.lang_items.f64_impl(),
             Char => tcx.lang_items.char_impl(),
-            Bool => None,
+            Bool => tcx.lang_items.bool_impl(),
             Str => tcx.lang_items.str_impl(),
             Slice => tcx.lang_items.slice_impl(),
             Array => tcx.lang_items.slice_impl(),
@@ -27,8 +27,13 @@
 #![stable(feature = "rust1", since = "1.0.0")]
 
 use fmt;
+use mem;
 use ops::Range;
 use iter::FusedIterator;
+use ptr;
+
+#[unstable(feature = "ascii_char", issue = "0")]
+pub use core::ascii::Char;
 
 /// Extension methods for ASCII-subset only operations.
 ///
@@ -663,7 +668,11 @@ impl AsciiExt for [u8] {
     type Owned = Vec<u8>;
     #[inline]
     fn is_ascii(&self) -> bool {
-        self.iter().all(|b| b.is_ascii())
+        let mut chunks = self.chunks_exact(mem::size_of::<usize>());
+        if !chunks.by_ref().all(|c| read_word(c) & HIGH_BITS == 0) {
+            return false;
+        }
+        chunks.remainder().iter().all(|b| b.is_ascii())
     }
 
     #[inline]
@@ -682,20 +691,40 @@ impl AsciiExt for [u8] {
 
     #[inline]
     fn eq_ignore_ascii_case(&self, other: &[u8]) -> bool {
-        self.len() == other.len() &&
-        self.iter().zip(other).all(|(a, b)| {
+        if self.len() != other.len() {
+            return false;
+        }
+        let mut a = self.chunks_exact(mem::size_of::<usize>());
+        let mut b = other.chunks_exact(mem::size_of::<usize>());
+        if !a.by_ref().zip(b.by_ref()).all(|(a, b)| {
+            let (a, b) = (read_word(a), read_word(b));
+            a == b || word_to_lowercase(a) == word_to_lowercase(b)
+        }) {
+            return false;
+        }
+        a.remainder().iter().zip(b.remainder()).all(|(a, b)| {
             a.eq_ignore_ascii_case(b)
         })
     }
 
     fn make_ascii_uppercase(&mut self) {
-        for byte in self {
+        let mut chunks = self.chunks_exact_mut(mem::size_of::<usize>());
+        for chunk in chunks.by_ref() {
+            let word = read_word(chunk);
+            write_word(chunk, word_to_uppercase(word));
+        }
+        for byte in chunks.into_remainder() {
             byte.make_ascii_uppercase();
         }
     }
 
     fn make_ascii_lowercase(&mut self) {
-        for byte in self {
+        let mut chunks = self.chunks_exact_mut(mem::size_of::<usize>());
+        for chunk in chunks.by_ref() {
+            let word = read_word(chunk);
+            write_word(chunk, word_to_lowercase(word));
+        }
+        for byte in chunks.into_remainder() {
             byte.make_ascii_lowercase();
         }
     }
@@ -771,92 +800,52 @@ impl AsciiExt for u8 {
 
     #[inline]
     fn is_ascii_alphabetic(&self) -> bool {
-        if *self >= 0x80 { return false; }
-        match ASCII_CHARACTER_CLASS[*self as usize] {
-            L|Lx|U|Ux => true,
-            _ => false
-        }
+        u8::is_ascii_alphabetic(self)
     }
 
     #[inline]
     fn is_ascii_uppercase(&self) -> bool {
-        if *self >= 0x80 { return false }
-        match ASCII_CHARACTER_CLASS[*self as usize] {
-            U|Ux => true,
-            _ => false
-        }
+        u8::is_ascii_uppercase(self)
     }
 
     #[inline]
     fn is_ascii_lowercase(&self) -> bool {
-        if *self >= 0x80 { return false }
-        match ASCII_CHARACTER_CLASS[*self as usize] {
-            L|Lx => true,
-            _ => false
-        }
+        u8::is_ascii_lowercase(self)
     }
 
     #[inline]
     fn is_ascii_alphanumeric(&self) -> bool {
-        if *self >= 0x80 { return false }
-        match ASCII_CHARACTER_CLASS[*self as usize] {
-            D|L|Lx|U|Ux => true,
-            _ => false
-        }
+        u8::is_ascii_alphanumeric(self)
     }
 
     #[inline]
     fn is_ascii_digit(&self) -> bool {
-        if *self >= 0x80 { return false }
-        match ASCII_CHARACTER_CLASS[*self as usize] {
-            D => true,
-            _ => false
-        }
+        u8::is_ascii_digit(self)
     }
 
     #[inline]
     fn is_ascii_hexdigit(&self) -> bool {
-        if *self >= 0x80 { return false }
-        match ASCII_CHARACTER_CLASS[*self as usize] {
-            D|Lx|Ux => true,
-            _ => false
-        }
+        u8::is_ascii_hexdigit(self)
     }
 
     #[inline]
     fn is_ascii_punctuation(&self) -> bool {
-        if *self >= 0x80 { return false }
-        match ASCII_CHARACTER_CLASS[*self as usize] {
-            P => true,
-            _ => false
-        }
+        u8::is_ascii_punctuation(self)
     }
 
     #[inline]
     fn is_ascii_graphic(&self) -> bool {
-        if *self >= 0x80 { return false; }
-        match ASCII_CHARACTER_CLASS[*self as usize] {
-            Ux|U|Lx|L|D|P => true,
-            _ => false
-        }
+        u8::is_ascii_graphic(self)
     }
 
     #[inline]
     fn is_ascii_whitespace(&self) -> bool {
-        if *self >= 0x80 { return false; }
-        match ASCII_CHARACTER_CLASS[*self as usize] {
-            Cw|W => true,
-            _ => false
-        }
+        u8::is_ascii_whitespace(self)
     }
 
     #[inline]
     fn is_ascii_control(&self) -> bool {
-        if *self >= 0x80 { return false; }
-        match ASCII_CHARACTER_CLASS[*self as usize] {
-            C|Cw => true,
-            _ => false
-        }
+        u8::is_ascii_control(self)
     }
 }
 
@@ -898,52 +887,52 @@ impl AsciiExt for char {
 
     #[inline]
     fn is_ascii_alphabetic(&self) -> bool {
-        (*self as u32 <= 0x7f) && (*self as u8).is_ascii_alphabetic()
+        char::is_ascii_alphabetic(self)
     }
 
     #[inline]
     fn is_ascii_uppercase(&self) -> bool {
-        (*self as u32 <= 0x7f) && (*self as u8).is_ascii_uppercase()
+        char::is_ascii_uppercase(self)
     }
 
     #[inline]
     fn is_ascii_lowercase(&self) -> bool {
-        (*self as u32 <= 0x7f) && (*self as u8).is_ascii_lowercase()
+        char::is_ascii_lowercase(self)
     }
 
     #[inline]
     fn is_ascii_alphanumeric(&self) -> bool {
-        (*self as u32 <= 0x7f) && (*self as u8).is_ascii_alphanumeric()
+        char::is_ascii_alphanumeric(self)
     }
 
     #[inline]
     fn is_ascii_digit(&self) -> bool {
-        (*self as u32 <= 0x7f) && (*self as u8).is_ascii_digit()
+        char::is_ascii_digit(self)
     }
 
     #[inline]
     fn is_ascii_hexdigit(&self) -> bool {
-        (*self as u32 <= 0x7f) && (*self as u8).is_ascii_hexdigit()
+        char::is_ascii_hexdigit(self)
     }
 
     #[inline]
     fn is_ascii_punctuation(&self) -> bool {
-        (*self as u32 <= 0x7f) && (*self as u8).is_ascii_punctuation()
+        char::is_ascii_punctuation(self)
     }
 
     #[inline]
     fn is_ascii_graphic(&self) -> bool {
-        (*self as u32 <= 0x7f) && (*self as u8).is_ascii_graphic()
+        char::is_ascii_graphic(self)
     }
 
     #[inline]
     fn is_ascii_whitespace(&self) -> bool {
-        (*self as u32 <= 0x7f) && (*self as u8).is_ascii_whitespace()
+        char::is_ascii_whitespace(self)
     }
 
     #[inline]
     fn is_ascii_control(&self) -> bool {
-        (*self as u32 <= 0x7f) && (*self as u8).is_ascii_control()
+        char::is_ascii_control(self)
     }
 }
 
@@ -1063,6 +1052,48 @@ impl fmt::Debug for EscapeDefault {
     }
 }
 
+// Word-at-a-time helpers for the `[u8]` impl above. Each `usize` is treated
+// as a vector of bytes; the byte-wise additions below never carry into the
+// neighbouring byte because they are only ever applied to 7-bit values.
+
+const LOW_BITS: usize = 0x0101010101010101u64 as usize;
+const HIGH_BITS: usize = 0x8080808080808080u64 as usize;
+
+/// Reads a word from a chunk of exactly `size_of::<usize>()` bytes.
+#[inline]
+fn read_word(chunk: &[u8]) -> usize {
+    debug_assert_eq!(chunk.len(), mem::size_of::<usize>());
+    unsafe { ptr::read_unaligned(chunk.as_ptr() as *const usize) }
+}
+
+/// Writes a word to a chunk of exactly `size_of::<usize>()` bytes.
+#[inline]
+fn write_word(chunk: &mut [u8], word: usize) {
+    debug_assert_eq!(chunk.len(), mem::size_of::<usize>());
+    unsafe { ptr::write_unaligned(chunk.as_mut_ptr() as *mut usize, word) }
+}
+
+/// Returns a word with the high bit set in every byte of `word` that lies
+/// in `lo...hi`, and all other bits clear.
+#[inline]
+fn bytes_in_range(word: usize, lo: u8, hi: u8) -> usize {
+    let heptets = word & !HIGH_BITS;
+    let ge_lo = heptets + LOW_BITS * (0x80 - lo as usize);
+    let gt_hi = heptets + LOW_BITS * (0x7f - hi as usize);
+    ge_lo & !gt_hi & !word & HIGH_BITS
+}
+
+#[inline]
+fn word_to_uppercase(word: usize) -> usize {
+    // The ASCII case bit is 0x20, two bits below the high bit of each byte.
+    word ^ (bytes_in_range(word, b'a', b'z') >> 2)
+}
+
+#[inline]
+fn word_to_lowercase(word: usize) -> usize {
+    word ^ (bytes_in_range(word, b'A', b'Z') >> 2)
+}
+
 
 static ASCII_LOWERCASE_MAP: [u8; 256] = [
     0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07,
@@ -1142,31 +1173,6 @@ static ASCII_UPPERCASE_MAP: [u8; 256] = [
     0xf8, 0xf9, 0xfa, 0xfb, 0xfc, 0xfd, 0xfe, 0xff,
 ];
 
-enum AsciiCharacterClass {
-    C,  // control
-    Cw, // control whitespace
-    W,  // whitespace
-    D,  // digit
-    L,  // lowercase
-    Lx, // lowercase hex digit
-    U,  // uppercase
-    Ux, // uppercase hex digit
-    P,  // punctuation
-}
-use self::AsciiCharacterClass::*;
-
-static ASCII_CHARACTER_CLASS: [AsciiCharacterClass; 128] = [
-//  _0 _1 _2 _3 _4 _5 _6 _7 _8 _9 _a _b _c _d _e _f
-    C, C, C, C, C, C, C, C, C, Cw,Cw,C, Cw,Cw,C, C, // 0_
-    C, C, C, C, C, C, C, C, C, C, C, C, C, C, C, C, // 1_
-    W, P, P, P, P, P, P, P, P, P, P, P, P, P, P, P, // 2_
-    D, D, D, D, D, D, D, D, D, D, P, P, P, P, P, P, // 3_
-    P, Ux,Ux,Ux,Ux,Ux,Ux,U, U, U, U, U, U, U, U, U, // 4_
-    U, U, U, U, U, U, U, U, U, U, U, P, P, P, P, P, // 5_
-    P, Lx,Lx,Lx,Lx,Lx,Lx,L, L, L, L, L, L, L, L, L, // 6_
-    L, L, L, L, L, L, L, L, L, L, L, P, P, P, P, C, // 7_
-];
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1188,6 +1194,39 @@ mod tests {
         assert!(!"ประเทศไทย中华ệ ".chars().any(|c| c.is_ascii()));
     }
 
+    #[test]
+    fn test_slice_word_at_a_time() {
+        // Exercise every byte value at every position within a word, as
+        // well as the unaligned head and the tail handled byte-wise.
+        let bytes: Vec<u8> = (0..512).map(|b| (b % 256) as u8).collect();
+        for start in 0..16 {
+            for end in (bytes.len() - 16)..bytes.len() {
+                let slice = &bytes[start..end];
+                assert_eq!(slice.is_ascii(), slice.iter().all(|b| b.is_ascii()));
+
+                let upper: Vec<u8> = slice.iter().map(|b| b.to_ascii_uppercase()).collect();
+                let lower: Vec<u8> = slice.iter().map(|b| b.to_ascii_lowercase()).collect();
+                assert_eq!(slice.to_ascii_uppercase(), upper);
+                assert_eq!(slice.to_ascii_lowercase(), lower);
+                assert!(slice.eq_ignore_ascii_case(&upper));
+                assert!(upper.eq_ignore_ascii_case(&lower));
+            }
+        }
+        for end in 0..bytes.len() {
+            assert_eq!(bytes[..end].is_ascii(), end <= 0x80);
+        }
+
+        let ascii = b"Content-Type: text/html; charset=utf-8";
+        assert!(ascii.is_ascii());
+        let mut non_ascii = ascii.to_vec();
+        non_ascii[30] = 0xc3;
+        assert!(!non_ascii.is_ascii());
+        assert!(!ascii.eq_ignore_ascii_case(&non_ascii));
+        assert!(!ascii.eq_ignore_ascii_case(&ascii[1..]));
+        assert!(ascii.eq_ignore_ascii_case(b"CONTENT-TYPE: TEXT/HTML; CHARSET=UTF-8"));
+        assert!(!ascii.eq_ignore_ascii_case(b"CONTENT-TYPE: TEXT/HTML; CHARSET=UTF-9"));
+    }
+
     #[test]
     fn test_to_ascii_uppercase() {
         assert_eq!("url()URL()uRl()ürl".to_ascii_uppercase(), "URL()URL()URL()üRL");
@@ -1519,4 +1558,32 @@ mod tests {
             " ",
         );
     }
+
+    #[test]
+    fn test_is_ascii_octdigit() {
+        for b in 0..128u8 {
+            let c = b as char;
+            assert_eq!(b.is_ascii_octdigit(), b >= b'0' && b <= b'7');
+            assert_eq!(c.is_ascii_octdigit(), c.is_digit(8));
+        }
+        assert!(!0xB7u8.is_ascii_octdigit());
+        assert!(!'\u{37}\u{300}'.chars().nth(1).unwrap().is_ascii_octdigit());
+    }
+
+    #[test]
+    fn test_ascii_ctype_const() {
+        const DIGIT: bool = b'5'.is_ascii_digit();
+        const HEX: bool = 'F'.is_ascii_hexdigit();
+        const OCT: bool = '8'.is_ascii_octdigit();
+        const TABLE: [bool; 4] = [
+            b'a'.is_ascii_alphabetic(),
+            b' '.is_ascii_whitespace(),
+            '~'.is_ascii_graphic(),
+            '\u{7f}'.is_ascii_control(),
+        ];
+        assert!(DIGIT);
+        assert!(HEX);
+        assert!(!OCT);
+        assert_eq!(TABLE, [true, true, true, true]);
+    }
 }
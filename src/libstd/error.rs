@@ -57,6 +57,7 @@ use cell;
 use char;
 use fmt::{self, Debug, Display};
 use mem::transmute;
+use net;
 use num;
 use str;
 use string;
@@ -347,6 +348,13 @@ impl Error for char::ParseCharError {
     }
 }
 
+#[stable(feature = "addr_parse_error_error", since = "1.4.0")]
+impl Error for net::AddrParseError {
+    fn description(&self) -> &str {
+        self.__description()
+    }
+}
+
 
 // copied from any.rs
 impl Error + 'static {
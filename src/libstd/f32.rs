@@ -1142,6 +1142,80 @@ impl f32 {
         }
         unsafe { ::mem::transmute(v) }
     }
+
+    /// Returns an ordering between `self` and `other` values.
+    ///
+    /// Unlike the standard partial comparison between floating point numbers,
+    /// this comparison always produces an ordering in accordance to the
+    /// `totalOrder` predicate as defined in IEEE 754 (2008 revision) floating
+    /// point standard. The values are ordered in the following order:
+    ///
+    /// - Negative quiet NaN
+    /// - Negative signaling NaN
+    /// - Negative infinity
+    /// - Negative numbers
+    /// - Negative subnormal numbers
+    /// - Negative zero
+    /// - Positive zero
+    /// - Positive subnormal numbers
+    /// - Positive numbers
+    /// - Positive infinity
+    /// - Positive signaling NaN
+    /// - Positive quiet NaN
+    ///
+    /// Note that this function does not always agree with the `PartialOrd`
+    /// and `PartialEq` implementations of `f32`. In particular, they regard
+    /// negative and positive zero as equal, while `total_cmp` doesn't.
+    ///
+    /// # Examples
+    ///
+    /// Sorting a slice of floats, including NaNs, without allocating and
+    /// without panicking:
+    ///
+    /// ```
+    /// #![feature(total_cmp)]
+    /// use std::f32;
+    ///
+    /// let mut v = [2.5, -0.0, f32::NAN.abs(), 0.0, f32::NEG_INFINITY, 1.0];
+    /// v.sort_unstable_by(|a, b| a.total_cmp(b));
+    ///
+    /// assert_eq!(&v[..5], &[f32::NEG_INFINITY, -0.0, 0.0, 1.0, 2.5]);
+    /// assert!(v[1].is_sign_negative() && v[2].is_sign_positive());
+    /// assert!(v[5].is_nan());
+    /// ```
+    #[unstable(feature = "total_cmp", issue = "0")]
+    #[inline]
+    pub fn total_cmp(&self, other: &f32) -> ::cmp::Ordering {
+        let mut left = self.to_bits() as i32;
+        let mut right = other.to_bits() as i32;
+
+        // In case of negatives, flip all the bits except the sign
+        // to achieve a similar layout as two's complement integers
+        //
+        // Why does this work? IEEE 754 floats consist of three fields:
+        // Sign bit, exponent and mantissa. The set of exponent and mantissa
+        // fields as a whole have the property that their bitwise order is
+        // equal to the numeric magnitude where the magnitude is defined.
+        // The magnitude is not normally defined on NaN values, but
+        // IEEE 754 totalOrder defines the NaN values also to follow the
+        // bitwise order. This leads to order explained in the doc comment.
+        // However, the representation of magnitude is the same for negative
+        // and positive numbers - only the sign bit is different.
+        // To easily compare the floats as signed integers, we need to
+        // flip the exponent and mantissa bits in case of negative numbers.
+        // We effectively convert the numbers to "two's complement" form.
+        //
+        // To do the flipping, we construct a mask and XOR against it.
+        // We branchlessly calculate an "all-ones except for the sign bit"
+        // mask from negative-signed values: right shifting sign-extends
+        // the integer, so we "fill" the mask with sign bits, and then
+        // convert to unsigned to push one more zero bit.
+        // On positive values, the mask is all zeros, so it's a no-op.
+        left ^= (((left >> 31) as u32) >> 1) as i32;
+        right ^= (((right >> 31) as u32) >> 1) as i32;
+
+        left.cmp(&right)
+    }
 }
 
 #[cfg(test)]
@@ -1751,4 +1825,34 @@ mod tests {
         assert_ne!(nan_masked & QNAN_MASK, 0);
         assert!(nan_masked_fl.is_nan());
     }
+
+    #[test]
+    fn test_total_cmp() {
+        use cmp::Ordering::{Equal, Greater, Less};
+
+        // The sign of `NAN` itself is unspecified, so build both explicitly.
+        let pos_nan = NAN.abs();
+        let neg_nan = -pos_nan;
+
+        assert_eq!(Equal, (0.0 as f32).total_cmp(&0.0));
+        assert_eq!(Less, (-0.0 as f32).total_cmp(&0.0));
+        assert_eq!(Greater, (0.0 as f32).total_cmp(&-0.0));
+        assert_eq!(Less, (-1.0 as f32).total_cmp(&-0.5));
+        assert_eq!(Less, (0.5 as f32).total_cmp(&1.0));
+        assert_eq!(Less, MAX.total_cmp(&INFINITY));
+        assert_eq!(Less, NEG_INFINITY.total_cmp(&MIN));
+        assert_eq!(Less, MIN_POSITIVE.total_cmp(&1.0));
+        assert_eq!(Equal, INFINITY.total_cmp(&INFINITY));
+        assert_eq!(Less, INFINITY.total_cmp(&pos_nan));
+        assert_eq!(Greater, NEG_INFINITY.total_cmp(&neg_nan));
+        assert_eq!(Less, neg_nan.total_cmp(&pos_nan));
+        assert_eq!(Equal, pos_nan.total_cmp(&pos_nan));
+
+        let mut v = [pos_nan, 1.0, -0.0, NEG_INFINITY, 0.0, neg_nan, -1.5];
+        v.sort_by(|a, b| a.total_cmp(b));
+        assert!(v[0].is_nan() && v[0].is_sign_negative());
+        assert_eq!(&v[1..6], &[NEG_INFINITY, -1.5, -0.0, 0.0, 1.0]);
+        assert!(v[3].is_sign_negative() && v[4].is_sign_positive());
+        assert!(v[6].is_nan() && v[6].is_sign_positive());
+    }
 }
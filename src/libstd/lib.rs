@@ -239,11 +239,14 @@
 
 // std is implemented with unstable features, many of which are internal
 // compiler details that will never be stable
+#![feature(addr_parse_error_internals)]
 #![feature(alloc)]
 #![feature(allocator_api)]
 #![feature(alloc_system)]
 #![feature(allocator_internals)]
 #![feature(allow_internal_unstable)]
+#![feature(ascii_char)]
+#![feature(ascii_ctype)]
 #![feature(asm)]
 #![feature(box_syntax)]
 #![feature(cfg_target_has_atomic)]
@@ -251,6 +254,7 @@
 #![feature(cfg_target_vendor)]
 #![feature(char_error_internals)]
 #![feature(char_internals)]
+#![feature(chunks_exact)]
 #![feature(collections_range)]
 #![feature(compiler_builtins_lib)]
 #![feature(const_fn)]
@@ -259,6 +263,7 @@
 #![feature(dropck_eyepatch)]
 #![feature(exact_size_is_empty)]
 #![feature(float_from_str_radix)]
+#![feature(fmt_flags_align)]
 #![feature(fn_traits)]
 #![feature(fnbox)]
 #![feature(fused)]
@@ -271,6 +276,7 @@
 #![feature(int_error_internals)]
 #![feature(integer_atomics)]
 #![feature(into_cow)]
+#![feature(ip_in_core)]
 #![feature(lang_items)]
 #![feature(libc)]
 #![feature(link_args)]
@@ -298,6 +304,7 @@
 #![feature(sip_hash_13)]
 #![feature(slice_bytes)]
 #![feature(slice_concat_ext)]
+#![feature(slice_internals)]
 #![feature(slice_patterns)]
 #![feature(staged_api)]
 #![feature(stmt_expr_attributes)]
@@ -314,6 +321,7 @@
 #![feature(untagged_unions)]
 #![feature(unwind_attributes)]
 #![feature(vec_push_all)]
+#![cfg_attr(test, feature(is_ascii_octdigit))]
 #![cfg_attr(test, feature(update_panic_count))]
 
 #![default_lib_allocator]
@@ -378,6 +386,8 @@ pub use core::convert;
 pub use core::default;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::hash;
+#[unstable(feature = "core_hint", issue = "0")]
+pub use core::hint;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::intrinsics;
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -390,6 +400,8 @@ pub use core::mem;
 pub use core::ops;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::ptr;
+#[unstable(feature = "portable_simd", issue = "0")]
+pub use core::simd;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::raw;
 #[stable(feature = "rust1", since = "1.0.0")]
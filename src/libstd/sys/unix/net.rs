@@ -127,7 +127,7 @@ impl Socket {
         self.set_nonblocking(true)?;
         let r = unsafe {
             let (addrp, len) = addr.into_inner();
-            cvt(libc::connect(self.0.raw(), addrp, len))
+            cvt(libc::connect(self.0.raw(), addrp.as_ptr(), len))
         };
         self.set_nonblocking(false)?;
 
@@ -119,7 +119,7 @@ impl Socket {
         self.set_nonblocking(true)?;
         let r = unsafe {
             let (addrp, len) = addr.into_inner();
-            cvt(c::connect(self.0, addrp, len))
+            cvt(c::connect(self.0, addrp.as_ptr(), len))
         };
         self.set_nonblocking(false)?;
 
@@ -112,6 +112,37 @@ pub fn sockaddr_to_addr(storage: &c::sockaddr_storage,
     }
 }
 
+/// A `SocketAddr` laid out as the C socket address that the system calls
+/// expect.
+pub struct SocketAddrCRepr {
+    storage: c::sockaddr_storage,
+}
+
+impl SocketAddrCRepr {
+    pub fn as_ptr(&self) -> *const c::sockaddr {
+        &self.storage as *const _ as *const c::sockaddr
+    }
+}
+
+impl<'a> IntoInner<(SocketAddrCRepr, c::socklen_t)> for &'a SocketAddr {
+    fn into_inner(self) -> (SocketAddrCRepr, c::socklen_t) {
+        let mut storage: c::sockaddr_storage = unsafe { mem::zeroed() };
+        let len = match *self {
+            SocketAddr::V4(a) => {
+                let a: c::sockaddr_in = a.into_inner();
+                unsafe { *(&mut storage as *mut _ as *mut c::sockaddr_in) = a };
+                mem::size_of_val(&a)
+            }
+            SocketAddr::V6(a) => {
+                let a: c::sockaddr_in6 = a.into_inner();
+                unsafe { *(&mut storage as *mut _ as *mut c::sockaddr_in6) = a };
+                mem::size_of_val(&a)
+            }
+        };
+        (SocketAddrCRepr { storage: storage }, len as c::socklen_t)
+    }
+}
+
 #[cfg(target_os = "android")]
 fn to_ipv6mr_interface(value: u32) -> c_int {
     value as c_int
@@ -203,7 +234,7 @@ impl TcpStream {
         let sock = Socket::new(addr, c::SOCK_STREAM)?;
 
         let (addrp, len) = addr.into_inner();
-        cvt_r(|| unsafe { c::connect(*sock.as_inner(), addrp, len) })?;
+        cvt_r(|| unsafe { c::connect(*sock.as_inner(), addrp.as_ptr(), len) })?;
         Ok(TcpStream { inner: sock })
     }
 
@@ -348,7 +379,7 @@ impl TcpListener {
 
         // Bind our new socket
         let (addrp, len) = addr.into_inner();
-        cvt(unsafe { c::bind(*sock.as_inner(), addrp, len as _) })?;
+        cvt(unsafe { c::bind(*sock.as_inner(), addrp.as_ptr(), len as _) })?;
 
         // Start listening
         cvt(unsafe { c::listen(*sock.as_inner(), 128) })?;
@@ -439,7 +470,7 @@ impl UdpSocket {
 
         let sock = Socket::new(addr, c::SOCK_DGRAM)?;
         let (addrp, len) = addr.into_inner();
-        cvt(unsafe { c::bind(*sock.as_inner(), addrp, len as _) })?;
+        cvt(unsafe { c::bind(*sock.as_inner(), addrp.as_ptr(), len as _) })?;
         Ok(UdpSocket { inner: sock })
     }
 
@@ -467,7 +498,7 @@ impl UdpSocket {
         let ret = cvt(unsafe {
             c::sendto(*self.inner.as_inner(),
                       buf.as_ptr() as *const c_void, len,
-                      MSG_NOSIGNAL, dstp, dstlen)
+                      MSG_NOSIGNAL, dstp.as_ptr(), dstlen)
         })?;
         Ok(ret as usize)
     }
@@ -531,8 +562,8 @@ impl UdpSocket {
     pub fn join_multicast_v4(&self, multiaddr: &Ipv4Addr, interface: &Ipv4Addr)
                          -> io::Result<()> {
         let mreq = c::ip_mreq {
-            imr_multiaddr: *multiaddr.as_inner(),
-            imr_interface: *interface.as_inner(),
+            imr_multiaddr: multiaddr.into_inner(),
+            imr_interface: interface.into_inner(),
         };
         setsockopt(&self.inner, c::IPPROTO_IP, c::IP_ADD_MEMBERSHIP, mreq)
     }
@@ -540,7 +571,7 @@ impl UdpSocket {
     pub fn join_multicast_v6(&self, multiaddr: &Ipv6Addr, interface: u32)
                          -> io::Result<()> {
         let mreq = c::ipv6_mreq {
-            ipv6mr_multiaddr: *multiaddr.as_inner(),
+            ipv6mr_multiaddr: multiaddr.into_inner(),
             ipv6mr_interface: to_ipv6mr_interface(interface),
         };
         setsockopt(&self.inner, c::IPPROTO_IPV6, IPV6_ADD_MEMBERSHIP, mreq)
@@ -549,8 +580,8 @@ impl UdpSocket {
     pub fn leave_multicast_v4(&self, multiaddr: &Ipv4Addr, interface: &Ipv4Addr)
                           -> io::Result<()> {
         let mreq = c::ip_mreq {
-            imr_multiaddr: *multiaddr.as_inner(),
-            imr_interface: *interface.as_inner(),
+            imr_multiaddr: multiaddr.into_inner(),
+            imr_interface: interface.into_inner(),
         };
         setsockopt(&self.inner, c::IPPROTO_IP, c::IP_DROP_MEMBERSHIP, mreq)
     }
@@ -558,7 +589,7 @@ impl UdpSocket {
     pub fn leave_multicast_v6(&self, multiaddr: &Ipv6Addr, interface: u32)
                           -> io::Result<()> {
         let mreq = c::ipv6_mreq {
-            ipv6mr_multiaddr: *multiaddr.as_inner(),
+            ipv6mr_multiaddr: multiaddr.into_inner(),
             ipv6mr_interface: to_ipv6mr_interface(interface),
         };
         setsockopt(&self.inner, c::IPPROTO_IPV6, IPV6_DROP_MEMBERSHIP, mreq)
@@ -602,7 +633,7 @@ impl UdpSocket {
 
     pub fn connect(&self, addr: &SocketAddr) -> io::Result<()> {
         let (addrp, len) = addr.into_inner();
-        cvt_r(|| unsafe { c::connect(*self.inner.as_inner(), addrp, len) }).map(|_| ())
+        cvt_r(|| unsafe { c::connect(*self.inner.as_inner(), addrp.as_ptr(), len) }).map(|_| ())
     }
 }
 
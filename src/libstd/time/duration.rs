@@ -8,11 +8,15 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use cmp;
+use error::Error;
+use fmt::{self, Write};
 use iter::Sum;
 use ops::{Add, Sub, Mul, Div, AddAssign, SubAssign, MulAssign, DivAssign};
 
 const NANOS_PER_SEC: u32 = 1_000_000_000;
 const NANOS_PER_MILLI: u32 = 1_000_000;
+const NANOS_PER_MICRO: u32 = 1_000;
 const MILLIS_PER_SEC: u64 = 1_000;
 
 /// A `Duration` type to represent a span of time, typically used for system
@@ -51,6 +55,86 @@ pub struct Duration {
 }
 
 impl Duration {
+    /// A duration of zero time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_constants)]
+    /// use std::time::Duration;
+    ///
+    /// let duration = Duration::ZERO;
+    /// assert!(duration.is_zero());
+    /// assert_eq!(duration, Duration::new(0, 0));
+    /// ```
+    #[unstable(feature = "duration_constants", issue = "0")]
+    pub const ZERO: Duration = Duration { secs: 0, nanos: 0 };
+
+    /// The maximum duration, roughly 584,942,417,355 years.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_constants)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::MAX, Duration::new(u64::max_value(), 999_999_999));
+    /// ```
+    #[unstable(feature = "duration_constants", issue = "0")]
+    pub const MAX: Duration = Duration { secs: ::u64::MAX, nanos: NANOS_PER_SEC - 1 };
+
+    /// The duration of one second.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_constants)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::SECOND, Duration::from_secs(1));
+    /// ```
+    #[unstable(feature = "duration_constants", issue = "0")]
+    pub const SECOND: Duration = Duration { secs: 1, nanos: 0 };
+
+    /// The duration of one millisecond.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_constants)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::MILLISECOND, Duration::from_millis(1));
+    /// ```
+    #[unstable(feature = "duration_constants", issue = "0")]
+    pub const MILLISECOND: Duration = Duration { secs: 0, nanos: NANOS_PER_MILLI };
+
+    /// The duration of one microsecond.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_constants)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::MICROSECOND, Duration::new(0, 1_000));
+    /// ```
+    #[unstable(feature = "duration_constants", issue = "0")]
+    pub const MICROSECOND: Duration = Duration { secs: 0, nanos: NANOS_PER_MICRO };
+
+    /// The duration of one nanosecond.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_constants)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::NANOSECOND, Duration::new(0, 1));
+    /// ```
+    #[unstable(feature = "duration_constants", issue = "0")]
+    pub const NANOSECOND: Duration = Duration { secs: 0, nanos: 1 };
+
     /// Creates a new `Duration` from the specified number of whole seconds and
     /// additional nanoseconds.
     ///
@@ -167,6 +251,370 @@ impl Duration {
     #[inline]
     pub fn subsec_nanos(&self) -> u32 { self.nanos }
 
+    /// Returns true if this `Duration` spans no time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_constants)]
+    /// use std::time::Duration;
+    ///
+    /// assert!(Duration::ZERO.is_zero());
+    /// assert!(Duration::new(0, 0).is_zero());
+    /// assert!(Duration::from_millis(0).is_zero());
+    ///
+    /// assert!(!Duration::new(1, 1).is_zero());
+    /// assert!(!Duration::new(0, 1).is_zero());
+    /// ```
+    #[unstable(feature = "duration_constants", issue = "0")]
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.secs == 0 && self.nanos == 0
+    }
+
+    /// Creates a new `Duration` from the specified number of seconds
+    /// represented as `f64`.
+    ///
+    /// The fractional part is truncated to whole nanoseconds.
+    ///
+    /// # Panics
+    ///
+    /// This constructor will panic if `secs` is negative, not finite, or
+    /// overflows the seconds counter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_float)]
+    /// use std::time::Duration;
+    ///
+    /// let duration = Duration::from_secs_f64(2.7);
+    /// assert_eq!(duration, Duration::new(2, 700_000_000));
+    /// ```
+    #[unstable(feature = "duration_float", issue = "0")]
+    #[inline]
+    pub fn from_secs_f64(secs: f64) -> Duration {
+        match Duration::try_from_secs_f64(secs) {
+            Ok(duration) => duration,
+            Err(e) => panic!("{}", e.description()),
+        }
+    }
+
+    /// Creates a new `Duration` from the specified number of seconds
+    /// represented as `f32`.
+    ///
+    /// The fractional part is truncated to whole nanoseconds.
+    ///
+    /// # Panics
+    ///
+    /// This constructor will panic if `secs` is negative, not finite, or
+    /// overflows the seconds counter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_float)]
+    /// use std::time::Duration;
+    ///
+    /// let duration = Duration::from_secs_f32(0.25);
+    /// assert_eq!(duration, Duration::from_millis(250));
+    /// ```
+    #[unstable(feature = "duration_float", issue = "0")]
+    #[inline]
+    pub fn from_secs_f32(secs: f32) -> Duration {
+        // Every `f32` is exactly representable as an `f64`.
+        Duration::from_secs_f64(secs as f64)
+    }
+
+    /// The checked version of [`from_secs_f64`].
+    ///
+    /// Returns an error instead of panicking if `secs` is negative, not
+    /// finite, or overflows the seconds counter.
+    ///
+    /// [`from_secs_f64`]: #method.from_secs_f64
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_checked_float)]
+    /// use std::f64;
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::try_from_secs_f64(2.7), Ok(Duration::new(2, 700_000_000)));
+    /// assert!(Duration::try_from_secs_f64(-1.0).is_err());
+    /// assert!(Duration::try_from_secs_f64(f64::NAN).is_err());
+    /// assert!(Duration::try_from_secs_f64(1e20).is_err());
+    /// ```
+    #[unstable(feature = "duration_checked_float", issue = "0")]
+    #[inline]
+    pub fn try_from_secs_f64(secs: f64) -> Result<Duration, FromSecsError> {
+        let nanos = secs * (NANOS_PER_SEC as f64);
+        let kind = if !nanos.is_finite() {
+            FromSecsErrorKind::NonFinite
+        } else if nanos >= (::u64::MAX as f64) * (NANOS_PER_SEC as f64) {
+            FromSecsErrorKind::Overflow
+        } else if nanos < 0.0 {
+            FromSecsErrorKind::Negative
+        } else {
+            let nanos = nanos as u128;
+            return Ok(Duration {
+                secs: (nanos / (NANOS_PER_SEC as u128)) as u64,
+                nanos: (nanos % (NANOS_PER_SEC as u128)) as u32,
+            });
+        };
+        Err(FromSecsError { kind: kind })
+    }
+
+    /// The checked version of [`from_secs_f32`].
+    ///
+    /// Returns an error instead of panicking if `secs` is negative, not
+    /// finite, or overflows the seconds counter.
+    ///
+    /// [`from_secs_f32`]: #method.from_secs_f32
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_checked_float)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::try_from_secs_f32(0.25), Ok(Duration::from_millis(250)));
+    /// assert!(Duration::try_from_secs_f32(-0.25).is_err());
+    /// ```
+    #[unstable(feature = "duration_checked_float", issue = "0")]
+    #[inline]
+    pub fn try_from_secs_f32(secs: f32) -> Result<Duration, FromSecsError> {
+        Duration::try_from_secs_f64(secs as f64)
+    }
+
+    /// Returns the number of seconds contained by this `Duration` as `f64`.
+    ///
+    /// The returned value does include the fractional (nanosecond) part of
+    /// the duration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_float)]
+    /// use std::time::Duration;
+    ///
+    /// let duration = Duration::new(2, 700_000_000);
+    /// assert_eq!(duration.as_secs_f64(), 2.7);
+    /// ```
+    #[unstable(feature = "duration_float", issue = "0")]
+    #[inline]
+    pub fn as_secs_f64(&self) -> f64 {
+        (self.secs as f64) + (self.nanos as f64) / (NANOS_PER_SEC as f64)
+    }
+
+    /// Returns the number of seconds contained by this `Duration` as `f32`.
+    ///
+    /// The returned value does include the fractional (nanosecond) part of
+    /// the duration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_float)]
+    /// use std::time::Duration;
+    ///
+    /// let duration = Duration::new(2, 700_000_000);
+    /// assert_eq!(duration.as_secs_f32(), 2.7);
+    /// ```
+    #[unstable(feature = "duration_float", issue = "0")]
+    #[inline]
+    pub fn as_secs_f32(&self) -> f32 {
+        (self.secs as f32) + (self.nanos as f32) / (NANOS_PER_SEC as f32)
+    }
+
+    /// Returns the number of milliseconds contained by this `Duration` as
+    /// `f64`.
+    ///
+    /// The returned value does include the fractional (nanosecond) part of
+    /// the duration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_float)]
+    /// use std::time::Duration;
+    ///
+    /// let duration = Duration::new(2, 345_678_000);
+    /// assert_eq!(duration.as_millis_f64(), 2345.678);
+    /// ```
+    #[unstable(feature = "duration_float", issue = "0")]
+    #[inline]
+    pub fn as_millis_f64(&self) -> f64 {
+        (self.secs as f64) * (MILLIS_PER_SEC as f64) +
+            (self.nanos as f64) / (NANOS_PER_MILLI as f64)
+    }
+
+    /// Multiplies `Duration` by `f64`.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the result is negative, not finite, or
+    /// overflows the seconds counter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_float)]
+    /// use std::time::Duration;
+    ///
+    /// let dur = Duration::new(2, 700_000_000);
+    /// assert_eq!(dur.mul_f64(3.14), Duration::new(8, 478_000_000));
+    /// assert_eq!(dur.mul_f64(3.14e5), Duration::new(847_800, 0));
+    /// ```
+    #[unstable(feature = "duration_float", issue = "0")]
+    #[inline]
+    pub fn mul_f64(self, rhs: f64) -> Duration {
+        Duration::from_secs_f64(rhs * self.as_secs_f64())
+    }
+
+    /// Multiplies `Duration` by `f32`.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the result is negative, not finite, or
+    /// overflows the seconds counter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_float)]
+    /// use std::time::Duration;
+    ///
+    /// let dur = Duration::new(2, 0);
+    /// assert_eq!(dur.mul_f32(0.75), Duration::new(1, 500_000_000));
+    /// ```
+    #[unstable(feature = "duration_float", issue = "0")]
+    #[inline]
+    pub fn mul_f32(self, rhs: f32) -> Duration {
+        Duration::from_secs_f64((rhs as f64) * self.as_secs_f64())
+    }
+
+    /// Divides `Duration` by `f64`.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the result is negative, not finite, or
+    /// overflows the seconds counter. In particular it panics when `rhs` is
+    /// zero, unless `self` is zero too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_float)]
+    /// use std::time::Duration;
+    ///
+    /// let dur = Duration::new(2, 700_000_000);
+    /// assert_eq!(dur.div_f64(3.14), Duration::new(0, 859_872_611));
+    /// assert_eq!(dur.div_f64(3.14e5), Duration::new(0, 8_598));
+    /// ```
+    #[unstable(feature = "duration_float", issue = "0")]
+    #[inline]
+    pub fn div_f64(self, rhs: f64) -> Duration {
+        Duration::from_secs_f64(self.as_secs_f64() / rhs)
+    }
+
+    /// Divides `Duration` by `f32`.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the result is negative, not finite, or
+    /// overflows the seconds counter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_float)]
+    /// use std::time::Duration;
+    ///
+    /// let dur = Duration::new(3, 0);
+    /// assert_eq!(dur.div_f32(4.0), Duration::new(0, 750_000_000));
+    /// ```
+    #[unstable(feature = "duration_float", issue = "0")]
+    #[inline]
+    pub fn div_f32(self, rhs: f32) -> Duration {
+        Duration::from_secs_f64(self.as_secs_f64() / (rhs as f64))
+    }
+
+    /// Divides `Duration` by `Duration` and returns `f64`.
+    ///
+    /// Unlike dividing nanosecond counts held in a `u64`, this can't overflow
+    /// for any pair of durations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_float)]
+    /// use std::time::Duration;
+    ///
+    /// let dur1 = Duration::new(2, 700_000_000);
+    /// let dur2 = Duration::new(5, 400_000_000);
+    /// assert_eq!(dur1.div_duration_f64(dur2), 0.5);
+    /// ```
+    #[unstable(feature = "duration_float", issue = "0")]
+    #[inline]
+    pub fn div_duration_f64(self, rhs: Duration) -> f64 {
+        self.as_nanos_u128() as f64 / rhs.as_nanos_u128() as f64
+    }
+
+    /// Divides `Duration` by `Duration` and returns `f32`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_float)]
+    /// use std::time::Duration;
+    ///
+    /// let dur1 = Duration::new(2, 700_000_000);
+    /// let dur2 = Duration::new(5, 400_000_000);
+    /// assert_eq!(dur1.div_duration_f32(dur2), 0.5);
+    /// ```
+    #[unstable(feature = "duration_float", issue = "0")]
+    #[inline]
+    pub fn div_duration_f32(self, rhs: Duration) -> f32 {
+        self.div_duration_f64(rhs) as f32
+    }
+
+    /// The total number of nanoseconds, which always fits in a `u128`.
+    #[inline]
+    fn as_nanos_u128(&self) -> u128 {
+        (self.secs as u128) * (NANOS_PER_SEC as u128) + (self.nanos as u128)
+    }
+
+    /// Returns an object that implements [`Display`] by rendering this
+    /// `Duration` in the largest unit that keeps the integer part non-zero,
+    /// such as `1.5s`, `250ms`, `3µs` or `12ns`.
+    ///
+    /// By default all significant fractional digits are shown. A precision
+    /// rounds the fractional part to that many digits instead, and the width,
+    /// fill and alignment flags pad the result like a string.
+    ///
+    /// [`Display`]: ../../std/fmt/trait.Display.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_display)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::from_millis(1500).display().to_string(), "1.5s");
+    /// assert_eq!(Duration::from_millis(250).display().to_string(), "250ms");
+    /// assert_eq!(Duration::new(0, 3_000).display().to_string(), "3µs");
+    /// assert_eq!(Duration::new(0, 1_234_567).display().to_string(), "1.234567ms");
+    ///
+    /// assert_eq!(format!("{:.2}", Duration::new(0, 1_234_567).display()), "1.23ms");
+    /// assert_eq!(format!("{:>8.1}", Duration::new(2, 960_000_000).display()), "    3.0s");
+    /// ```
+    #[unstable(feature = "duration_display", issue = "0")]
+    #[inline]
+    pub fn display(&self) -> DurationDisplay {
+        DurationDisplay { duration: *self }
+    }
+
     /// Checked `Duration` addition. Computes `self + other`, returning [`None`]
     /// if overflow occurred.
     ///
@@ -241,6 +689,26 @@ impl Duration {
         }
     }
 
+    /// Computes the absolute difference between `self` and `other`.
+    ///
+    /// Unlike `self - other`, this doesn't panic when `other` is the larger
+    /// of the two.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_abs_diff)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::new(100, 0).abs_diff(Duration::new(80, 0)), Duration::new(20, 0));
+    /// assert_eq!(Duration::new(80, 0).abs_diff(Duration::new(100, 0)), Duration::new(20, 0));
+    /// ```
+    #[unstable(feature = "duration_abs_diff", issue = "0")]
+    #[inline]
+    pub fn abs_diff(self, other: Duration) -> Duration {
+        if self > other { self - other } else { other - self }
+    }
+
     /// Checked `Duration` multiplication. Computes `self * other`, returning
     /// [`None`] if overflow occurred.
     ///
@@ -306,6 +774,75 @@ impl Duration {
             None
         }
     }
+
+    /// Saturating `Duration` addition. Computes `self + other`, returning
+    /// [`Duration::MAX`] if overflow occurred.
+    ///
+    /// [`Duration::MAX`]: #associatedconstant.MAX
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_saturating_ops, duration_constants)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::new(0, 0).saturating_add(Duration::new(0, 1)), Duration::new(0, 1));
+    /// assert_eq!(Duration::new(1, 0).saturating_add(Duration::MAX), Duration::MAX);
+    /// ```
+    #[unstable(feature = "duration_saturating_ops", issue = "0")]
+    #[inline]
+    pub fn saturating_add(self, rhs: Duration) -> Duration {
+        match self.checked_add(rhs) {
+            Some(res) => res,
+            None => Duration::MAX,
+        }
+    }
+
+    /// Saturating `Duration` subtraction. Computes `self - other`, returning
+    /// [`Duration::ZERO`] if the result would be negative.
+    ///
+    /// [`Duration::ZERO`]: #associatedconstant.ZERO
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_saturating_ops, duration_constants)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::new(0, 1).saturating_sub(Duration::new(0, 0)), Duration::new(0, 1));
+    /// assert_eq!(Duration::new(0, 0).saturating_sub(Duration::new(0, 1)), Duration::ZERO);
+    /// ```
+    #[unstable(feature = "duration_saturating_ops", issue = "0")]
+    #[inline]
+    pub fn saturating_sub(self, rhs: Duration) -> Duration {
+        match self.checked_sub(rhs) {
+            Some(res) => res,
+            None => Duration::ZERO,
+        }
+    }
+
+    /// Saturating `Duration` multiplication. Computes `self * other`,
+    /// returning [`Duration::MAX`] if overflow occurred.
+    ///
+    /// [`Duration::MAX`]: #associatedconstant.MAX
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_saturating_ops, duration_constants)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::new(0, 500_000_001).saturating_mul(2), Duration::new(1, 2));
+    /// assert_eq!(Duration::new(u64::max_value() - 1, 0).saturating_mul(2), Duration::MAX);
+    /// ```
+    #[unstable(feature = "duration_saturating_ops", issue = "0")]
+    #[inline]
+    pub fn saturating_mul(self, rhs: u32) -> Duration {
+        match self.checked_mul(rhs) {
+            Some(res) => res,
+            None => Duration::MAX,
+        }
+    }
 }
 
 #[stable(feature = "duration", since = "1.3.0")]
@@ -372,17 +909,229 @@ impl DivAssign<u32> for Duration {
     }
 }
 
+/// An error which can be returned when converting a floating-point number
+/// of seconds into a [`Duration`].
+///
+/// This error is used as the error type for [`Duration::try_from_secs_f64`]
+/// and [`Duration::try_from_secs_f32`].
+///
+/// [`Duration`]: struct.Duration.html
+/// [`Duration::try_from_secs_f64`]: struct.Duration.html#method.try_from_secs_f64
+/// [`Duration::try_from_secs_f32`]: struct.Duration.html#method.try_from_secs_f32
+#[unstable(feature = "duration_checked_float", issue = "0")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FromSecsError {
+    kind: FromSecsErrorKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FromSecsErrorKind {
+    NonFinite,
+    Overflow,
+    Negative,
+}
+
+#[unstable(feature = "duration_checked_float", issue = "0")]
+impl Error for FromSecsError {
+    fn description(&self) -> &str {
+        match self.kind {
+            FromSecsErrorKind::NonFinite => {
+                "got non-finite value when converting float to duration"
+            }
+            FromSecsErrorKind::Overflow => "overflow when converting float to duration",
+            FromSecsErrorKind::Negative => {
+                "cannot convert float seconds to Duration: value is negative"
+            }
+        }
+    }
+}
+
+#[unstable(feature = "duration_checked_float", issue = "0")]
+impl fmt::Display for FromSecsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self.description(), f)
+    }
+}
+
+/// Helper struct for printing a [`Duration`] in human-friendly units.
+///
+/// This `struct` is created by the [`display`] method on [`Duration`].
+///
+/// [`Duration`]: struct.Duration.html
+/// [`display`]: struct.Duration.html#method.display
+#[unstable(feature = "duration_display", issue = "0")]
+#[derive(Debug)]
+pub struct DurationDisplay {
+    duration: Duration,
+}
+
+#[unstable(feature = "duration_display", issue = "0")]
+impl fmt::Display for DurationDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Duration { secs, nanos } = self.duration;
+        if secs > 0 {
+            fmt_decimal(f, secs, nanos, NANOS_PER_SEC / 10, "s")
+        } else if nanos >= NANOS_PER_MILLI {
+            fmt_decimal(f, (nanos / NANOS_PER_MILLI) as u64, nanos % NANOS_PER_MILLI,
+                        NANOS_PER_MILLI / 10, "ms")
+        } else if nanos >= NANOS_PER_MICRO {
+            fmt_decimal(f, (nanos / NANOS_PER_MICRO) as u64, nanos % NANOS_PER_MICRO,
+                        NANOS_PER_MICRO / 10, "µs")
+        } else {
+            fmt_decimal(f, nanos as u64, 0, 1, "ns")
+        }
+    }
+}
+
+/// Writes `integer_part.fractional_part` followed by `suffix`, where
+/// `divisor` is the place value of the first fractional digit.
+fn fmt_decimal(f: &mut fmt::Formatter, integer_part: u64, mut fractional_part: u32,
+               mut divisor: u32, suffix: &str) -> fmt::Result {
+    // Rounding up `u64::MAX` seconds needs one more bit.
+    let mut integer_part = integer_part as u128;
+
+    // A fractional part never has more than 9 digits.
+    let mut buf = [b'0'; 9];
+    let end = f.precision().map(|p| cmp::min(p, 9)).unwrap_or(9);
+    let mut pos = 0;
+    while fractional_part > 0 && pos < end {
+        buf[pos] = b'0' + (fractional_part / divisor) as u8;
+        fractional_part %= divisor;
+        divisor /= 10;
+        pos += 1;
+    }
+
+    // Round half up on whatever digits didn't fit the precision.
+    if fractional_part > 0 && fractional_part >= divisor * 5 {
+        let mut carry = true;
+        let mut i = pos;
+        while carry && i > 0 {
+            i -= 1;
+            if buf[i] < b'9' {
+                buf[i] += 1;
+                carry = false;
+            } else {
+                buf[i] = b'0';
+            }
+        }
+        if carry {
+            integer_part += 1;
+        }
+    }
+
+    let mut s = integer_part.to_string();
+    let digits = f.precision().unwrap_or(pos);
+    if digits > 0 {
+        s.push('.');
+        for &b in &buf[..cmp::min(digits, 9)] {
+            s.push(b as char);
+        }
+        for _ in 9..digits {
+            s.push('0');
+        }
+    }
+    s.push_str(suffix);
+
+    // `Formatter::pad` would treat the precision as a maximum width, so the
+    // padding is done by hand.
+    let len = s.chars().count();
+    let padding = match f.width() {
+        Some(width) if width > len => width - len,
+        _ => return f.write_str(&s),
+    };
+    let (pre, post) = match f.align() {
+        fmt::Alignment::Right => (padding, 0),
+        fmt::Alignment::Center => (padding / 2, (padding + 1) / 2),
+        fmt::Alignment::Left | fmt::Alignment::Unknown => (0, padding),
+    };
+    let fill = f.fill();
+    for _ in 0..pre {
+        f.write_char(fill)?;
+    }
+    f.write_str(&s)?;
+    for _ in 0..post {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+/// Sums an iterator of `Duration`s.
+///
+/// # Panics
+///
+/// Like [`Add`], this panics if the total overflows the seconds counter.
+/// Summing into an `Option<Duration>` instead yields `None` on overflow.
+///
+/// [`Add`]: ../../std/ops/trait.Add.html
 #[stable(feature = "duration_sum", since = "1.16.0")]
 impl Sum for Duration {
     fn sum<I: Iterator<Item=Duration>>(iter: I) -> Duration {
-        iter.fold(Duration::new(0, 0), |a, b| a + b)
+        iter.fold(Duration::ZERO, |a, b| a + b)
     }
 }
 
+/// Sums an iterator of `&Duration`s.
+///
+/// # Panics
+///
+/// Like [`Add`], this panics if the total overflows the seconds counter.
+/// Summing into an `Option<Duration>` instead yields `None` on overflow.
+///
+/// [`Add`]: ../../std/ops/trait.Add.html
 #[stable(feature = "duration_sum", since = "1.16.0")]
 impl<'a> Sum<&'a Duration> for Duration {
     fn sum<I: Iterator<Item=&'a Duration>>(iter: I) -> Duration {
-        iter.fold(Duration::new(0, 0), |a, b| a + *b)
+        iter.fold(Duration::ZERO, |a, b| a + *b)
+    }
+}
+
+/// Sums an iterator of `Duration`s, returning `None` if the total
+/// overflows.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(duration_checked_sum)]
+/// use std::time::Duration;
+///
+/// let samples = [Duration::from_millis(1500), Duration::from_millis(250)];
+/// assert_eq!(samples.iter().cloned().sum::<Option<Duration>>(),
+///            Some(Duration::from_millis(1750)));
+///
+/// let huge = [Duration::new(u64::max_value(), 0), Duration::new(1, 0)];
+/// assert_eq!(huge.iter().cloned().sum::<Option<Duration>>(), None);
+/// ```
+#[unstable(feature = "duration_checked_sum", issue = "0")]
+impl Sum<Duration> for Option<Duration> {
+    fn sum<I: Iterator<Item=Duration>>(iter: I) -> Option<Duration> {
+        let mut total = Duration::ZERO;
+        for d in iter {
+            total = match total.checked_add(d) {
+                Some(total) => total,
+                None => return None,
+            };
+        }
+        Some(total)
+    }
+}
+
+/// Sums an iterator of `&Duration`s, returning `None` if the total
+/// overflows.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(duration_checked_sum)]
+/// use std::time::Duration;
+///
+/// let samples = [Duration::from_millis(1500), Duration::from_millis(250)];
+/// assert_eq!(samples.iter().sum::<Option<Duration>>(),
+///            Some(Duration::from_millis(1750)));
+/// ```
+#[unstable(feature = "duration_checked_sum", issue = "0")]
+impl<'a> Sum<&'a Duration> for Option<Duration> {
+    fn sum<I: Iterator<Item=&'a Duration>>(iter: I) -> Option<Duration> {
+        iter.map(|d| *d).sum()
     }
 }
 
@@ -418,6 +1167,80 @@ mod tests {
         assert_eq!(Duration::from_millis(1001).subsec_nanos(), 1 * 1_000_000);
     }
 
+    #[test]
+    fn float_conversions() {
+        assert_eq!(Duration::from_secs_f64(0.0), Duration::new(0, 0));
+        assert_eq!(Duration::from_secs_f64(1.5), Duration::new(1, 500_000_000));
+        assert_eq!(Duration::from_secs_f32(1.5), Duration::new(1, 500_000_000));
+        assert_eq!(Duration::from_secs_f64(1e-9), Duration::new(0, 1));
+        assert_eq!(Duration::new(1, 500_000_000).as_secs_f64(), 1.5);
+        assert_eq!(Duration::new(1, 500_000_000).as_secs_f32(), 1.5);
+        assert_eq!(Duration::new(1, 500_000).as_millis_f64(), 1000.5);
+
+        let d = Duration::new(12_345, 678_000_000);
+        assert_eq!(Duration::from_secs_f64(d.as_secs_f64()).as_secs(), 12_345);
+    }
+
+    #[test]
+    fn float_scaling() {
+        let d = Duration::new(2, 500_000_000);
+        assert_eq!(d.mul_f64(2.0), Duration::new(5, 0));
+        assert_eq!(d.mul_f32(0.5), Duration::new(1, 250_000_000));
+        assert_eq!(d.div_f64(0.5), Duration::new(5, 0));
+        assert_eq!(d.div_f32(2.0), Duration::new(1, 250_000_000));
+        assert_eq!(d.div_duration_f64(Duration::new(5, 0)), 0.5);
+        assert_eq!(d.div_duration_f32(Duration::new(1, 250_000_000)), 2.0);
+
+        // Would overflow u64 if done on nanosecond counts.
+        let big = Duration::new(::u64::MAX / 2, 0);
+        assert_eq!(big.div_duration_f64(big), 1.0);
+    }
+
+    #[test] #[should_panic]
+    fn div_f64_by_zero() {
+        Duration::new(1, 0).div_f64(0.0);
+    }
+
+    #[test]
+    fn try_from_secs_float() {
+        assert_eq!(Duration::try_from_secs_f64(1.5), Ok(Duration::new(1, 500_000_000)));
+        assert_eq!(Duration::try_from_secs_f32(1.5), Ok(Duration::new(1, 500_000_000)));
+        assert_eq!(Duration::try_from_secs_f64(0.0), Ok(Duration::ZERO));
+
+        let bad = [-1.0, ::f64::NAN, ::f64::INFINITY, ::f64::NEG_INFINITY, 1e20];
+        for &secs in &bad {
+            assert!(Duration::try_from_secs_f64(secs).is_err());
+            assert!(Duration::try_from_secs_f32(secs as f32).is_err());
+        }
+        assert!(Duration::try_from_secs_f64(-1.0) != Duration::try_from_secs_f64(1e20));
+    }
+
+    #[test] #[should_panic]
+    fn from_secs_f64_negative() {
+        Duration::from_secs_f64(-1.0);
+    }
+
+    #[test] #[should_panic]
+    fn from_secs_f64_nan() {
+        Duration::from_secs_f64(::f64::NAN);
+    }
+
+    #[test] #[should_panic]
+    fn from_secs_f64_overflow() {
+        Duration::from_secs_f64(1e20);
+    }
+
+    #[test]
+    fn constants() {
+        assert!(Duration::ZERO.is_zero());
+        assert!(!Duration::NANOSECOND.is_zero());
+        assert_eq!(Duration::SECOND, Duration::MILLISECOND * 1000);
+        assert_eq!(Duration::MILLISECOND, Duration::MICROSECOND * 1000);
+        assert_eq!(Duration::MICROSECOND, Duration::NANOSECOND * 1000);
+        assert_eq!(Duration::MAX.checked_add(Duration::NANOSECOND), None);
+        assert_eq!(Duration::MAX - Duration::MAX, Duration::ZERO);
+    }
+
     #[test]
     fn add() {
         assert_eq!(Duration::new(0, 0) + Duration::new(0, 1),
@@ -457,6 +1280,16 @@ mod tests {
         assert_eq!(zero.checked_sub(one_sec), None);
     }
 
+    #[test]
+    fn abs_diff() {
+        let a = Duration::new(1, 0);
+        let b = Duration::new(0, 999_999_999);
+        assert_eq!(a.abs_diff(b), Duration::new(0, 1));
+        assert_eq!(b.abs_diff(a), Duration::new(0, 1));
+        assert_eq!(a.abs_diff(a), Duration::ZERO);
+        assert_eq!(Duration::MAX.abs_diff(Duration::ZERO), Duration::MAX);
+    }
+
     #[test] #[should_panic]
     fn sub_bad1() {
         Duration::new(0, 0) - Duration::new(0, 1);
@@ -500,4 +1333,61 @@ mod tests {
         assert_eq!(Duration::new(1, 0).checked_div(2), Some(Duration::new(0, 500_000_000)));
         assert_eq!(Duration::new(2, 0).checked_div(0), None);
     }
+
+    #[test]
+    fn saturating_ops() {
+        let max = Duration::MAX;
+        assert_eq!(Duration::new(0, 500_000_000).saturating_add(Duration::new(0, 500_000_001)),
+                   Duration::new(1, 1));
+        assert_eq!(max.saturating_add(Duration::NANOSECOND), max);
+        assert_eq!(Duration::new(1, 0).saturating_sub(Duration::new(0, 1)),
+                   Duration::new(0, 999_999_999));
+        assert_eq!(Duration::new(0, 1).saturating_sub(Duration::new(1, 0)), Duration::ZERO);
+        assert_eq!(Duration::new(1, 1).saturating_mul(3), Duration::new(3, 3));
+        assert_eq!(max.saturating_mul(2), max);
+    }
+
+    #[test]
+    fn display() {
+        fn show(d: Duration) -> String { d.display().to_string() }
+
+        assert_eq!(show(Duration::new(0, 0)), "0ns");
+        assert_eq!(show(Duration::new(0, 999)), "999ns");
+        assert_eq!(show(Duration::new(0, 1_500)), "1.5µs");
+        assert_eq!(show(Duration::new(0, 250_000_000)), "250ms");
+        assert_eq!(show(Duration::new(1, 500_000_000)), "1.5s");
+        assert_eq!(show(Duration::new(1, 1)), "1.000000001s");
+        assert_eq!(show(Duration::new(::u64::MAX, 999_999_999)),
+                   "18446744073709551615.999999999s");
+
+        let d = Duration::new(1, 996_000_000);
+        assert_eq!(format!("{:.0}", d.display()), "2s");
+        assert_eq!(format!("{:.2}", d.display()), "2.00s");
+        assert_eq!(format!("{:.3}", d.display()), "1.996s");
+        assert_eq!(format!("{:.11}", d.display()), "1.99600000000s");
+        assert_eq!(format!("{:.0}", Duration::new(::u64::MAX, 999_999_999).display()),
+                   "18446744073709551616s");
+
+        assert_eq!(format!("{:6}|", Duration::new(0, 5).display()), "5ns   |");
+        assert_eq!(format!("{:>6}", Duration::new(0, 5).display()), "   5ns");
+        assert_eq!(format!("{:*^7}", Duration::new(0, 5).display()), "**5ns**");
+    }
+
+    #[test]
+    fn sum() {
+        let durations = [Duration::new(0, 500_000_000), Duration::new(1, 500_000_001)];
+        assert_eq!(durations.iter().sum::<Duration>(), Duration::new(2, 1));
+        assert_eq!(durations.iter().cloned().sum::<Duration>(), Duration::new(2, 1));
+        assert_eq!(durations.iter().sum::<Option<Duration>>(), Some(Duration::new(2, 1)));
+
+        let overflow = [Duration::new(::u64::MAX, 999_999_999), Duration::new(0, 1)];
+        assert_eq!(overflow.iter().sum::<Option<Duration>>(), None);
+        assert_eq!(overflow.iter().cloned().sum::<Option<Duration>>(), None);
+    }
+
+    #[test] #[should_panic]
+    fn sum_overflow() {
+        let overflow = [Duration::new(::u64::MAX, 999_999_999), Duration::new(0, 1)];
+        overflow.iter().sum::<Duration>();
+    }
 }
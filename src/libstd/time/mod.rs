@@ -30,6 +30,10 @@ use sys_common::FromInner;
 
 #[stable(feature = "time", since = "1.3.0")]
 pub use self::duration::Duration;
+#[unstable(feature = "duration_display", issue = "0")]
+pub use self::duration::DurationDisplay;
+#[unstable(feature = "duration_checked_float", issue = "0")]
+pub use self::duration::FromSecsError;
 
 mod duration;
 
@@ -185,13 +185,17 @@ impl char {
     /// * `a-z`
     /// * `A-Z`
     ///
+    /// Up to radix 36 the letters are case-insensitive. Above that they are
+    /// distinct digits; see [`to_digit`] for the alphabet.
+    ///
     /// For a more comprehensive understanding of 'digit', see [`is_numeric`][is_numeric].
     ///
     /// [is_numeric]: #method.is_numeric
+    /// [`to_digit`]: #method.to_digit
     ///
     /// # Panics
     ///
-    /// Panics if given a radix larger than 36.
+    /// Panics if given a radix larger than 62.
     ///
     /// # Examples
     ///
@@ -210,7 +214,7 @@ impl char {
     ///
     /// let result = thread::spawn(|| {
     ///     // this panics
-    ///     '1'.is_digit(37);
+    ///     '1'.is_digit(63);
     /// }).join();
     ///
     /// assert!(result.is_err());
@@ -234,13 +238,21 @@ impl char {
     /// * `a-z`
     /// * `A-Z`
     ///
+    /// Up to radix 36, `a-z` and `A-Z` both stand for ten through thirty-five.
+    /// Radices from 37 to 62 are case-sensitive and use the alphabet `0-9`,
+    /// `A-Z`, `a-z` in that order, matching [`char::from_digit`], so `A` is
+    /// ten and `a` is thirty-six. Alphabets that skip characters, like
+    /// Bitcoin's base-58, still need their own lookup table.
+    ///
+    /// [`char::from_digit`]: char/fn.from_digit.html
+    ///
     /// # Errors
     ///
     /// Returns `None` if the `char` does not refer to a digit in the given radix.
     ///
     /// # Panics
     ///
-    /// Panics if given a radix larger than 36.
+    /// Panics if given a radix larger than 62.
     ///
     /// # Examples
     ///
@@ -258,13 +270,22 @@ impl char {
     /// assert_eq!('z'.to_digit(16), None);
     /// ```
     ///
+    /// Above radix 36, case matters:
+    ///
+    /// ```
+    /// assert_eq!('a'.to_digit(36), Some(10));
+    /// assert_eq!('A'.to_digit(62), Some(10));
+    /// assert_eq!('a'.to_digit(62), Some(36));
+    /// assert_eq!('z'.to_digit(61), None);
+    /// ```
+    ///
     /// Passing a large radix, causing a panic:
     ///
     /// ```
     /// use std::thread;
     ///
     /// let result = thread::spawn(|| {
-    ///     '1'.to_digit(37);
+    ///     '1'.to_digit(63);
     /// }).join();
     ///
     /// assert!(result.is_err());
@@ -568,6 +589,289 @@ impl char {
         C::encode_utf16(self, dst)
     }
 
+    /// Encodes this character as UTF-8 into the provided byte buffer,
+    /// and then returns the subslice of the buffer that contains the encoded character,
+    /// or `None` if the buffer is too small.
+    ///
+    /// This is the non-panicking version of [`encode_utf8`]. When it fails
+    /// the buffer is left untouched, and [`len_utf8`] tells how many bytes
+    /// would be needed.
+    ///
+    /// [`encode_utf8`]: #method.encode_utf8
+    /// [`len_utf8`]: #method.len_utf8
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(try_encode_char)]
+    ///
+    /// let mut b = [0; 2];
+    /// assert_eq!('ß'.try_encode_utf8(&mut b).map(|s| &*s), Some("ß"));
+    ///
+    /// // '€' needs three bytes
+    /// assert_eq!('€'.try_encode_utf8(&mut b), None);
+    /// assert_eq!('€'.len_utf8(), 3);
+    /// ```
+    #[unstable(feature = "try_encode_char", issue = "0")]
+    #[inline]
+    pub fn try_encode_utf8(self, dst: &mut [u8]) -> Option<&mut str> {
+        C::try_encode_utf8(self, dst)
+    }
+
+    /// Encodes this character as UTF-16 into the provided `u16` buffer,
+    /// and then returns the subslice of the buffer that contains the encoded character,
+    /// or `None` if the buffer is too small.
+    ///
+    /// This is the non-panicking version of [`encode_utf16`]. When it fails
+    /// the buffer is left untouched, and [`len_utf16`] tells how many `u16`s
+    /// would be needed.
+    ///
+    /// [`encode_utf16`]: #method.encode_utf16
+    /// [`len_utf16`]: #method.len_utf16
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(try_encode_char)]
+    ///
+    /// let mut b = [0; 1];
+    /// assert_eq!('ß'.try_encode_utf16(&mut b).map(|s| s.len()), Some(1));
+    ///
+    /// // '𝕊' needs a surrogate pair
+    /// assert_eq!('𝕊'.try_encode_utf16(&mut b), None);
+    /// assert_eq!('𝕊'.len_utf16(), 2);
+    /// ```
+    #[unstable(feature = "try_encode_char", issue = "0")]
+    #[inline]
+    pub fn try_encode_utf16(self, dst: &mut [u16]) -> Option<&mut [u16]> {
+        C::try_encode_utf16(self, dst)
+    }
+
+    /// Returns this `char` as an [ASCII character](../std/ascii/struct.Char.html)
+    /// if it is in the ASCII range, and `None` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_char)]
+    ///
+    /// let a = 'a'.as_ascii().unwrap();
+    /// assert_eq!(a.to_u8(), b'a');
+    /// assert_eq!('é'.as_ascii(), None);
+    /// ```
+    #[unstable(feature = "ascii_char", issue = "0")]
+    #[inline]
+    pub fn as_ascii(&self) -> Option<::core::ascii::Char> {
+        C::as_ascii(self)
+    }
+
+    /// Checks if the value is an ASCII alphabetic character:
+    ///
+    /// - U+0041 'A' ... U+005A 'Z', or
+    /// - U+0061 'a' ... U+007A 'z'.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_ctype)]
+    ///
+    /// assert!('G'.is_ascii_alphabetic());
+    /// assert!(!'é'.is_ascii_alphabetic());
+    /// ```
+    #[unstable(feature = "ascii_ctype", issue = "39658")]
+    #[inline]
+    pub const fn is_ascii_alphabetic(&self) -> bool {
+        (*self as u32 <= 0x7F) & (*self as u8).is_ascii_alphabetic()
+    }
+
+    /// Checks if the value is an ASCII uppercase character:
+    /// U+0041 'A' ... U+005A 'Z'.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_ctype)]
+    ///
+    /// assert!('G'.is_ascii_uppercase());
+    /// assert!(!'g'.is_ascii_uppercase());
+    /// ```
+    #[unstable(feature = "ascii_ctype", issue = "39658")]
+    #[inline]
+    pub const fn is_ascii_uppercase(&self) -> bool {
+        (*self as u32 <= 0x7F) & (*self as u8).is_ascii_uppercase()
+    }
+
+    /// Checks if the value is an ASCII lowercase character:
+    /// U+0061 'a' ... U+007A 'z'.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_ctype)]
+    ///
+    /// assert!('g'.is_ascii_lowercase());
+    /// assert!(!'G'.is_ascii_lowercase());
+    /// ```
+    #[unstable(feature = "ascii_ctype", issue = "39658")]
+    #[inline]
+    pub const fn is_ascii_lowercase(&self) -> bool {
+        (*self as u32 <= 0x7F) & (*self as u8).is_ascii_lowercase()
+    }
+
+    /// Checks if the value is an ASCII alphanumeric character:
+    ///
+    /// - U+0041 'A' ... U+005A 'Z', or
+    /// - U+0061 'a' ... U+007A 'z', or
+    /// - U+0030 '0' ... U+0039 '9'.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_ctype)]
+    ///
+    /// assert!('0'.is_ascii_alphanumeric());
+    /// assert!(!'%'.is_ascii_alphanumeric());
+    /// ```
+    #[unstable(feature = "ascii_ctype", issue = "39658")]
+    #[inline]
+    pub const fn is_ascii_alphanumeric(&self) -> bool {
+        (*self as u32 <= 0x7F) & (*self as u8).is_ascii_alphanumeric()
+    }
+
+    /// Checks if the value is an ASCII decimal digit:
+    /// U+0030 '0' ... U+0039 '9'.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_ctype)]
+    ///
+    /// assert!('7'.is_ascii_digit());
+    /// assert!(!'٣'.is_ascii_digit());
+    /// ```
+    #[unstable(feature = "ascii_ctype", issue = "39658")]
+    #[inline]
+    pub const fn is_ascii_digit(&self) -> bool {
+        (*self as u32 <= 0x7F) & (*self as u8).is_ascii_digit()
+    }
+
+    /// Checks if the value is an ASCII octal digit:
+    /// U+0030 '0' ... U+0037 '7'.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(is_ascii_octdigit)]
+    ///
+    /// assert!('7'.is_ascii_octdigit());
+    /// assert!(!'8'.is_ascii_octdigit());
+    /// ```
+    #[unstable(feature = "is_ascii_octdigit", issue = "0")]
+    #[inline]
+    pub const fn is_ascii_octdigit(&self) -> bool {
+        (*self as u32 <= 0x7F) & (*self as u8).is_ascii_octdigit()
+    }
+
+    /// Checks if the value is an ASCII hexadecimal digit:
+    ///
+    /// - U+0030 '0' ... U+0039 '9', or
+    /// - U+0041 'A' ... U+0046 'F', or
+    /// - U+0061 'a' ... U+0066 'f'.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_ctype)]
+    ///
+    /// assert!('f'.is_ascii_hexdigit());
+    /// assert!(!'g'.is_ascii_hexdigit());
+    /// ```
+    #[unstable(feature = "ascii_ctype", issue = "39658")]
+    #[inline]
+    pub const fn is_ascii_hexdigit(&self) -> bool {
+        (*self as u32 <= 0x7F) & (*self as u8).is_ascii_hexdigit()
+    }
+
+    /// Checks if the value is an ASCII punctuation character:
+    ///
+    /// - U+0021 ... U+002F `! " # $ % & ' ( ) * + , - . /`, or
+    /// - U+003A ... U+0040 `: ; < = > ? @`, or
+    /// - U+005B ... U+0060 ``[ \ ] ^ _ ` ``, or
+    /// - U+007B ... U+007E `{ | } ~`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_ctype)]
+    ///
+    /// assert!('%'.is_ascii_punctuation());
+    /// assert!(!'¿'.is_ascii_punctuation());
+    /// ```
+    #[unstable(feature = "ascii_ctype", issue = "39658")]
+    #[inline]
+    pub const fn is_ascii_punctuation(&self) -> bool {
+        (*self as u32 <= 0x7F) & (*self as u8).is_ascii_punctuation()
+    }
+
+    /// Checks if the value is an ASCII graphic character:
+    /// U+0021 '!' ... U+007E '~'.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_ctype)]
+    ///
+    /// assert!('%'.is_ascii_graphic());
+    /// assert!(!' '.is_ascii_graphic());
+    /// ```
+    #[unstable(feature = "ascii_ctype", issue = "39658")]
+    #[inline]
+    pub const fn is_ascii_graphic(&self) -> bool {
+        (*self as u32 <= 0x7F) & (*self as u8).is_ascii_graphic()
+    }
+
+    /// Checks if the value is an ASCII whitespace character:
+    /// U+0020 SPACE, U+0009 HORIZONTAL TAB, U+000A LINE FEED,
+    /// U+000C FORM FEED, or U+000D CARRIAGE RETURN.
+    ///
+    /// Unlike [`is_whitespace`], this doesn't accept any non-ASCII
+    /// whitespace, nor U+000B VERTICAL TAB.
+    ///
+    /// [`is_whitespace`]: #method.is_whitespace
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_ctype)]
+    ///
+    /// assert!('\n'.is_ascii_whitespace());
+    /// assert!(!'\u{A0}'.is_ascii_whitespace());
+    /// ```
+    #[unstable(feature = "ascii_ctype", issue = "39658")]
+    #[inline]
+    pub const fn is_ascii_whitespace(&self) -> bool {
+        (*self as u32 <= 0x7F) & (*self as u8).is_ascii_whitespace()
+    }
+
+    /// Checks if the value is an ASCII control character:
+    /// U+0000 NUL ... U+001F UNIT SEPARATOR, or U+007F DELETE.
+    /// Note that most ASCII whitespace characters are control
+    /// characters, but SPACE is not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ascii_ctype)]
+    ///
+    /// assert!('\u{1b}'.is_ascii_control());
+    /// assert!(!'\u{80}'.is_ascii_control());
+    /// ```
+    #[unstable(feature = "ascii_ctype", issue = "39658")]
+    #[inline]
+    pub const fn is_ascii_control(&self) -> bool {
+        (*self as u32 <= 0x7F) & (*self as u8).is_ascii_control()
+    }
+
     /// Returns true if this `char` is an alphabetic code point, and false if not.
     ///
     /// # Examples
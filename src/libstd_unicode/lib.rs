@@ -32,16 +32,22 @@
 #![deny(warnings)]
 #![no_std]
 
+#![feature(ascii_char)]
+#![feature(ascii_ctype)]
+#![feature(const_fn)]
 #![feature(core_char_ext)]
 #![feature(str_internals)]
 #![feature(core_intrinsics)]
 #![feature(decode_utf8)]
 #![feature(fused)]
+#![feature(is_ascii_octdigit)]
 #![feature(fn_traits)]
 #![feature(lang_items)]
 #![feature(staged_api)]
+#![feature(try_encode_char)]
 #![feature(try_from)]
 #![feature(unboxed_closures)]
+#![feature(utf8_chunks)]
 
 mod tables;
 mod u_str;
@@ -33,7 +33,8 @@
        test(attr(deny(warnings))))]
 #![deny(warnings)]
 
-#![feature(asm)]
+#![feature(core_hint)]
+#![feature(hint_black_box)]
 #![feature(libc)]
 #![feature(set_stdio)]
 #![feature(panic_unwind)]
@@ -1550,22 +1551,8 @@ impl MetricMap {
 /// pretend to use outputs to assist in avoiding dead-code
 /// elimination.
 ///
-/// This function is a no-op, and does not even read from `dummy`.
-#[cfg(not(any(all(target_os = "nacl", target_arch = "le32"),
-              target_arch = "asmjs", target_arch = "wasm32")))]
-pub fn black_box<T>(dummy: T) -> T {
-    // we need to "use" the argument in some way LLVM can't
-    // introspect.
-    unsafe { asm!("" : : "r"(&dummy)) }
-    dummy
-}
-#[cfg(any(all(target_os = "nacl", target_arch = "le32"),
-          target_arch = "asmjs", target_arch = "wasm32"))]
-#[inline(never)]
-pub fn black_box<T>(dummy: T) -> T {
-    dummy
-}
-
+/// This is a re-export of `std::hint::black_box`.
+pub use std::hint::black_box;
 
 impl Bencher {
     /// Callback for benchmark functions to run in their body.
@@ -0,0 +1,21 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Check that `matches!` is feature-gated in a `#![no_std]` crate, which
+// gets `#[macro_use] extern crate core` injected.
+
+// error-pattern:use of unstable library feature 'matches_macro'
+
+#![no_std]
+#![crate_type = "lib"]
+
+pub fn is_some(x: Option<u8>) -> bool {
+    matches!(x, Some(_))
+}
@@ -0,0 +1,21 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// The `impl [u8]` methods are not available on other slice types.
+
+#![feature(byte_slice_trim_ascii)]
+
+fn main() {
+    let bytes: &[u8] = b" a ";
+    assert_eq!(bytes.trim_ascii(), b"a");
+
+    let ints: &[i32] = &[1, 2];
+    ints.trim_ascii(); //~ ERROR no method named `trim_ascii` found
+}
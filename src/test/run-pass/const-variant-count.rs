@@ -0,0 +1,43 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(variant_count)]
+
+use std::mem;
+
+#[allow(dead_code)]
+enum Opcode {
+    Load,
+    Store,
+    Add,
+    Jump(usize),
+}
+
+enum Void {}
+
+#[allow(dead_code)]
+struct NotAnEnum(u8, u8);
+
+const OPCODES: usize = mem::variant_count::<Opcode>();
+static COUNTS: [u32; mem::variant_count::<Opcode>()] = [0; mem::variant_count::<Opcode>()];
+
+fn count<T>() -> usize {
+    mem::variant_count::<T>()
+}
+
+fn main() {
+    assert_eq!(OPCODES, 4);
+    assert_eq!(COUNTS.len(), 4);
+    assert_eq!(count::<Opcode>(), 4);
+    assert_eq!(count::<Option<Opcode>>(), 2);
+    assert_eq!(count::<Void>(), 0);
+    assert_eq!(count::<NotAnEnum>(), 0);
+    assert_eq!(count::<u32>(), 0);
+}
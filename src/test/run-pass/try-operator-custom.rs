@@ -8,9 +8,9 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-#![feature(try_trait)]
+#![feature(try_trait, never_type)]
 
-use std::ops::Try;
+use std::ops::{ControlFlow, FromResidual, Try};
 
 enum MyResult<T, U> {
     Awesome(T),
@@ -18,21 +18,44 @@ enum MyResult<T, U> {
 }
 
 impl<U, V> Try for MyResult<U, V> {
-    type Ok = U;
-    type Error = V;
+    type Output = U;
+    type Residual = MyResult<!, V>;
 
-    fn from_ok(u: U) -> MyResult<U, V> {
+    fn from_output(u: U) -> MyResult<U, V> {
         MyResult::Awesome(u)
     }
 
-    fn from_error(e: V) -> MyResult<U, V> {
-        MyResult::Terrible(e)
+    fn branch(self) -> ControlFlow<MyResult<!, V>, U> {
+        match self {
+            MyResult::Awesome(u) => ControlFlow::Continue(u),
+            MyResult::Terrible(e) => ControlFlow::Break(MyResult::Terrible(e)),
+        }
     }
+}
 
-    fn into_result(self) -> Result<U, V> {
-        match self {
-            MyResult::Awesome(u) => Ok(u),
-            MyResult::Terrible(e) => Err(e),
+impl<U, V, W: From<V>> FromResidual<MyResult<!, V>> for MyResult<U, W> {
+    fn from_residual(r: MyResult<!, V>) -> MyResult<U, W> {
+        match r {
+            MyResult::Awesome(never) => never,
+            MyResult::Terrible(e) => MyResult::Terrible(From::from(e)),
+        }
+    }
+}
+
+impl<U, V, W: From<V>> FromResidual<Result<!, V>> for MyResult<U, W> {
+    fn from_residual(r: Result<!, V>) -> MyResult<U, W> {
+        match r {
+            Ok(never) => never,
+            Err(e) => MyResult::Terrible(From::from(e)),
+        }
+    }
+}
+
+impl<T, U, V: From<U>> FromResidual<MyResult<!, U>> for Result<T, V> {
+    fn from_residual(r: MyResult<!, U>) -> Result<T, V> {
+        match r {
+            MyResult::Awesome(never) => never,
+            MyResult::Terrible(e) => Err(From::from(e)),
         }
     }
 }